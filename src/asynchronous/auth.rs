@@ -0,0 +1,56 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use crate::cmn::*;
+use crate::emulator::EmulatorAuth;
+
+/// The future returned by `TokenProvider::token()`.
+pub type TokenFuture<'a> = Pin<Box<dyn Future<Output = Result<oauth2::Token>> + Send + 'a>>;
+
+/// Obtains the access tokens of an asynchronous hub, the counterpart of
+/// `oauth2::GetToken`.
+///
+/// It is shared by all clones of the hub, and may be asked for tokens by several
+/// tasks at once.
+pub trait TokenProvider: Send + Sync {
+    /// A token granting access to the given scopes. Its `access_token` is sent as
+    /// `Bearer` token.
+    fn token<'a>(&'a self, scopes: &'a [String]) -> TokenFuture<'a>;
+}
+
+impl TokenProvider for EmulatorAuth {
+    fn token<'a>(&'a self, _scopes: &'a [String]) -> TokenFuture<'a> {
+        Box::pin(async { Ok(EmulatorAuth::owner_token()) })
+    }
+}
+
+/// Obtains tokens from a blocking `oauth2::GetToken` authenticator, such as
+/// `ServiceAccountAuth`, on the blocking thread pool of tokio.
+///
+/// The authenticator is used by one task at a time, and keeps caching its tokens as
+/// it does for the blocking hub.
+pub struct BlockingAuth<A> {
+    auth: Arc<Mutex<A>>,
+}
+
+impl<A> BlockingAuth<A> where A: oauth2::GetToken + Send + 'static {
+    pub fn new(authenticator: A) -> BlockingAuth<A> {
+        BlockingAuth {
+            auth: Arc::new(Mutex::new(authenticator)),
+        }
+    }
+}
+
+impl<A> TokenProvider for BlockingAuth<A> where A: oauth2::GetToken + Send + 'static {
+    fn token<'a>(&'a self, scopes: &'a [String]) -> TokenFuture<'a> {
+        let auth = self.auth.clone();
+        let scopes = scopes.to_vec();
+        Box::pin(async move {
+            let token = tokio::task::spawn_blocking(move || {
+                let mut auth = auth.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                auth.token(&scopes).map_err(|err| Error::MissingToken(err.to_string().into()))
+            }).await;
+            token.unwrap_or_else(|err| Err(Error::MissingToken(Box::new(err))))
+        })
+    }
+}
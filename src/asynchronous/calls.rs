@@ -0,0 +1,959 @@
+use std::collections::{BTreeMap, HashMap};
+use reqwest::Method;
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
+use serde::de::DeserializeOwned;
+use serde_json as json;
+use crate::asynchronous::Firestore;
+use crate::cmn::*;
+use crate::document::*;
+use crate::method::*;
+use crate::query::*;
+use crate::status::FirestoreError;
+use crate::transaction::*;
+use crate::value::Empty;
+
+impl Firestore {
+
+    /// Sends the request of a call once, and decodes the response of a successful call.
+    pub(crate) async fn execute_json<T>(&self, request: CallRequest) -> Result<T>
+        where T: DeserializeOwned {
+        request.check_params()?;
+        let hub = &self.inner;
+        let url = reqwest::Url::parse(request.url(&hub.base_url).as_str())
+            .expect("the base url to be valid");
+        let method = Method::from_bytes(request.method.http_method.as_ref().as_bytes())
+            .expect("a valid http method");
+
+        let scopes: Vec<String> = if request.scopes.is_empty() {
+            hub.scopes.clone()
+        } else {
+            request.scopes.into_keys().collect()
+        };
+        let token = hub.auth.token(&scopes).await?;
+
+        let mut req = hub.client.request(method, url)
+            .header(USER_AGENT, hub.user_agent.as_str())
+            .bearer_auth(&token.access_token);
+        if let Some(body) = request.body {
+            req = req.header(CONTENT_TYPE, "application/json").body(body);
+        }
+        let res = req.send().await.map_err(Error::AsyncHttpError)?;
+        let status = res.status().as_u16();
+        let json_response = res.text().await.map_err(Error::AsyncHttpError)?;
+        if !(200..300).contains(&status) {
+            let status = hyper::status::StatusCode::from_u16(status);
+            return Err(Error::Firestore(FirestoreError::from_response(status, &json_response)));
+        }
+        match json::from_str(&json_response) {
+            Ok(decoded) => Ok(decoded),
+            Err(err) => Err(Error::JsonDecodeError(json_response, err)),
+        }
+    }
+}
+
+
+/// Gets a single document.
+///
+/// A builder for the *databases.documents.get* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_get()`.
+pub struct ProjectDatabaseDocumentGetCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _name: String,
+    pub(super) _transaction: Option<String>,
+    pub(super) _read_time: Option<String>,
+    pub(super) _mask_field_paths: Vec<String>,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentGetCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentGetCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<Document> {
+        let mut params = Vec::with_capacity(3);
+        if let Some(value) = self._transaction {
+            params.push(("transaction", value));
+        }
+        if let Some(value) = self._read_time {
+            params.push(("readTime", value));
+        }
+        for value in self._mask_field_paths {
+            params.push(("mask.fieldPaths", value));
+        }
+        self.hub.execute_json(CallRequest {
+            method: &GET,
+            url_params: vec![("{+name}", self._name)],
+            params,
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: None,
+        }).await
+    }
+
+    /// Perform the operation you have build so far, returning `None` if the document
+    /// does not exist.
+    pub async fn doit_optional(self) -> Result<Option<Document>> {
+        match self.doit().await {
+            Ok(document) => Ok(Some(document)),
+            Err(ref err) if err.is_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// The resource name of the Document to get. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    ///
+    /// Sets the *name* path property to the given value.
+    pub fn name<N: Into<String>>(mut self, new_value: N) -> ProjectDatabaseDocumentGetCall<'a> {
+        self._name = new_value.into();
+        self
+    }
+    /// Reads the document in a transaction.
+    ///
+    /// Sets the *transaction* query property to the given value.
+    pub fn transaction(mut self, new_value: &str) -> ProjectDatabaseDocumentGetCall<'a> {
+        self._transaction = Some(new_value.to_string());
+        self
+    }
+    /// Reads the version of the document at the given time.
+    /// This may not be older than 60 seconds.
+    ///
+    /// Sets the *read time* query property to the given value.
+    pub fn read_time(mut self, new_value: &str) -> ProjectDatabaseDocumentGetCall<'a> {
+        self._read_time = Some(new_value.to_string());
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *mask.field paths* query property.
+    pub fn add_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentGetCall<'a> {
+        self._mask_field_paths.push(new_value.to_string());
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentGetCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentGetCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Runs a query.
+///
+/// A builder for the *databases.documents.runQuery* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_run_query()`.
+pub struct ProjectDatabaseDocumentRunQueryCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _request: RunQueryRequest,
+    pub(super) _parent: String,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentRunQueryCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentRunQueryCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<Vec<RunQueryResponse>> {
+        self.hub.execute_json(CallRequest {
+            method: &RUN_QUERY,
+            url_params: vec![("{+parent}", self._parent)],
+            params: Vec::new(),
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: Some(json_body(&self._request)),
+        }).await
+    }
+
+    /// Sets the *request* property to the given value.
+    pub fn request(mut self, new_value: RunQueryRequest) -> ProjectDatabaseDocumentRunQueryCall<'a> {
+        self._request = new_value;
+        self
+    }
+    /// The parent resource name. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents` or
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    ///
+    /// Sets the *parent* path property to the given value.
+    pub fn parent<P: Into<String>>(mut self, new_value: P) -> ProjectDatabaseDocumentRunQueryCall<'a> {
+        self._parent = new_value.into();
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentRunQueryCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentRunQueryCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Gets multiple documents.
+///
+/// Documents returned by this method are not guaranteed to be returned in the
+/// same order that they were requested.
+///
+/// A builder for the *databases.documents.batchGet* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_batch_get()`.
+pub struct ProjectDatabaseDocumentBatchGetCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _request: BatchGetDocumentsRequest,
+    pub(super) _database: String,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentBatchGetCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentBatchGetCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<Vec<BatchGetDocumentsResponse>> {
+        self.hub.execute_json(CallRequest {
+            method: &BATCH_GET,
+            url_params: vec![("{+database}", self._database)],
+            params: Vec::new(),
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: Some(json_body(&self._request)),
+        }).await
+    }
+
+    /// Sets the *request* property to the given value.
+    pub fn request(mut self, new_value: BatchGetDocumentsRequest) -> ProjectDatabaseDocumentBatchGetCall<'a> {
+        self._request = new_value;
+        self
+    }
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    ///
+    /// Sets the *database* path property to the given value.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentBatchGetCall<'a> {
+        self._database = new_value.into();
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentBatchGetCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentBatchGetCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Starts a new transaction.
+///
+/// A builder for the *databases.documents.beginTransaction* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_begin_transaction()`.
+pub struct ProjectDatabaseDocumentBeginTransactionCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _request: BeginTransactionRequest,
+    pub(super) _database: String,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentBeginTransactionCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentBeginTransactionCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<BeginTransactionResponse> {
+        self.hub.execute_json(CallRequest {
+            method: &BEGIN_TRANSACTION,
+            url_params: vec![("{+database}", self._database)],
+            params: Vec::new(),
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: Some(json_body(&self._request)),
+        }).await
+    }
+
+    /// Sets the *request* property to the given value.
+    pub fn request(mut self, new_value: BeginTransactionRequest) -> ProjectDatabaseDocumentBeginTransactionCall<'a> {
+        self._request = new_value;
+        self
+    }
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    ///
+    /// Sets the *database* path property to the given value.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentBeginTransactionCall<'a> {
+        self._database = new_value.into();
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentBeginTransactionCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentBeginTransactionCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Commits a transaction, while optionally updating documents.
+///
+/// A builder for the *databases.documents.commit* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_commit()`.
+pub struct ProjectDatabaseDocumentCommitCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _request: CommitRequest,
+    pub(super) _database: String,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentCommitCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentCommitCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<CommitResponse> {
+        self.hub.execute_json(CallRequest {
+            method: &COMMIT,
+            url_params: vec![("{+database}", self._database)],
+            params: Vec::new(),
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: Some(json_body(&self._request)),
+        }).await
+    }
+
+    /// Sets the *request* property to the given value.
+    pub fn request(mut self, new_value: CommitRequest) -> ProjectDatabaseDocumentCommitCall<'a> {
+        self._request = new_value;
+        self
+    }
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    ///
+    /// Sets the *database* path property to the given value.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentCommitCall<'a> {
+        self._database = new_value.into();
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentCommitCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentCommitCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Rolls back a transaction.
+///
+/// A builder for the *databases.documents.rollback* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_rollback()`.
+pub struct ProjectDatabaseDocumentRollbackCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _request: RollbackRequest,
+    pub(super) _database: String,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentRollbackCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentRollbackCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<Empty> {
+        self.hub.execute_json(CallRequest {
+            method: &ROLLBACK,
+            url_params: vec![("{+database}", self._database)],
+            params: Vec::new(),
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: Some(json_body(&self._request)),
+        }).await
+    }
+
+    /// Sets the *request* property to the given value.
+    pub fn request(mut self, new_value: RollbackRequest) -> ProjectDatabaseDocumentRollbackCall<'a> {
+        self._request = new_value;
+        self
+    }
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    ///
+    /// Sets the *database* path property to the given value.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentRollbackCall<'a> {
+        self._database = new_value.into();
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentRollbackCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentRollbackCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Deletes a document.
+///
+/// A builder for the *databases.documents.delete* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_delete()`.
+pub struct ProjectDatabaseDocumentDeleteCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _name: String,
+    pub(super) _current_document_update_time: Option<String>,
+    pub(super) _current_document_exists: Option<bool>,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentDeleteCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentDeleteCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<Empty> {
+        let mut params = Vec::with_capacity(2);
+        if let Some(value) = self._current_document_update_time {
+            params.push(("currentDocument.updateTime", value));
+        }
+        if let Some(value) = self._current_document_exists {
+            params.push(("currentDocument.exists", value.to_string()));
+        }
+        self.hub.execute_json(CallRequest {
+            method: &DELETE,
+            url_params: vec![("{+name}", self._name)],
+            params,
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: None,
+        }).await
+    }
+
+    /// The resource name of the Document to delete. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    ///
+    /// Sets the *name* path property to the given value.
+    pub fn name<N: Into<String>>(mut self, new_value: N) -> ProjectDatabaseDocumentDeleteCall<'a> {
+        self._name = new_value.into();
+        self
+    }
+    /// When set, the target document must exist and have been last updated at
+    /// that time.
+    ///
+    /// Sets the *current document.update time* query property to the given value.
+    pub fn current_document_update_time(mut self, new_value: &str) -> ProjectDatabaseDocumentDeleteCall<'a> {
+        self._current_document_update_time = Some(new_value.to_string());
+        self
+    }
+    /// When set to `true`, the target document must exist.
+    /// When set to `false`, the target document must not exist.
+    ///
+    /// Sets the *current document.exists* query property to the given value.
+    pub fn current_document_exists(mut self, new_value: bool) -> ProjectDatabaseDocumentDeleteCall<'a> {
+        self._current_document_exists = Some(new_value);
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentDeleteCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentDeleteCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Creates a new document.
+///
+/// A builder for the *databases.documents.createDocument* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_create_document()`.
+pub struct ProjectDatabaseDocumentCreateDocumentCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _request: Document,
+    pub(super) _parent: String,
+    pub(super) _collection_id: String,
+    pub(super) _mask_field_paths: Vec<String>,
+    pub(super) _document_id: Option<String>,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentCreateDocumentCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentCreateDocumentCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<Document> {
+        let mut params = Vec::with_capacity(2);
+        for value in self._mask_field_paths {
+            params.push(("mask.fieldPaths", value));
+        }
+        if let Some(value) = self._document_id {
+            params.push(("documentId", value));
+        }
+        self.hub.execute_json(CallRequest {
+            method: &CREATE_DOCUMENT,
+            url_params: vec![("{+parent}", self._parent), ("{collectionId}", self._collection_id)],
+            params,
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: Some(json_body(&self._request)),
+        }).await
+    }
+
+    /// Sets the *request* property to the given value.
+    pub fn request(mut self, new_value: Document) -> ProjectDatabaseDocumentCreateDocumentCall<'a> {
+        self._request = new_value;
+        self
+    }
+    /// The parent resource. For example:
+    /// `projects/{project_id}/databases/{database_id}/documents` or
+    /// `projects/{project_id}/databases/{database_id}/documents/chatrooms/{chatroom_id}`
+    ///
+    /// Sets the *parent* path property to the given value.
+    pub fn parent<P: Into<String>>(mut self, new_value: P) -> ProjectDatabaseDocumentCreateDocumentCall<'a> {
+        self._parent = new_value.into();
+        self
+    }
+    /// The collection ID, relative to `parent`, to list. For example: `chatrooms`.
+    ///
+    /// Sets the *collection id* path property to the given value.
+    pub fn collection_id(mut self, new_value: &str) -> ProjectDatabaseDocumentCreateDocumentCall<'a> {
+        self._collection_id = new_value.to_string();
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *mask.field paths* query property.
+    pub fn add_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentCreateDocumentCall<'a> {
+        self._mask_field_paths.push(new_value.to_string());
+        self
+    }
+    /// The client-assigned document ID to use for this document.
+    ///
+    /// Optional. If not specified, an ID will be assigned by the service.
+    ///
+    /// Sets the *document id* query property to the given value.
+    pub fn document_id(mut self, new_value: &str) -> ProjectDatabaseDocumentCreateDocumentCall<'a> {
+        self._document_id = Some(new_value.to_string());
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentCreateDocumentCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentCreateDocumentCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Lists documents.
+///
+/// A builder for the *databases.documents.list* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_list()`.
+pub struct ProjectDatabaseDocumentListCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _parent: String,
+    pub(super) _collection_id: String,
+    pub(super) _transaction: Option<String>,
+    pub(super) _show_missing: Option<bool>,
+    pub(super) _read_time: Option<String>,
+    pub(super) _page_token: Option<String>,
+    pub(super) _page_size: Option<i32>,
+    pub(super) _order_by: Option<String>,
+    pub(super) _mask_field_paths: Vec<String>,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentListCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentListCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<ListDocumentsResponse> {
+        let mut params = Vec::with_capacity(7);
+        if let Some(value) = self._transaction {
+            params.push(("transaction", value));
+        }
+        if let Some(value) = self._show_missing {
+            params.push(("showMissing", value.to_string()));
+        }
+        if let Some(value) = self._read_time {
+            params.push(("readTime", value));
+        }
+        if let Some(value) = self._page_token {
+            params.push(("pageToken", value));
+        }
+        if let Some(value) = self._page_size {
+            params.push(("pageSize", value.to_string()));
+        }
+        if let Some(value) = self._order_by {
+            params.push(("orderBy", value));
+        }
+        for value in self._mask_field_paths {
+            params.push(("mask.fieldPaths", value));
+        }
+        self.hub.execute_json(CallRequest {
+            method: &LIST,
+            url_params: vec![("{+parent}", self._parent), ("{collectionId}", self._collection_id)],
+            params,
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: None,
+        }).await
+    }
+
+    /// The parent resource name. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents` or
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    ///
+    /// Sets the *parent* path property to the given value.
+    pub fn parent<P: Into<String>>(mut self, new_value: P) -> ProjectDatabaseDocumentListCall<'a> {
+        self._parent = new_value.into();
+        self
+    }
+    /// The collection ID, relative to `parent`, to list. For example: `chatrooms`
+    /// or `messages`.
+    ///
+    /// Sets the *collection id* path property to the given value.
+    pub fn collection_id(mut self, new_value: &str) -> ProjectDatabaseDocumentListCall<'a> {
+        self._collection_id = new_value.to_string();
+        self
+    }
+    /// Reads documents in a transaction.
+    ///
+    /// Sets the *transaction* query property to the given value.
+    pub fn transaction(mut self, new_value: &str) -> ProjectDatabaseDocumentListCall<'a> {
+        self._transaction = Some(new_value.to_string());
+        self
+    }
+    /// If the list should show missing documents. A missing document is a
+    /// document that does not exist but has sub-documents. These documents will
+    /// be returned with a key but will not have fields, Document.create_time,
+    /// or Document.update_time set.
+    ///
+    /// Requests with `show_missing` may not specify `where` or
+    /// `order_by`.
+    ///
+    /// Sets the *show missing* query property to the given value.
+    pub fn show_missing(mut self, new_value: bool) -> ProjectDatabaseDocumentListCall<'a> {
+        self._show_missing = Some(new_value);
+        self
+    }
+    /// Reads documents as they were at the given time.
+    /// This may not be older than 60 seconds.
+    ///
+    /// Sets the *read time* query property to the given value.
+    pub fn read_time(mut self, new_value: &str) -> ProjectDatabaseDocumentListCall<'a> {
+        self._read_time = Some(new_value.to_string());
+        self
+    }
+    /// The `next_page_token` value returned from a previous List request, if any.
+    ///
+    /// Sets the *page token* query property to the given value.
+    pub fn page_token(mut self, new_value: &str) -> ProjectDatabaseDocumentListCall<'a> {
+        self._page_token = Some(new_value.to_string());
+        self
+    }
+    /// The maximum number of documents to return.
+    ///
+    /// Sets the *page size* query property to the given value.
+    pub fn page_size(mut self, new_value: i32) -> ProjectDatabaseDocumentListCall<'a> {
+        self._page_size = Some(new_value);
+        self
+    }
+    /// The order to sort results by. For example: `priority desc, name`.
+    ///
+    /// Sets the *order by* query property to the given value.
+    pub fn order_by(mut self, new_value: &str) -> ProjectDatabaseDocumentListCall<'a> {
+        self._order_by = Some(new_value.to_string());
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *mask.field paths* query property.
+    pub fn add_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentListCall<'a> {
+        self._mask_field_paths.push(new_value.to_string());
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentListCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentListCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
+
+
+/// Updates or inserts a document.
+///
+/// A builder for the *databases.documents.patch* method of an asynchronous hub, created by
+/// `ProjectMethods::databases_documents_patch()`.
+pub struct ProjectDatabaseDocumentPatchCall<'a> {
+    pub(super) hub: &'a Firestore,
+    pub(super) _request: Document,
+    pub(super) _name: String,
+    pub(super) _update_mask_field_paths: Vec<String>,
+    pub(super) _mask_field_paths: Vec<String>,
+    pub(super) _current_document_update_time: Option<String>,
+    pub(super) _current_document_exists: Option<bool>,
+    pub(super) _additional_params: HashMap<String, String>,
+    pub(super) _scopes: BTreeMap<String, ()>,
+}
+
+impl<'a> CallBuilder for ProjectDatabaseDocumentPatchCall<'a> {}
+
+impl<'a> ProjectDatabaseDocumentPatchCall<'a> {
+
+    /// Perform the operation you have build so far.
+    pub async fn doit(self) -> Result<Document> {
+        let mut params = Vec::with_capacity(4);
+        for value in self._update_mask_field_paths {
+            params.push(("updateMask.fieldPaths", value));
+        }
+        for value in self._mask_field_paths {
+            params.push(("mask.fieldPaths", value));
+        }
+        if let Some(value) = self._current_document_update_time {
+            params.push(("currentDocument.updateTime", value));
+        }
+        if let Some(value) = self._current_document_exists {
+            params.push(("currentDocument.exists", value.to_string()));
+        }
+        self.hub.execute_json(CallRequest {
+            method: &PATCH,
+            url_params: vec![("{+name}", self._name)],
+            params,
+            additional_params: self._additional_params,
+            scopes: self._scopes,
+            body: Some(json_body(&self._request)),
+        }).await
+    }
+
+    /// Sets the *request* property to the given value.
+    pub fn request(mut self, new_value: Document) -> ProjectDatabaseDocumentPatchCall<'a> {
+        self._request = new_value;
+        self
+    }
+    /// The resource name of the document, for example
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    ///
+    /// Sets the *name* path property to the given value.
+    pub fn name<N: Into<String>>(mut self, new_value: N) -> ProjectDatabaseDocumentPatchCall<'a> {
+        self._name = new_value.into();
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *update mask.field paths* query property.
+    pub fn add_update_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a> {
+        self._update_mask_field_paths.push(new_value.to_string());
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *mask.field paths* query property.
+    pub fn add_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a> {
+        self._mask_field_paths.push(new_value.to_string());
+        self
+    }
+    /// When set, the target document must exist and have been last updated at
+    /// that time.
+    ///
+    /// Sets the *current document.update time* query property to the given value.
+    pub fn current_document_update_time(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a> {
+        self._current_document_update_time = Some(new_value.to_string());
+        self
+    }
+    /// When set to `true`, the target document must exist.
+    /// When set to `false`, the target document must not exist.
+    ///
+    /// Sets the *current document.exists* query property to the given value.
+    pub fn current_document_exists(mut self, new_value: bool) -> ProjectDatabaseDocumentPatchCall<'a> {
+        self._current_document_exists = Some(new_value);
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request, e.g.
+    /// `fields` or `quotaUser`.
+    ///
+    /// It must not be one of the parameters which have their own setter, or the call
+    /// fails with `Error::FieldClash`.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentPatchCall<'a>
+        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// If no scope is added, the default scopes of the hub are used, which are
+    /// `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentPatchCall<'a>
+        where T: Into<Option<S>>, S: AsRef<str> {
+        if let Some(scope) = scope.into() {
+            self._scopes.insert(scope.as_ref().to_string(), ());
+        }
+        self
+    }
+}
@@ -0,0 +1,502 @@
+//! An asynchronous hub, whose calls are futures sending their requests with a
+//! `reqwest::Client`. It is available with the `async` feature.
+//!
+//! `Firestore::projects()` offers the methods of the blocking hub which have a single
+//! response, and their call builders have the same setters, but `doit()` returns a
+//! future of the decoded response. The hub is `Send + Sync` and cheap to clone, as all
+//! clones share the same client and authenticator, so a single hub can be used by any
+//! number of tasks.
+//!
+//! Tokens are obtained from a `TokenProvider`: `EmulatorAuth` for the emulator, or
+//! `BlockingAuth` to use any blocking `oauth2::GetToken` authenticator.
+//!
+//! # Limitations
+//!
+//! The asynchronous hub only covers the plain calls of the API:
+//!
+//! * calls do not take a `Delegate`, and send their request only once, without retries;
+//! * every response body is read completely before it is decoded, there is no
+//!   `doit_stream()` for `runQuery` and `batchGet`;
+//! * there is no `Listener` for listening to changes;
+//! * the helpers built on the blocking hub, like `all_documents()` of list calls,
+//!   `run_transaction()`, `WriteBatch::commit()` and the references of
+//!   `Firestore::collection()`, are not available. `WriteBatch::into_request()` builds
+//!   the request of a commit call.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # extern crate google_firestore as firestore1;
+//! # extern crate reqwest;
+//! # extern crate tokio;
+//! # use firestore1::{Document, EmulatorAuth, FirestoreBuilder, Result};
+//! # async fn egal() -> Result<()> {
+//! let hub = FirestoreBuilder::new()
+//!     .project_id("demo-project")
+//!     .emulator_host("localhost:8080")
+//!     .build_async(reqwest::Client::new(), EmulatorAuth)?;
+//!
+//! let parent = "projects/demo-project/databases/(default)/documents";
+//! let task = {
+//!     let hub = hub.clone();
+//!     tokio::spawn(async move {
+//!         hub.projects().databases_documents_create_document(Document::default(), parent, "users")
+//!            .document_id("alice")
+//!            .doit().await
+//!     })
+//! };
+//! let alice = task.await.expect("the task to finish")?;
+//! let same = hub.projects().databases_documents_get(alice.name.unwrap()).doit().await?;
+//! # Ok(())
+//! # }
+//! ```
+use std::sync::Arc;
+use crate::cmn::*;
+use crate::document::*;
+use crate::firestore::*;
+use crate::path::DatabasePath;
+use crate::query::*;
+use crate::transaction::*;
+use crate::Scope;
+
+mod auth;
+mod calls;
+pub use self::auth::*;
+pub use self::calls::*;
+
+/// The asynchronous counterpart of the blocking `Firestore` hub, see the
+/// [module documentation](index.html).
+///
+/// It is created with `Firestore::new()`, or configured by `FirestoreBuilder::build_async()`.
+#[derive(Clone)]
+pub struct Firestore {
+    inner: Arc<Hub>,
+}
+
+struct Hub {
+    client: reqwest::Client,
+    auth: Box<dyn TokenProvider>,
+    user_agent: String,
+    base_url: String,
+    database: Option<DatabasePath>,
+    scopes: Vec<String>,
+}
+
+impl crate::cmn::Hub for Firestore {}
+
+impl Firestore {
+
+    /// A hub with the default settings of `FirestoreBuilder`, and no default database.
+    pub fn new<A>(client: reqwest::Client, authenticator: A) -> Firestore
+        where A: TokenProvider + 'static {
+        Firestore {
+            inner: Arc::new(Hub {
+                client,
+                auth: Box::new(authenticator),
+                user_agent: DEFAULT_USER_AGENT.to_string(),
+                base_url: DEFAULT_BASE_URL.to_string(),
+                database: None,
+                scopes: vec![Scope::CloudPlatform.as_ref().to_string()],
+            }),
+        }
+    }
+
+    pub fn projects(&self) -> ProjectMethods<'_> {
+        ProjectMethods { hub: self }
+    }
+
+    /// The database set with `FirestoreBuilder`, if any.
+    pub fn database_path(&self) -> Option<&DatabasePath> {
+        self.inner.database.as_ref()
+    }
+}
+
+impl FirestoreBuilder {
+
+    /// Creates an asynchronous hub. It fails if the project or database id are not valid.
+    pub fn build_async<A>(self, client: reqwest::Client, authenticator: A) -> Result<Firestore>
+        where A: TokenProvider + 'static {
+        let database = match self.project_id {
+            Some(ref project_id) => Some(DatabasePath::new(project_id, &self.database_id)?),
+            None => None,
+        };
+        let scopes = if self.scopes.is_empty() {
+            vec![Scope::CloudPlatform.as_ref().to_string()]
+        } else {
+            self.scopes
+        };
+        Ok(Firestore {
+            inner: Arc::new(Hub {
+                client,
+                auth: Box::new(authenticator),
+                user_agent: self.user_agent,
+                base_url: self.base_url,
+                database,
+                scopes,
+            }),
+        })
+    }
+}
+
+/// The methods of the *project* resource of an asynchronous hub, created by
+/// `Firestore::projects()`.
+pub struct ProjectMethods<'a> {
+    hub: &'a Firestore,
+}
+
+impl<'a> MethodsBuilder for ProjectMethods<'a> {}
+
+impl<'a> ProjectMethods<'a> {
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Gets a single document.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The resource name of the Document to get. In the format:
+    ///   `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub fn databases_documents_get<N: Into<String>>(&self, name: N) -> ProjectDatabaseDocumentGetCall<'a> {
+        ProjectDatabaseDocumentGetCall {
+            hub: self.hub,
+            _name: name.into(),
+            _transaction: Default::default(),
+            _read_time: Default::default(),
+            _mask_field_paths: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Runs a query.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `parent` - The parent resource name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}/documents` or
+    ///   `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub fn databases_documents_run_query<P: Into<String>>(&self, request: RunQueryRequest, parent: P) -> ProjectDatabaseDocumentRunQueryCall<'a> {
+        ProjectDatabaseDocumentRunQueryCall {
+            hub: self.hub,
+            _request: request,
+            _parent: parent.into(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Gets multiple documents.
+    ///
+    /// Documents returned by this method are not guaranteed to be returned in the
+    /// same order that they were requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn databases_documents_batch_get<D: Into<String>>(&self, request: BatchGetDocumentsRequest, database: D) -> ProjectDatabaseDocumentBatchGetCall<'a> {
+        ProjectDatabaseDocumentBatchGetCall {
+            hub: self.hub,
+            _request: request,
+            _database: database.into(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Deletes a document.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The resource name of the Document to delete. In the format:
+    ///   `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub fn databases_documents_delete<N: Into<String>>(&self, name: N) -> ProjectDatabaseDocumentDeleteCall<'a> {
+        ProjectDatabaseDocumentDeleteCall {
+            hub: self.hub,
+            _name: name.into(),
+            _current_document_update_time: Default::default(),
+            _current_document_exists: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Creates a new document.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `parent` - The parent resource. For example:
+    ///   `projects/{project_id}/databases/{database_id}/documents` or
+    ///   `projects/{project_id}/databases/{database_id}/documents/chatrooms/{chatroom_id}`
+    /// * `collectionId` - The collection ID, relative to `parent`, to list. For example: `chatrooms`.
+    pub fn databases_documents_create_document<P: Into<String>>(&self, request: Document, parent: P, collection_id: &str) -> ProjectDatabaseDocumentCreateDocumentCall<'a> {
+        ProjectDatabaseDocumentCreateDocumentCall {
+            hub: self.hub,
+            _request: request,
+            _parent: parent.into(),
+            _collection_id: collection_id.to_string(),
+            _mask_field_paths: Default::default(),
+            _document_id: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Lists documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The parent resource name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}/documents` or
+    ///   `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    /// * `collectionId` - The collection ID, relative to `parent`, to list. For example: `chatrooms`
+    ///   or `messages`.
+    pub fn databases_documents_list<P: Into<String>>(&self, parent: P, collection_id: &str) -> ProjectDatabaseDocumentListCall<'a> {
+        ProjectDatabaseDocumentListCall {
+            hub: self.hub,
+            _parent: parent.into(),
+            _collection_id: collection_id.to_string(),
+            _transaction: Default::default(),
+            _show_missing: Default::default(),
+            _read_time: Default::default(),
+            _page_token: Default::default(),
+            _page_size: Default::default(),
+            _order_by: Default::default(),
+            _mask_field_paths: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Updates or inserts a document.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `name` - The resource name of the document, for example
+    ///   `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub fn databases_documents_patch<N: Into<String>>(&self, request: Document, name: N) -> ProjectDatabaseDocumentPatchCall<'a> {
+        ProjectDatabaseDocumentPatchCall {
+            hub: self.hub,
+            _request: request,
+            _name: name.into(),
+            _update_mask_field_paths: Default::default(),
+            _mask_field_paths: Default::default(),
+            _current_document_update_time: Default::default(),
+            _current_document_exists: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Starts a new transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn databases_documents_begin_transaction<D: Into<String>>(&self, request: BeginTransactionRequest, database: D) -> ProjectDatabaseDocumentBeginTransactionCall<'a> {
+        ProjectDatabaseDocumentBeginTransactionCall {
+            hub: self.hub,
+            _request: request,
+            _database: database.into(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Commits a transaction, while optionally updating documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn databases_documents_commit<D: Into<String>>(&self, request: CommitRequest, database: D) -> ProjectDatabaseDocumentCommitCall<'a> {
+        ProjectDatabaseDocumentCommitCall {
+            hub: self.hub,
+            _request: request,
+            _database: database.into(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+
+    /// Create a builder to help you perform the following task:
+    ///
+    /// Rolls back a transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn databases_documents_rollback<D: Into<String>>(&self, request: RollbackRequest, database: D) -> ProjectDatabaseDocumentRollbackCall<'a> {
+        ProjectDatabaseDocumentRollbackCall {
+            hub: self.hub,
+            _request: request,
+            _database: database.into(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as StdError;
+    use std::future::Future;
+    use std::io::{self, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use crate::emulator::EmulatorAuth;
+    use crate::status::Code;
+    use super::*;
+
+    const ALICE: &str = "projects/p/databases/(default)/documents/users/alice";
+
+    /// Answers one connection per response on a local port, and returns the host and
+    /// a thread yielding the heads of the requests it received.
+    fn serve(responses: Vec<(u16, String)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+        let server = thread::spawn(move || {
+            let mut heads = Vec::new();
+            for (code, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 4096];
+                let head = loop {
+                    let n = stream.read(&mut buf).unwrap();
+                    assert!(n > 0, "the connection was closed before the request was complete");
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let content_length = text[..end].lines()
+                            .filter_map(|line| line.split_once(':'))
+                            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                            .map_or(0, |(_, value)| value.trim().parse().unwrap());
+                        if request.len() >= end + 4 + content_length {
+                            break text[..end].to_string();
+                        }
+                    }
+                };
+                write!(stream, "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\n\
+                                Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                       code, body.len(), body).unwrap();
+                heads.push(head);
+            }
+            heads
+        });
+        (host, server)
+    }
+
+    fn hub(host: &str) -> Firestore {
+        FirestoreBuilder::new()
+            .project_id("p")
+            .emulator_host(host)
+            .build_async(reqwest::Client::new(), EmulatorAuth)
+            .unwrap()
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn hubs_and_errors_are_send() {
+        assert_send_sync_clone::<Firestore>();
+        assert_send(&Error::MissingToken("no token".into()));
+        let hub = hub("localhost:8080");
+        assert_send(&hub.projects().databases_documents_get(ALICE).doit());
+    }
+
+    #[test]
+    fn gets_documents() {
+        let document = format!(r#"{{"name": "{}", "fields": {{"age": {{"integerValue": "42"}}}}}}"#, ALICE);
+        let (host, server) = serve(vec![(200, document)]);
+        let hub = hub(&host);
+
+        let alice = block_on(hub.projects().databases_documents_get(ALICE).doit()).unwrap();
+        assert_eq!(alice.name.as_deref(), Some(ALICE));
+        assert!(alice.fields.unwrap().contains_key("age"));
+
+        let heads = server.join().unwrap();
+        let mut lines = heads[0].lines();
+        assert_eq!(lines.next(), Some(format!("GET /v1/{}?alt=json HTTP/1.1", ALICE).as_str()));
+        assert!(lines.any(|line| line.eq_ignore_ascii_case("authorization: Bearer owner")));
+    }
+
+    #[test]
+    fn firestore_errors() {
+        let not_found = r#"{"error": {"code": 404, "message": "no alice", "status": "NOT_FOUND"}}"#;
+        let (host, server) = serve(vec![(404, not_found.to_string()), (404, not_found.to_string())]);
+        let hub = hub(&host);
+
+        match block_on(hub.projects().databases_documents_get(ALICE).doit()) {
+            Err(Error::Firestore(err)) => {
+                assert_eq!(err.code, Code::NotFound);
+                assert_eq!(err.message, "no alice");
+            },
+            other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+        }
+        let missing = block_on(hub.projects().databases_documents_get(ALICE).doit_optional()).unwrap();
+        assert!(missing.is_none());
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    struct FailingAuth;
+
+    impl TokenProvider for FailingAuth {
+        fn token<'a>(&'a self, _scopes: &'a [String]) -> TokenFuture<'a> {
+            Box::pin(async {
+                Err(Error::MissingToken(Box::new(io::Error::other("no token"))))
+            })
+        }
+    }
+
+    #[test]
+    fn token_errors() {
+        // Nothing listens on the host, as no request is sent without a token.
+        let hub = FirestoreBuilder::new()
+            .emulator_host("127.0.0.1:9")
+            .build_async(reqwest::Client::new(), FailingAuth)
+            .unwrap();
+
+        match block_on(hub.projects().databases_documents_get(ALICE).doit()) {
+            Err(err @ Error::MissingToken(_)) => {
+                let source = err.source().and_then(|source| source.downcast_ref::<io::Error>());
+                assert_eq!(source.map(|err| err.to_string()), Some("no token".to_string()));
+            },
+            other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+        }
+    }
+}
@@ -77,9 +77,13 @@ pub struct ErrorResponse {
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ServerError {
+    #[serde(default)]
     pub errors: Vec<ServerMessage>,
     pub code: u16,
     pub message: String,
+    /// The canonical status, e.g. `NOT_FOUND`, which Firestore sends along with the
+    /// HTTP status `code`.
+    pub status: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -210,7 +214,7 @@ pub trait Delegate: Send {
     ///
     /// If you choose to retry after a duration, the duration should be chosen using the
     /// [exponential backoff algorithm](http://en.wikipedia.org/wiki/Exponential_backoff).
-    fn http_failure(&mut self, _: &hyper::client::Response, _: Option<JsonServerError>, _: Option<ServerError>) -> Retry {
+    fn http_failure(&mut self, _: &crate::transport::HttpResponse, _: Option<JsonServerError>, _: Option<ServerError>) -> Retry {
         Retry::Abort
     }
 
@@ -261,20 +265,36 @@ pub enum Error {
     /// The http connection failed
     HttpError(hyper::Error),
 
+    /// The http connection of an asynchronous call failed
+    #[cfg(feature = "async")]
+    AsyncHttpError(reqwest::Error),
+
     /// An attempt was made to upload a resource with size stored in field `.0`
     /// even though the maximum upload size is what is stored in field `.1`.
     UploadSizeLimitExceeded(u64, u64),
 
     /// Represents information about a request that was not understood by the server.
     /// Details are included.
+    #[deprecated(note = "error responses are returned as `Error::Firestore`")]
     BadRequest(ErrorResponse),
 
+    /// The server answered with the error response `.0`
+    Firestore(crate::status::FirestoreError),
+
     /// We needed an API key for authentication, but didn't obtain one.
     /// Neither through the authenticator, nor through the Delegate.
     MissingAPIKey,
 
-    /// We required a Token, but didn't get one from the Authenticator
-    MissingToken(Box<dyn error::Error>),
+    /// We required a Token, but didn't get one from the Authenticator.
+    ///
+    /// The errors of `oauth2::GetToken` are not `Send`, so only their message is kept.
+    MissingToken(Box<dyn error::Error + Send + Sync>),
+
+    /// The service account key could not be used, for the reason given in `.0`
+    InvalidServiceAccountKey(String),
+
+    /// The authorization server answered a token request with status `.0` and the body `.1`
+    TokenRequestFailed(hyper::status::StatusCode, String),
 
     /// The delgate instructed to cancel the operation
     Cancelled,
@@ -282,19 +302,52 @@ pub enum Error {
     /// An additional, free form field clashed with one of the built-in optional ones
     FieldClash(&'static str),
 
+    /// The write at index `.0` exceeds the maximum amount of writes allowed in a single commit
+    WriteLimitExceeded(usize),
+
+    /// The write at index `.0` contains an invalid transform of the field at path `.1`,
+    /// or a transform without any field transforms, with an empty path
+    InvalidFieldTransform(usize, String),
+
+    /// A value could not be converted between a Rust type and a Firestore `Value`
+    Conversion(String),
+
+    /// The clause `.0` of a query violates Firestore's query rules, as described in `.1`
+    InvalidQuery(crate::query::QueryClause, String),
+
+    /// The resource name `.0` is not a valid Firestore path
+    InvalidPath(String),
+
+    /// A reference was requested, or a call was made in the default database, of a hub
+    /// without a database set
+    MissingDatabase,
+
+    /// An emulator hub was built without an emulator host
+    MissingEmulatorHost,
+
+    /// The server removed the target of a listener, with the cause `.0` if one was given
+    TargetRemoved(Option<crate::listen::Status>),
+
+    /// The server closed the stream of a listener `.0` consecutive times without a response
+    ListenStreamClosed(u32),
+
     /// Shows that we failed to decode the server response.
     /// This can happen if the protocol changes in conjunction with strict json decoding.
     JsonDecodeError(String, json::Error),
 
     /// Indicates an HTTP repsonse with a non-success status code
-    Failure(hyper::client::Response),
+    #[deprecated(note = "error responses are returned as `Error::Firestore`")]
+    Failure(Box<hyper::client::Response>),
 }
 
 
 impl Display for Error {
+    #[allow(deprecated)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Error::HttpError(ref err) => err.fmt(f),
+            #[cfg(feature = "async")]
+            Error::AsyncHttpError(ref err) => err.fmt(f),
             Error::UploadSizeLimitExceeded(ref resource_size, ref max_size) =>
                 writeln!(f, "The media size {} exceeds the maximum allowed upload size of {}"
                          , resource_size, max_size),
@@ -309,19 +362,50 @@ impl Display for Error {
                                             err.domain,
                                             err.message,
                                             err.reason,
-                                            match &err.location {
-                                                &Some(ref loc) => format!("@{}", loc),
-                                                &None => String::new(),
+                                            match err.location {
+                                                Some(ref loc) => format!("@{}", loc),
+                                                None => String::new(),
                                             })?;
                 }
                 Ok(())
             },
+            Error::Firestore(ref err) =>
+                writeln!(f, "{}", err),
             Error::MissingToken(ref err) =>
                 writeln!(f, "Token retrieval failed with error: {}", err),
+            Error::InvalidServiceAccountKey(ref msg) =>
+                writeln!(f, "Invalid service account key: {}", msg),
+            Error::TokenRequestFailed(status, ref body) =>
+                writeln!(f, "The token request failed with status {}: {}", status, body),
             Error::Cancelled =>
                 writeln!(f, "Operation cancelled by delegate"),
             Error::FieldClash(field) =>
                 writeln!(f, "The custom parameter '{}' is already provided natively by the CallBuilder.", field),
+            Error::WriteLimitExceeded(index) =>
+                writeln!(f, "The write at index {} exceeds the limit of {} writes per commit", index, crate::write::MAX_WRITES_PER_COMMIT),
+            Error::InvalidFieldTransform(index, ref field_path) if field_path.is_empty() =>
+                writeln!(f, "The write at index {} has an invalid transform without a field path", index),
+            Error::InvalidFieldTransform(index, ref field_path) =>
+                writeln!(f, "The write at index {} has an invalid transform of field '{}'", index, field_path),
+            Error::Conversion(ref msg) =>
+                writeln!(f, "Value conversion failed: {}", msg),
+            Error::InvalidQuery(clause, ref msg) =>
+                writeln!(f, "Invalid '{}' clause: {}", clause.as_ref(), msg),
+            Error::InvalidPath(ref path) =>
+                writeln!(f, "'{}' is not a valid Firestore resource name", path),
+            Error::MissingDatabase =>
+                writeln!(f, "No database was set on the hub, see `Firestore::database()`"),
+            Error::MissingEmulatorHost =>
+                writeln!(f, "No emulator host was set, see `FirestoreBuilder::emulator_host()` and {}",
+                         crate::emulator::EMULATOR_HOST_ENV),
+            Error::TargetRemoved(ref cause) => match *cause {
+                Some(ref status) =>
+                    writeln!(f, "The listen target was removed ({}): {}",
+                             status.code.unwrap_or_default(), status.message.as_ref().map_or("", String::as_str)),
+                None => writeln!(f, "The listen target was removed"),
+            },
+            Error::ListenStreamClosed(attempts) =>
+                writeln!(f, "The listen stream was closed {} times in a row without a response", attempts),
             Error::JsonDecodeError(ref json_str, ref err)
                 => writeln!(f, "{}: {}", err, json_str),
             Error::Failure(ref response) =>
@@ -339,10 +423,13 @@ impl error::Error for Error {
         }
     }
 
-    fn cause(&self) -> Option<&dyn error::Error> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
             Error::HttpError(ref err) => err.source(),
+            #[cfg(feature = "async")]
+            Error::AsyncHttpError(ref err) => err.source(),
             Error::JsonDecodeError(_, ref err) => err.source(),
+            Error::MissingToken(ref err) => Some(&**err),
             _ => None
         }
     }
@@ -612,7 +699,7 @@ impl HeaderFormat for RangeResponseHeader {
 
 /// A utility type to perform a resumable upload from start to end.
 pub struct ResumableUploadHelper<'a, A: 'a> {
-    pub client: &'a mut hyper::client::Client,
+    pub client: &'a mut dyn crate::transport::Transport,
     pub delegate: &'a mut dyn Delegate,
     pub start_at: Option<u64>,
     pub auth: &'a mut A,
@@ -627,13 +714,22 @@ pub struct ResumableUploadHelper<'a, A: 'a> {
 impl<'a, A> ResumableUploadHelper<'a, A>
     where A: oauth2::GetToken {
 
-    fn query_transfer_status(&mut self) -> std::result::Result<u64, hyper::Result<hyper::client::Response>> {
+    fn request(&self, headers: hyper::header::Headers, body: Vec<u8>) -> hyper::Result<crate::transport::HttpRequest> {
+        Ok(crate::transport::HttpRequest {
+            method: hyper::method::Method::Post,
+            url: hyper::Url::parse(self.url).map_err(hyper::Error::Uri)?,
+            headers,
+            body,
+        })
+    }
+
+    fn query_transfer_status(&mut self) -> std::result::Result<u64, hyper::Result<crate::transport::HttpResponse>> {
         loop {
-            match self.client.post(self.url)
-                .header(UserAgent(self.user_agent.to_string()))
-                .header(ContentRange { range: None, total_length: self.content_length })
-                .header(self.auth_header.clone())
-                .send() {
+            let mut headers = hyper::header::Headers::new();
+            headers.set(UserAgent(self.user_agent.to_string()));
+            headers.set(ContentRange { range: None, total_length: self.content_length });
+            headers.set(self.auth_header.clone());
+            match self.request(headers, Vec::new()).and_then(|request| self.client.send(request)) {
                 Ok(r) => {
                     // 308 = resume-incomplete == PermanentRedirect
                     let headers = r.headers.clone();
@@ -663,7 +759,7 @@ impl<'a, A> ResumableUploadHelper<'a, A>
     /// returns None if operation was cancelled by delegate, or the HttpResult.
     /// It can be that we return the result just because we didn't understand the status code -
     /// caller should check for status himself before assuming it's OK to use
-    pub fn upload(&mut self) -> Option<hyper::Result<hyper::client::Response>> {
+    pub fn upload(&mut self) -> Option<hyper::Result<crate::transport::HttpResponse>> {
         let mut start = match self.start_at {
             Some(s) => s,
             None => match self.query_transfer_status() {
@@ -694,12 +790,15 @@ impl<'a, A> ResumableUploadHelper<'a, A>
             if self.delegate.cancel_chunk_upload(&range_header) {
                 return None
             }
-            let res = self.client.post(self.url)
-                                 .header(range_header)
-                                 .header(ContentType(self.media_type.clone()))
-                                 .header(UserAgent(self.user_agent.to_string()))
-                                 .body(&mut section_reader)
-                                 .send();
+            let mut headers = hyper::header::Headers::new();
+            headers.set(range_header);
+            headers.set(ContentType(self.media_type.clone()));
+            headers.set(UserAgent(self.user_agent.to_string()));
+            let mut body = Vec::with_capacity(request_size as usize);
+            if let Err(err) = section_reader.read_to_end(&mut body) {
+                return Some(Err(hyper::Error::Io(err)))
+            }
+            let res = self.request(headers, body).and_then(|request| self.client.send(request));
             match res {
                 Ok(mut res) => {
                     if res.status == StatusCode::PermanentRedirect  {
@@ -710,7 +809,7 @@ impl<'a, A> ResumableUploadHelper<'a, A>
                         res.read_to_string(&mut json_err).unwrap();
                         if let Retry::After(d) = self.delegate.http_failure(&res,
                                                         json::from_str(&json_err).ok(),
-                                                        json::from_str(&json_err).ok()) {
+                                                        json::from_str::<ErrorResponse>(&json_err).ok().map(|r| r.error)) {
                             sleep(d);
                             continue;
                         }
@@ -0,0 +1,148 @@
+use serde::de::{self, Visitor, IntoDeserializer};
+use serde::de::value::{SeqDeserializer, MapDeserializer};
+use crate::cmn::*;
+use crate::value::*;
+
+/// A `Deserializer` reading from a Firestore `Value`, see `from_value`.
+pub struct ValueDeserializer {
+    value: Value,
+}
+
+impl ValueDeserializer {
+    pub fn new(value: Value) -> ValueDeserializer {
+        ValueDeserializer { value }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Value {
+    type Deserializer = ValueDeserializer;
+
+    fn into_deserializer(self) -> ValueDeserializer {
+        ValueDeserializer::new(self)
+    }
+}
+
+fn is_null(value: &Value) -> bool {
+    *value == Value::default() || value.null_value.is_some()
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let value = self.value;
+        if let Some(b) = value.boolean_value {
+            visitor.visit_bool(b)
+        } else if let Some(i) = value.integer_value {
+            match i.parse::<i64>() {
+                Ok(i) => visitor.visit_i64(i),
+                Err(_) => Err(Error::Conversion(format!("invalid integer value '{}'", i))),
+            }
+        } else if let Some(d) = value.double_value {
+            visitor.visit_f64(d)
+        } else if let Some(s) = value.string_value
+                                    .or(value.timestamp_value)
+                                    .or(value.reference_value) {
+            visitor.visit_string(s)
+        } else if let Some(b) = value.bytes_value {
+            match base64::decode(&b) {
+                Ok(bytes) => visitor.visit_byte_buf(bytes),
+                Err(err) => Err(Error::Conversion(format!("invalid bytes value: {}", err))),
+            }
+        } else if let Some(point) = value.geo_point_value {
+            let entries = vec![
+                ("latitude", point.latitude.unwrap_or_default()),
+                ("longitude", point.longitude.unwrap_or_default()),
+            ];
+            let mut map = MapDeserializer::new(entries.into_iter());
+            let result = visitor.visit_map(&mut map)?;
+            map.end()?;
+            Ok(result)
+        } else if let Some(array) = value.array_value {
+            let mut seq = SeqDeserializer::new(array.values.unwrap_or_default().into_iter());
+            let result = visitor.visit_seq(&mut seq)?;
+            seq.end()?;
+            Ok(result)
+        } else if let Some(map) = value.map_value {
+            let mut map = MapDeserializer::new(map.fields.unwrap_or_default().into_iter());
+            let result = visitor.visit_map(&mut map)?;
+            map.end()?;
+            Ok(result)
+        } else {
+            visitor.visit_unit()
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if is_null(&self.value) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str],
+                                         visitor: V) -> Result<V::Value> {
+        let value = self.value;
+        if let Some(variant) = value.string_value {
+            return visitor.visit_enum(variant.into_deserializer())
+        }
+        let mut fields = value.map_value.and_then(|m| m.fields).unwrap_or_default();
+        if fields.len() != 1 {
+            return Err(Error::Conversion("an enum must be a string or a map with a single key".to_string()))
+        }
+        let variant = fields.keys().next().cloned().unwrap_or_default();
+        let value = fields.remove(&variant).unwrap_or_default();
+        visitor.visit_enum(EnumDeserializer { variant, value })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Deserializes an enum variant carrying data, represented as a single entry map.
+struct EnumDeserializer {
+    variant: String,
+    value: Value,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer {
+    type Error = Error;
+    type Variant = ValueDeserializer;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, ValueDeserializer)> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, ValueDeserializer::new(self.value)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        if is_null(&self.value) {
+            Ok(())
+        } else {
+            Err(Error::Conversion("expected a unit variant".to_string()))
+        }
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
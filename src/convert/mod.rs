@@ -0,0 +1,214 @@
+use std::fmt::Display;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use crate::cmn::*;
+use crate::document::*;
+
+mod ser;
+mod de;
+pub use self::ser::ValueSerializer;
+pub use self::de::ValueDeserializer;
+
+/// The value Firestore expects in `Value.null_value`.
+pub(crate) const NULL_VALUE: &str = "NULL_VALUE";
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Error {
+        Error::Conversion(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Error {
+        Error::Conversion(msg.to_string())
+    }
+}
+
+/// Converts any serializable value into a Firestore `Value`.
+///
+/// Integers become `integer_value`, floats `double_value`, sequences and tuples
+/// `array_value`, structs and maps `map_value` and `None` or `()` a `null_value`.
+/// Enum variants carrying data are represented as a map with the variant name as
+/// the only key, unit variants as their name.
+pub fn to_value<T: ?Sized + Serialize>(value: &T) -> Result<Value> {
+    value.serialize(ValueSerializer)
+}
+
+/// Converts a Firestore `Value` into any deserializable type.
+///
+/// This is the inverse of `to_value`. `timestamp_value` and `reference_value`
+/// deserialize as strings, `bytes_value` as a byte buffer and `geo_point_value` as
+/// a map with `latitude` and `longitude`.
+pub fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T> {
+    T::deserialize(ValueDeserializer::new(value.clone()))
+}
+
+/// Converts a serializable struct or map into the fields of a `Document`.
+///
+/// The returned document has neither a `name` nor any timestamps set.
+pub fn to_document<T: ?Sized + Serialize>(value: &T) -> Result<Document> {
+    match to_value(value)?.map_value {
+        Some(map) => Ok(Document {
+            fields: Some(map.fields.unwrap_or_default()),
+            ..Default::default()
+        }),
+        None => Err(Error::Conversion("a document can only be created from a struct or map".to_string())),
+    }
+}
+
+/// Converts the fields of a `Document` into any deserializable type.
+pub fn from_document<T: DeserializeOwned>(document: &Document) -> Result<T> {
+    let fields = document.fields.clone().unwrap_or_default();
+    T::deserialize(ValueDeserializer::new(Value {
+        map_value: Some(MapValue { fields: Some(fields) }),
+        ..Default::default()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+    use std::fmt;
+    use serde::{Deserializer, Serializer};
+    use serde::de::Visitor;
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Role {
+        Reader,
+        Writer { since: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        city: String,
+        tags: BTreeMap<String, i64>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct User {
+        name: String,
+        age: u8,
+        score: f64,
+        admin: bool,
+        nickname: Option<String>,
+        address: Address,
+        roles: Vec<Role>,
+        matrix: Vec<Vec<i32>>,
+    }
+
+    /// Bytes serialized with `serialize_bytes`, like `serde_bytes::ByteBuf`.
+    #[derive(Debug, PartialEq)]
+    struct Bytes(Vec<u8>);
+
+    impl Serialize for Bytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Bytes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Bytes, D::Error> {
+            struct BytesVisitor;
+            impl<'de> Visitor<'de> for BytesVisitor {
+                type Value = Bytes;
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("bytes")
+                }
+                fn visit_byte_buf<E>(self, v: Vec<u8>) -> std::result::Result<Bytes, E> {
+                    Ok(Bytes(v))
+                }
+            }
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+
+    fn user() -> User {
+        let mut tags = BTreeMap::new();
+        tags.insert("floor".to_string(), 3);
+        tags.insert("zip".to_string(), -12345);
+        User {
+            name: "alice".to_string(),
+            age: 42,
+            score: 0.5,
+            admin: false,
+            nickname: None,
+            address: Address { city: "Berlin".to_string(), tags },
+            roles: vec![Role::Reader, Role::Writer { since: 2019 }],
+            matrix: vec![vec![1, 2], vec![], vec![3]],
+        }
+    }
+
+    #[test]
+    fn document_round_trip() {
+        let document = to_document(&user()).unwrap();
+        assert!(document.name.is_none());
+        let fields = document.fields.as_ref().unwrap();
+        assert_eq!(fields["age"].integer_value.as_deref(), Some("42"));
+        assert_eq!(fields["nickname"].null_value.as_deref(), Some(NULL_VALUE));
+        let address = fields["address"].map_value.as_ref().unwrap().fields.as_ref().unwrap();
+        assert_eq!(address["city"].string_value.as_deref(), Some("Berlin"));
+        let roles = fields["roles"].array_value.as_ref().unwrap().values.as_ref().unwrap();
+        assert_eq!(roles[0].string_value.as_deref(), Some("Reader"));
+        assert!(roles[1].map_value.as_ref().unwrap().fields.as_ref().unwrap().contains_key("Writer"));
+
+        assert_eq!(from_document::<User>(&document).unwrap(), user());
+    }
+
+    #[test]
+    fn nested_maps_and_arrays() {
+        let mut inner = HashMap::new();
+        inner.insert("b".to_string(), vec![Some(1u16), None]);
+        let mut outer = HashMap::new();
+        outer.insert("a".to_string(), inner);
+
+        let value = to_value(&outer).unwrap();
+        let a = &value.map_value.as_ref().unwrap().fields.as_ref().unwrap()["a"];
+        let b = &a.map_value.as_ref().unwrap().fields.as_ref().unwrap()["b"];
+        let values = b.array_value.as_ref().unwrap().values.as_ref().unwrap();
+        assert_eq!(values[0].integer_value.as_deref(), Some("1"));
+        assert!(values[1].null_value.is_some());
+
+        let back: HashMap<String, HashMap<String, Vec<Option<u16>>>> = from_value(&value).unwrap();
+        assert_eq!(back, outer);
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let value = to_value(&Bytes(vec![0, 1, 254, 255])).unwrap();
+        assert_eq!(value.bytes_value.as_deref(), Some("AAH+/w=="));
+        assert_eq!(from_value::<Bytes>(&value).unwrap(), Bytes(vec![0, 1, 254, 255]));
+
+        let invalid = Value { bytes_value: Some("not base64!".to_string()), ..Default::default() };
+        assert!(matches!(from_value::<Bytes>(&invalid), Err(Error::Conversion(_))));
+    }
+
+    #[test]
+    fn timestamps_and_references_as_strings() {
+        let timestamp = Value { timestamp_value: Some("2019-05-01T10:00:00Z".to_string()), ..Default::default() };
+        assert_eq!(from_value::<String>(&timestamp).unwrap(), "2019-05-01T10:00:00Z");
+
+        let name = "projects/p/databases/(default)/documents/users/alice";
+        let reference = Value { reference_value: Some(name.to_string()), ..Default::default() };
+        assert_eq!(from_value::<String>(&reference).unwrap(), name);
+        assert_eq!(from_value::<Option<String>>(&reference).unwrap().as_deref(), Some(name));
+    }
+
+    #[test]
+    fn integer_limits() {
+        let max = to_value(&(i64::MAX as u64)).unwrap();
+        assert_eq!(max.integer_value, Some(i64::MAX.to_string()));
+        assert_eq!(from_value::<u64>(&max).unwrap(), i64::MAX as u64);
+
+        match to_value(&(i64::MAX as u64 + 1)) {
+            Err(Error::Conversion(msg)) => assert!(msg.contains("9223372036854775808")),
+            other => panic!("expected a conversion error, got {:?}", other.map(|_| ())),
+        }
+        assert!(from_value::<u8>(&to_value(&256).unwrap()).is_err());
+    }
+
+    #[test]
+    fn non_map_document() {
+        assert!(matches!(to_document(&[1, 2]), Err(Error::Conversion(_))));
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use serde::ser::{self, Serialize};
+use crate::cmn::*;
+use crate::value::*;
+use super::NULL_VALUE;
+
+/// A `Serializer` producing Firestore `Value`s, see `to_value`.
+pub struct ValueSerializer;
+
+fn null() -> Value {
+    Value { null_value: Some(NULL_VALUE.to_string()), ..Default::default() }
+}
+
+fn integer(value: i64) -> Value {
+    Value { integer_value: Some(value.to_string()), ..Default::default() }
+}
+
+fn string(value: String) -> Value {
+    Value { string_value: Some(value), ..Default::default() }
+}
+
+fn array(values: Vec<Value>) -> Value {
+    Value { array_value: Some(ArrayValue { values: Some(values) }), ..Default::default() }
+}
+
+fn map(fields: HashMap<String, Value>) -> Value {
+    Value { map_value: Some(MapValue { fields: Some(fields) }), ..Default::default() }
+}
+
+fn variant(name: &str, value: Value) -> Value {
+    let mut fields = HashMap::with_capacity(1);
+    fields.insert(name.to_string(), value);
+    map(fields)
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeArray;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeMap;
+
+    fn serialize_bool(self, v: bool) -> Result<Value> {
+        Ok(Value { boolean_value: Some(v), ..Default::default() })
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value> {
+        Ok(integer(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value> {
+        Ok(integer(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value> {
+        Ok(integer(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value> {
+        Ok(integer(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value> {
+        Ok(integer(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value> {
+        Ok(integer(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value> {
+        Ok(integer(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value> {
+        if v > i64::MAX as u64 {
+            return Err(Error::Conversion(format!("{} does not fit into a 64 bit signed integer", v)))
+        }
+        Ok(integer(v as i64))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value> {
+        Ok(Value { double_value: Some(v), ..Default::default() })
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value> {
+        Ok(string(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value> {
+        Ok(string(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value> {
+        Ok(Value { bytes_value: Some(base64::encode(v)), ..Default::default() })
+    }
+
+    fn serialize_none(self) -> Result<Value> {
+        Ok(null())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value> {
+        Ok(null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+        Ok(null())
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Value> {
+        Ok(string(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _name: &'static str, _index: u32,
+                                                         variant: &'static str, value: &T) -> Result<Value> {
+        Ok(self::variant(variant, value.serialize(ValueSerializer)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray> {
+        Ok(SerializeArray {
+            variant: None,
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SerializeArray> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, _index: u32,
+                               variant: &'static str, len: usize) -> Result<SerializeArray> {
+        Ok(SerializeArray {
+            variant: Some(variant),
+            values: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap> {
+        Ok(SerializeMap {
+            variant: None,
+            fields: HashMap::with_capacity(len.unwrap_or(0)),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeMap> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, _index: u32,
+                                variant: &'static str, len: usize) -> Result<SerializeMap> {
+        Ok(SerializeMap {
+            variant: Some(variant),
+            fields: HashMap::with_capacity(len),
+            next_key: None,
+        })
+    }
+}
+
+/// Collects the elements of sequences, tuples and tuple variants into an `ArrayValue`.
+pub struct SerializeArray {
+    variant: Option<&'static str>,
+    values: Vec<Value>,
+}
+
+impl SerializeArray {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value> {
+        Ok(match self.variant {
+            Some(name) => variant(name, array(self.values)),
+            None => array(self.values),
+        })
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+/// Collects the entries of maps, structs and struct variants into a `MapValue`.
+pub struct SerializeMap {
+    variant: Option<&'static str>,
+    fields: HashMap<String, Value>,
+    next_key: Option<String>,
+}
+
+impl SerializeMap {
+    fn finish(self) -> Result<Value> {
+        Ok(match self.variant {
+            Some(name) => variant(name, map(self.fields)),
+            None => map(self.fields),
+        })
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        let key = key.serialize(ValueSerializer)?;
+        self.next_key = match (key.string_value, key.integer_value) {
+            (Some(key), _) | (None, Some(key)) => Some(key),
+            _ => return Err(Error::Conversion("map keys must be strings or integers".to_string())),
+        };
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let key = self.next_key.take()
+            .ok_or_else(|| Error::Conversion("map value serialized before its key".to_string()))?;
+        self.fields.insert(key, value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        self.fields.insert(key.to_string(), value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        self.fields.insert(key.to_string(), value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
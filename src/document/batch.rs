@@ -0,0 +1,70 @@
+use crate::cmn::*;
+use crate::document::*;
+use crate::transaction::*;
+
+/// The request for Firestore.BatchGetDocuments.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents batch get projects](struct.ProjectDatabaseDocumentBatchGetCall.html) (request)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct BatchGetDocumentsRequest {
+    /// Starts a new transaction and reads the documents.
+    /// Defaults to a read-only transaction.
+    /// The new transaction ID will be returned as the first response in the
+    /// stream.
+    #[serde(rename="newTransaction")]
+    pub new_transaction: Option<TransactionOptions>,
+    /// Reads documents in a transaction.
+    pub transaction: Option<String>,
+    /// The fields to return. If not set, returns all fields.
+    ///
+    /// If a document has a field that is not present in this mask, that field will
+    /// not be returned in the response.
+    pub mask: Option<DocumentMask>,
+    /// The names of the documents to retrieve. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    /// The request will fail if any of the document is not a child resource of the
+    /// given `database`. Duplicate names will be elided.
+    pub documents: Option<Vec<String>>,
+    /// Reads documents as they were at the given time.
+    /// This may not be older than 60 seconds.
+    #[serde(rename="readTime")]
+    pub read_time: Option<String>,
+}
+
+impl RequestValue for BatchGetDocumentsRequest {}
+
+/// The streamed response for Firestore.BatchGetDocuments.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents batch get projects](struct.ProjectDatabaseDocumentBatchGetCall.html) (response)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct BatchGetDocumentsResponse {
+    /// A document that was requested.
+    pub found: Option<Document>,
+    /// The transaction that was started as part of this request.
+    /// Will only be set in the first response, and only if
+    /// BatchGetDocumentsRequest.new_transaction was set in the request.
+    pub transaction: Option<String>,
+    /// A document name that was requested but does not exist. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub missing: Option<String>,
+    /// The time at which the document was read.
+    /// This may be monotically increasing, in this case the previous documents in
+    /// the result stream are guaranteed not to have changed between their
+    /// read_time and this one.
+    #[serde(rename="readTime")]
+    pub read_time: Option<String>,
+}
+
+impl ResponseResult for BatchGetDocumentsResponse {}
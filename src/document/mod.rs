@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
 mod fields;
+mod batch;
 pub use fields::*;
+pub use batch::*;
 pub use crate::value::*;
 
 /// A Firestore document.
@@ -72,3 +74,41 @@ impl std::cmp::PartialEq for Document {
         self.fields == other.fields
     }
 }
+
+/// A set of field paths on a document.
+/// Used to restrict a get or update operation on a document to a subset of its
+/// fields.
+/// This is different from standard field masks, as this is always scoped to a
+/// Document, and takes in account the dynamic nature of Value.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct DocumentMask {
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    #[serde(rename="fieldPaths")]
+    pub field_paths: Option<Vec<String>>,
+}
+
+impl Part for DocumentMask {}
+
+/// The response for Firestore.ListDocuments.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents list projects](struct.ProjectDatabaseDocumentListCall.html) (response)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct ListDocumentsResponse {
+    /// The next page token.
+    #[serde(rename="nextPageToken")]
+    pub next_page_token: Option<String>,
+    /// The Documents found.
+    pub documents: Option<Vec<Document>>,
+}
+
+impl ResponseResult for ListDocumentsResponse {}
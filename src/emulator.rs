@@ -0,0 +1,146 @@
+use std::env;
+use std::error::Error as StdError;
+use crate::cmn::*;
+use crate::firestore::*;
+use crate::transport::Transport;
+
+/// The environment variable holding the `host:port` of a running Firestore emulator.
+pub const EMULATOR_HOST_ENV: &str = "FIRESTORE_EMULATOR_HOST";
+
+/// An authenticator for the Firestore emulator, which accepts the fixed token
+/// `Bearer owner` and grants it access to all data, bypassing the security rules.
+///
+/// It never contacts an authorization server and must not be used with the
+/// production service.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmulatorAuth;
+
+impl oauth2::GetToken for EmulatorAuth {
+    fn token<'b, I, T>(&mut self, _scopes: I) -> std::result::Result<oauth2::Token, Box<dyn StdError>>
+        where T: AsRef<str> + Ord + 'b, I: IntoIterator<Item = &'b T> {
+        Ok(EmulatorAuth::owner_token())
+    }
+
+    fn api_key(&mut self) -> Option<String> {
+        None
+    }
+}
+
+impl EmulatorAuth {
+    /// The token of the `owner` user, which the emulator grants every permission.
+    pub(crate) fn owner_token() -> oauth2::Token {
+        oauth2::Token {
+            access_token: "owner".to_string(),
+            refresh_token: String::new(),
+            token_type: "Bearer".to_string(),
+            expires_in: None,
+            expires_in_timestamp: None,
+        }
+    }
+}
+
+/// The emulator host set in `FIRESTORE_EMULATOR_HOST`, if any.
+pub fn emulator_host() -> Option<String> {
+    env::var(EMULATOR_HOST_ENV).ok().filter(|host| !host.is_empty())
+}
+
+impl FirestoreBuilder {
+
+    /// Sends all requests over plain HTTP to the emulator at `host`, given as `host:port`.
+    ///
+    /// Use `build_emulator()` to create a hub authenticated the way the emulator expects.
+    pub fn emulator_host(mut self, host: &str) -> FirestoreBuilder {
+        let host = host.trim_end_matches('/');
+        let url = format!("http://{}/", host);
+        self.emulator_host = Some(host.to_string());
+        self.base_url(&url).root_url(&url)
+    }
+
+    /// Calls `emulator_host()` with the host set in `FIRESTORE_EMULATOR_HOST`, and leaves
+    /// the builder unchanged if it is not set, so `build_emulator()` fails.
+    pub fn emulator_host_from_env(self) -> FirestoreBuilder {
+        match emulator_host() {
+            Some(host) => self.emulator_host(&host),
+            None => self,
+        }
+    }
+
+    /// Creates a hub authenticated with `EmulatorAuth`.
+    ///
+    /// Fails with `Error::MissingEmulatorHost` unless an emulator host was set, so the
+    /// token of `EmulatorAuth` is never sent to the production service.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate hyper;
+    /// # extern crate google_firestore as firestore1;
+    /// # use firestore1::{FirestoreBuilder, Result};
+    /// # fn egal() -> Result<()> {
+    /// let hub = FirestoreBuilder::new()
+    ///     .project_id("demo-project")
+    ///     .emulator_host_from_env()
+    ///     .build_emulator(hyper::Client::new())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn build_emulator<C>(self, client: C) -> Result<Firestore<C, EmulatorAuth>>
+        where C: Transport {
+        if self.emulator_host.is_none() {
+            return Err(Error::MissingEmulatorHost)
+        }
+        self.build(client, EmulatorAuth)
+    }
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use hyper::header::{Authorization, Bearer};
+    use hyper::status::StatusCode;
+    use crate::transport::{HttpResponse, InMemoryTransport};
+    use super::*;
+
+    fn transport() -> InMemoryTransport {
+        InMemoryTransport::new(|_| Ok(HttpResponse::json(StatusCode::Ok, "{}")))
+    }
+
+    #[test]
+    fn no_emulator_host() {
+        match FirestoreBuilder::new().project_id("p").build_emulator(transport()) {
+            Err(Error::MissingEmulatorHost) => {},
+            other => panic!("unexpected result: {:?}", other.map(|_| ()).map_err(|e| e.to_string())),
+        }
+    }
+
+    #[test]
+    fn emulator_host_of_builder() {
+        let transport = transport();
+        let hub = FirestoreBuilder::new()
+            .emulator_host("localhost:8080/")
+            .build_emulator(transport.clone())
+            .unwrap();
+        assert_eq!(hub._base_url, "http://localhost:8080/");
+        hub.projects().databases_documents_get("projects/p/databases/(default)/documents/users/alice").doit().unwrap();
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.url.as_str(),
+                   "http://localhost:8080/v1/projects/p/databases/(default)/documents/users/alice?alt=json");
+        assert_eq!(request.headers.get::<Authorization<Bearer>>().map(|auth| auth.token.as_str()), Some("owner"));
+    }
+
+    // The only test changing the environment, so tests running in parallel see no changes.
+    #[test]
+    fn emulator_host_from_env() {
+        env::set_var(EMULATOR_HOST_ENV, "127.0.0.1:9090");
+        let hub = FirestoreBuilder::new().emulator_host_from_env().build_emulator(transport()).unwrap();
+        assert_eq!(hub._base_url, "http://127.0.0.1:9090/");
+
+        env::remove_var(EMULATOR_HOST_ENV);
+        assert_eq!(emulator_host(), None);
+        match FirestoreBuilder::new().emulator_host_from_env().build_emulator(transport()) {
+            Err(Error::MissingEmulatorHost) => {},
+            other => panic!("unexpected result: {:?}", other.map(|_| ()).map_err(|e| e.to_string())),
+        }
+    }
+}
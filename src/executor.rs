@@ -0,0 +1,117 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+use std::thread::sleep;
+use hyper::header::{Authorization, Bearer, ContentLength, ContentType, Headers, UserAgent};
+use serde::de::DeserializeOwned;
+use serde_json as json;
+use crate::cmn::*;
+use crate::firestore::*;
+use crate::method::CallRequest;
+use crate::status::FirestoreError;
+use crate::transport::*;
+
+impl<C, A> Firestore<C, A>
+    where C: Transport, A: oauth2::GetToken {
+
+    /// Sends the request of a call, retrying it as long as the delegate asks for it,
+    /// and returns the response once its status indicates success.
+    ///
+    /// The delegate is told the call `finished(false)` if it fails. Once the response
+    /// succeeded, the caller has to tell it how the call finished.
+    pub(crate) fn execute(&self, request: CallRequest, dlg: &mut dyn Delegate) -> Result<HttpResponse> {
+        let method = request.method;
+        dlg.begin(MethodInfo { id: method.id, http_method: method.http_method.clone() });
+        if let Err(err) = request.check_params() {
+            dlg.finished(false);
+            return Err(err);
+        }
+        let url = request.url(&self._base_url);
+
+        let mut scopes = request.scopes;
+        if scopes.is_empty() {
+            for scope in &self._scopes {
+                scopes.insert(scope.clone(), ());
+            }
+        }
+
+        loop {
+            let token = match self.auth.borrow_mut().token(scopes.keys()) {
+                Ok(token) => token,
+                Err(err) => {
+                    match dlg.token(&*err) {
+                        Some(token) => token,
+                        None => {
+                            dlg.finished(false);
+                            return Err(Error::MissingToken(err.to_string().into()))
+                        }
+                    }
+                }
+            };
+            let mut headers = Headers::new();
+            headers.set(UserAgent(self._user_agent.clone()));
+            headers.set(Authorization(Bearer { token: token.access_token }));
+            if let Some(ref body) = request.body {
+                headers.set(ContentType::json());
+                headers.set(ContentLength(body.len() as u64));
+            }
+            let http_request = HttpRequest {
+                method: method.http_method.clone(),
+                url: url.clone(),
+                headers,
+                body: request.body.clone().unwrap_or_default(),
+            };
+
+            dlg.pre_request();
+            match self.client.borrow_mut().send(http_request) {
+                Err(err) => {
+                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
+                        sleep(d);
+                        continue;
+                    }
+                    dlg.finished(false);
+                    return Err(Error::HttpError(err))
+                }
+                Ok(mut res) => {
+                    if !res.status.is_success() {
+                        let mut json_err = String::new();
+                        if let Err(err) = res.read_to_string(&mut json_err) {
+                            dlg.finished(false);
+                            return Err(Error::HttpError(hyper::Error::Io(err)))
+                        }
+                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
+                                                              json::from_str(&json_err).ok(),
+                                                              json::from_str::<ErrorResponse>(&json_err).ok().map(|r| r.error)) {
+                            sleep(d);
+                            continue;
+                        }
+                        dlg.finished(false);
+                        return Err(Error::Firestore(FirestoreError::from_response(res.status, &json_err)))
+                    }
+                    return Ok(res)
+                }
+            }
+        }
+    }
+
+    /// Sends the request of a call like `execute()`, and decodes the response.
+    pub(crate) fn execute_json<T>(&self, request: CallRequest, dlg: &mut dyn Delegate) -> Result<(HttpResponse, T)>
+        where T: DeserializeOwned {
+        let mut res = self.execute(request, dlg)?;
+        let mut json_response = String::new();
+        if let Err(err) = res.read_to_string(&mut json_response) {
+            dlg.finished(false);
+            return Err(Error::HttpError(hyper::Error::Io(err)))
+        }
+        match json::from_str(&json_response) {
+            Ok(decoded) => {
+                dlg.finished(true);
+                Ok((res, decoded))
+            },
+            Err(err) => {
+                dlg.response_json_decode_error(&json_response, &err);
+                dlg.finished(false);
+                Err(Error::JsonDecodeError(json_response, err))
+            }
+        }
+    }
+}
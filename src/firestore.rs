@@ -1,29 +1,50 @@
 use std::cell::RefCell;
-use std::borrow::BorrowMut;
-use crate::cmn::Hub;
+use std::fs;
+use std::io;
+use std::path::Path;
+use serde_json as json;
+use crate::cmn::{Error, Hub, Result};
+#[cfg(feature = "blocking")]
 use crate::ProjectMethods;
+use crate::Scope;
+use crate::path::DatabasePath;
 use std::mem;
+use crate::transport::Transport;
 
+/// The id of the database every project has.
+pub const DEFAULT_DATABASE_ID: &str = "(default)";
+
+pub(crate) const DEFAULT_USER_AGENT: &str = "google-api-rust-client/1.0.11";
+pub(crate) const DEFAULT_BASE_URL: &str = "https://firestore.googleapis.com/";
+pub(crate) const DEFAULT_ROOT_URL: &str = "https://firestore.googleapis.com/";
+
+#[cfg(feature = "blocking")]
 pub struct Firestore<C, A> {
     pub(crate) client: RefCell<C>,
     pub(crate) auth: RefCell<A>,
     pub(crate) _user_agent: String,
     pub(crate) _base_url: String,
     pub(crate) _root_url: String,
+    pub(crate) _database: Option<DatabasePath>,
+    pub(crate) _scopes: Vec<String>,
 }
 
+#[cfg(feature = "blocking")]
 impl<'a, C, A> Hub for Firestore<C, A> {}
 
+#[cfg(feature = "blocking")]
 impl<'a, C, A> Firestore<C, A>
-    where  C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+    where  C: Transport, A: oauth2::GetToken {
 
     pub fn new(client: C, authenticator: A) -> Firestore<C, A> {
         Firestore {
             client: RefCell::new(client),
             auth: RefCell::new(authenticator),
-            _user_agent: "google-api-rust-client/1.0.11".to_string(),
-            _base_url: "https://firestore.googleapis.com/".to_string(),
-            _root_url: "https://firestore.googleapis.com/".to_string(),
+            _user_agent: DEFAULT_USER_AGENT.to_string(),
+            _base_url: DEFAULT_BASE_URL.to_string(),
+            _root_url: DEFAULT_ROOT_URL.to_string(),
+            _database: None,
+            _scopes: vec![Scope::CloudPlatform.as_ref().to_string()],
         }
     }
 
@@ -54,4 +75,159 @@ impl<'a, C, A> Firestore<C, A>
     pub fn root_url(&mut self, new_root_url: String) -> String {
         mem::replace(&mut self._root_url, new_root_url)
     }
+
+    /// Set the database used by `collection()` and the references derived from it.
+    /// It is not set by default.
+    ///
+    /// Returns the previously set database.
+    pub fn database(&mut self, new_database: DatabasePath) -> Option<DatabasePath> {
+        self._database.replace(new_database)
+    }
+
+    /// The database set with `database()` or `FirestoreBuilder`, if any.
+    ///
+    /// It is accepted wherever a call takes the name of a database, and used by
+    /// `run_transaction_default()` and `WriteBatch::commit_default()`.
+    pub fn database_path(&self) -> Option<&DatabasePath> {
+        self._database.as_ref()
+    }
+
+    /// The database set with `database()` or `FirestoreBuilder`, or `Error::MissingDatabase`.
+    pub(crate) fn default_database(&self) -> Result<&DatabasePath> {
+        self._database.as_ref().ok_or(Error::MissingDatabase)
+    }
+}
+
+/// Configures and creates a `Firestore` hub, or an `asynchronous::Firestore` hub with
+/// `build_async()`.
+///
+/// Setting a project id makes the hub default to the database
+/// `projects/{project_id}/databases/{database_id}`, from which `collection()` and the
+/// references derived from it build the names of documents.
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate hyper;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// # use firestore1::{FirestoreBuilder, Scope};
+/// # use std::error::Error;
+/// # fn egal<A: oauth2::GetToken>(client: hyper::Client, auth: A) -> Result<(), Box<dyn Error>> {
+/// let hub = FirestoreBuilder::new()
+///     .service_account_key_file("service-account.json")?
+///     .database_id("my-database")
+///     .add_scope(Scope::Datastore)
+///     .build(client, auth)?;
+/// let users = hub.collection("users")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FirestoreBuilder {
+    pub(crate) project_id: Option<String>,
+    pub(crate) database_id: String,
+    pub(crate) user_agent: String,
+    pub(crate) base_url: String,
+    pub(crate) root_url: String,
+    pub(crate) scopes: Vec<String>,
+    /// The `host:port` of the emulator set with `emulator_host()`.
+    pub(crate) emulator_host: Option<String>,
+}
+
+impl Default for FirestoreBuilder {
+    fn default() -> FirestoreBuilder {
+        FirestoreBuilder {
+            project_id: None,
+            database_id: DEFAULT_DATABASE_ID.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            root_url: DEFAULT_ROOT_URL.to_string(),
+            scopes: Vec::new(),
+            emulator_host: None,
+        }
+    }
+}
+
+impl FirestoreBuilder {
+
+    pub fn new() -> FirestoreBuilder {
+        FirestoreBuilder::default()
+    }
+
+    /// The project of the default database. It is not set by default, in which case
+    /// the hub has no default database.
+    pub fn project_id(mut self, project_id: &str) -> FirestoreBuilder {
+        self.project_id = Some(project_id.to_string());
+        self
+    }
+
+    /// The id of the default database. It defaults to `(default)`.
+    pub fn database_id(mut self, database_id: &str) -> FirestoreBuilder {
+        self.database_id = database_id.to_string();
+        self
+    }
+
+    /// Takes the project id of the default database from a service account key,
+    /// unless the key has none.
+    pub fn service_account_key(mut self, key: &oauth2::ServiceAccountKey) -> FirestoreBuilder {
+        if let Some(ref project_id) = key.project_id {
+            self.project_id = Some(project_id.clone());
+        }
+        self
+    }
+
+    /// Takes the project id of the default database from the service account key
+    /// stored as JSON in the given file, as downloaded from the Google Cloud console.
+    pub fn service_account_key_file<P: AsRef<Path>>(self, path: P) -> io::Result<FirestoreBuilder> {
+        let key: oauth2::ServiceAccountKey = json::from_str(&fs::read_to_string(path)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Ok(self.service_account_key(&key))
+    }
+
+    /// The user-agent header field to use in all requests to the server.
+    /// It defaults to `google-api-rust-client/1.0.11`.
+    pub fn user_agent(mut self, agent_name: &str) -> FirestoreBuilder {
+        self.user_agent = agent_name.to_string();
+        self
+    }
+
+    /// The base url to use in all requests to the server.
+    /// It defaults to `https://firestore.googleapis.com/`.
+    pub fn base_url(mut self, base_url: &str) -> FirestoreBuilder {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    /// The root url to use in all requests to the server.
+    /// It defaults to `https://firestore.googleapis.com/`.
+    pub fn root_url(mut self, root_url: &str) -> FirestoreBuilder {
+        self.root_url = root_url.to_string();
+        self
+    }
+
+    /// Adds a scope to request for all calls which do not set their own scopes.
+    ///
+    /// If no scope is added, `Scope::CloudPlatform` is used.
+    pub fn add_scope<T: AsRef<str>>(mut self, scope: T) -> FirestoreBuilder {
+        self.scopes.push(scope.as_ref().to_string());
+        self
+    }
+
+    /// Creates the hub. It fails if the project or database id are not valid.
+    #[cfg(feature = "blocking")]
+    pub fn build<C, A>(self, client: C, authenticator: A) -> Result<Firestore<C, A>>
+        where C: Transport, A: oauth2::GetToken {
+        let mut hub = Firestore::new(client, authenticator);
+        if let Some(ref project_id) = self.project_id {
+            hub._database = Some(DatabasePath::new(project_id, &self.database_id)?);
+        }
+        hub._user_agent = self.user_agent;
+        hub._base_url = self.base_url;
+        hub._root_url = self.root_url;
+        if !self.scopes.is_empty() {
+            hub._scopes = self.scopes;
+        }
+        Ok(hub)
+    }
 }
@@ -1,41 +1,6 @@
 // ############
 // SCHEMAS ###
 // ##########
-/// The response for Firestore.Listen.
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents listen projects](struct.ProjectDatabaseDocumentListenCall.html) (response)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct ListenResponse {
-    /// A filter to apply to the set of documents previously returned for the
-    /// given target.
-    ///
-    /// Returned when documents may have been removed from the given target, but
-    /// the exact documents are unknown.
-    pub filter: Option<ExistenceFilter>,
-    /// A Document has been deleted.
-    #[serde(rename="documentDelete")]
-    pub document_delete: Option<DocumentDelete>,
-    /// A Document has changed.
-    #[serde(rename="documentChange")]
-    pub document_change: Option<DocumentChange>,
-    /// A Document has been removed from a target (because it is no longer
-    /// relevant to that target).
-    #[serde(rename="documentRemove")]
-    pub document_remove: Option<DocumentRemove>,
-    /// Targets have changed.
-    #[serde(rename="targetChange")]
-    pub target_change: Option<TargetChange>,
-}
-
-impl ResponseResult for ListenResponse {}
-
-
 /// Cloud Firestore indexes enable simple and complex queries against
 /// documents in a database.
 ///
@@ -87,59 +52,6 @@ impl ResponseResult for GoogleFirestoreAdminV1Index {}
 
 
 
-/// The streamed response for Firestore.BatchGetDocuments.
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents batch get projects](struct.ProjectDatabaseDocumentBatchGetCall.html) (response)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct BatchGetDocumentsResponse {
-    /// A document that was requested.
-    pub found: Option<Document>,
-    /// The transaction that was started as part of this request.
-    /// Will only be set in the first response, and only if
-    /// BatchGetDocumentsRequest.new_transaction was set in the request.
-    pub transaction: Option<String>,
-    /// A document name that was requested but does not exist. In the format:
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    pub missing: Option<String>,
-    /// The time at which the document was read.
-    /// This may be monotically increasing, in this case the previous documents in
-    /// the result stream are guaranteed not to have changed between their
-    /// read_time and this one.
-    #[serde(rename="readTime")]
-    pub read_time: Option<String>,
-}
-
-impl ResponseResult for BatchGetDocumentsResponse {}
-
-
-
-
-
-/// A set of field paths on a document.
-/// Used to restrict a get or update operation on a document to a subset of its
-/// fields.
-/// This is different from standard field masks, as this is always scoped to a
-/// Document, and takes in account the dynamic nature of Value.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct DocumentMask {
-    /// The list of field paths in the mask. See Document.fields for a field
-    /// path syntax reference.
-    #[serde(rename="fieldPaths")]
-    pub field_paths: Option<Vec<String>>,
-}
-
-impl Part for DocumentMask {}
-
-
 /// The request for Firestore.ListCollectionIds.
 ///
 /// # Activities
@@ -213,22 +125,6 @@ pub struct WriteResponse {
 impl ResponseResult for WriteResponse {}
 
 
-/// A target specified by a set of documents names.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct DocumentsTarget {
-    /// The names of the documents to retrieve. In the format:
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    /// The request will fail if any of the document is not a child resource of
-    /// the given `database`. Duplicate names will be elided.
-    pub documents: Option<Vec<String>>,
-}
-
-impl Part for DocumentsTarget {}
-
-
 /// The response from Firestore.ListCollectionIds.
 ///
 /// # Activities
@@ -250,87 +146,6 @@ pub struct ListCollectionIdsResponse {
 
 impl ResponseResult for ListCollectionIdsResponse {}
 
-/// A transformation of a field of the document.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct FieldTransform {
-    /// The path of the field. See Document.fields for the field path syntax
-    /// reference.
-    #[serde(rename="fieldPath")]
-    pub field_path: Option<String>,
-    /// Sets the field to the minimum of its current value and the given value.
-    ///
-    /// This must be an integer or a double value.
-    /// If the field is not an integer or double, or if the field does not yet
-    /// exist, the transformation will set the field to the input value.
-    /// If a minimum operation is applied where the field and the input value
-    /// are of mixed types (that is - one is an integer and one is a double)
-    /// the field takes on the type of the smaller operand. If the operands are
-    /// equivalent (e.g. 3 and 3.0), the field does not change.
-    /// 0, 0.0, and -0.0 are all zero. The minimum of a zero stored value and
-    /// zero input value is always the stored value.
-    /// The minimum of any numeric value x and NaN is NaN.
-    pub minimum: Option<Value>,
-    /// Append the given elements in order if they are not already present in
-    /// the current field value.
-    /// If the field is not an array, or if the field does not yet exist, it is
-    /// first set to the empty array.
-    ///
-    /// Equivalent numbers of different types (e.g. 3L and 3.0) are
-    /// considered equal when checking if a value is missing.
-    /// NaN is equal to NaN, and Null is equal to Null.
-    /// If the input contains multiple equivalent values, only the first will
-    /// be considered.
-    ///
-    /// The corresponding transform_result will be the null value.
-    #[serde(rename="appendMissingElements")]
-    pub append_missing_elements: Option<ArrayValue>,
-    /// Adds the given value to the field's current value.
-    ///
-    /// This must be an integer or a double value.
-    /// If the field is not an integer or double, or if the field does not yet
-    /// exist, the transformation will set the field to the given value.
-    /// If either of the given value or the current field value are doubles,
-    /// both values will be interpreted as doubles. Double arithmetic and
-    /// representation of double values follow IEEE 754 semantics.
-    /// If there is positive/negative integer overflow, the field is resolved
-    /// to the largest magnitude positive/negative integer.
-    pub increment: Option<Value>,
-    /// Sets the field to the maximum of its current value and the given value.
-    ///
-    /// This must be an integer or a double value.
-    /// If the field is not an integer or double, or if the field does not yet
-    /// exist, the transformation will set the field to the given value.
-    /// If a maximum operation is applied where the field and the input value
-    /// are of mixed types (that is - one is an integer and one is a double)
-    /// the field takes on the type of the larger operand. If the operands are
-    /// equivalent (e.g. 3 and 3.0), the field does not change.
-    /// 0, 0.0, and -0.0 are all zero. The maximum of a zero stored value and
-    /// zero input value is always the stored value.
-    /// The maximum of any numeric value x and NaN is NaN.
-    pub maximum: Option<Value>,
-    /// Remove all of the given elements from the array in the field.
-    /// If the field is not an array, or if the field does not yet exist, it is
-    /// set to the empty array.
-    ///
-    /// Equivalent numbers of the different types (e.g. 3L and 3.0) are
-    /// considered equal when deciding whether an element should be removed.
-    /// NaN is equal to NaN, and Null is equal to Null.
-    /// This will remove all equivalent values if there are duplicates.
-    ///
-    /// The corresponding transform_result will be the null value.
-    #[serde(rename="removeAllFromArray")]
-    pub remove_all_from_array: Option<ArrayValue>,
-    /// Sets the field to the given server value.
-    #[serde(rename="setToServerValue")]
-    pub set_to_server_value: Option<String>,
-}
-
-impl Part for FieldTransform {}
-
-
 /// The request for FirestoreAdmin.ExportDocuments.
 ///
 /// # Activities
@@ -362,176 +177,6 @@ impl RequestValue for GoogleFirestoreAdminV1ExportDocumentsRequest {}
 
 
 
-/// A precondition on a document, used for conditional operations.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct Precondition {
-    /// When set, the target document must exist and have been last updated at
-    /// that time.
-    #[serde(rename="updateTime")]
-    pub update_time: Option<String>,
-    /// When set to `true`, the target document must exist.
-    /// When set to `false`, the target document must not exist.
-    pub exists: Option<bool>,
-}
-
-impl Part for Precondition {}
-
-
-
-
-/// A Document has changed.
-///
-/// May be the result of multiple writes, including deletes, that
-/// ultimately resulted in a new value for the Document.
-///
-/// Multiple DocumentChange messages may be returned for the same logical
-/// change, if multiple targets are affected.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct DocumentChange {
-    /// A set of target IDs for targets that no longer match this document.
-    #[serde(rename="removedTargetIds")]
-    pub removed_target_ids: Option<Vec<i32>>,
-    /// The new state of the Document.
-    ///
-    /// If `mask` is set, contains only fields that were updated or added.
-    pub document: Option<Document>,
-    /// A set of target IDs of targets that match this document.
-    #[serde(rename="targetIds")]
-    pub target_ids: Option<Vec<i32>>,
-}
-
-impl Part for DocumentChange {}
-
-
-/// A Document has been removed from the view of the targets.
-///
-/// Sent if the document is no longer relevant to a target and is out of view.
-/// Can be sent instead of a DocumentDelete or a DocumentChange if the server
-/// can not send the new value of the document.
-///
-/// Multiple DocumentRemove messages may be returned for the same logical
-/// write or delete, if multiple targets are affected.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct DocumentRemove {
-    /// The resource name of the Document that has gone out of view.
-    pub document: Option<String>,
-    /// A set of target IDs for targets that previously matched this document.
-    #[serde(rename="removedTargetIds")]
-    pub removed_target_ids: Option<Vec<i32>>,
-    /// The read timestamp at which the remove was observed.
-    ///
-    /// Greater or equal to the `commit_time` of the change/delete/remove.
-    #[serde(rename="readTime")]
-    pub read_time: Option<String>,
-}
-
-impl Part for DocumentRemove {}
-
-
-/// The request for Firestore.Rollback.
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents rollback projects](struct.ProjectDatabaseDocumentRollbackCall.html) (request)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct RollbackRequest {
-    /// The transaction to roll back.
-    pub transaction: Option<String>,
-}
-
-impl RequestValue for RollbackRequest {}
-
-
-
-
-/// A transformation of a document.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct DocumentTransform {
-    /// The name of the document to transform.
-    pub document: Option<String>,
-    /// The list of transformations to apply to the fields of the document, in
-    /// order.
-    /// This must not be empty.
-    #[serde(rename="fieldTransforms")]
-    pub field_transforms: Option<Vec<FieldTransform>>,
-}
-
-impl Part for DocumentTransform {}
-
-
-
-/// The response for Firestore.BeginTransaction.
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents begin transaction projects](struct.ProjectDatabaseDocumentBeginTransactionCall.html) (response)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct BeginTransactionResponse {
-    /// The transaction that was started.
-    pub transaction: Option<String>,
-}
-
-impl ResponseResult for BeginTransactionResponse {}
-
-
-/// A write on a document.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct Write {
-    /// A document name to delete. In the format:
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    pub delete: Option<String>,
-    /// An optional precondition on the document.
-    ///
-    /// The write will fail if this is set and not met by the target document.
-    #[serde(rename="currentDocument")]
-    pub current_document: Option<Precondition>,
-    /// The fields to update in this write.
-    ///
-    /// This field can be set only when the operation is `update`.
-    /// If the mask is not set for an `update` and the document exists, any
-    /// existing data will be overwritten.
-    /// If the mask is set and the document on the server has fields not covered by
-    /// the mask, they are left unchanged.
-    /// Fields referenced in the mask, but not present in the input document, are
-    /// deleted from the document on the server.
-    /// The field paths in this mask must not contain a reserved field name.
-    #[serde(rename="updateMask")]
-    pub update_mask: Option<DocumentMask>,
-    /// Applies a transformation to a document.
-    /// At most one `transform` per document is allowed in a given request.
-    /// An `update` cannot follow a `transform` on the same document in a given
-    /// request.
-    pub transform: Option<DocumentTransform>,
-    /// A document to write.
-    pub update: Option<Document>,
-}
-
-impl Part for Write {}
-
-
 /// The response message for Locations.ListLocations.
 ///
 /// # Activities
@@ -611,78 +256,6 @@ pub struct GoogleFirestoreAdminV1IndexField {
 impl Part for GoogleFirestoreAdminV1IndexField {}
 
 
-/// The response for Firestore.Commit.
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents commit projects](struct.ProjectDatabaseDocumentCommitCall.html) (response)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct CommitResponse {
-    /// The result of applying the writes.
-    ///
-    /// This i-th write result corresponds to the i-th write in the
-    /// request.
-    #[serde(rename="writeResults")]
-    pub write_results: Option<Vec<WriteResult>>,
-    /// The time at which the commit occurred.
-    #[serde(rename="commitTime")]
-    pub commit_time: Option<String>,
-}
-
-impl ResponseResult for CommitResponse {}
-
-
-/// A request for Firestore.Listen
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents listen projects](struct.ProjectDatabaseDocumentListenCall.html) (request)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct ListenRequest {
-    /// A target to add to this stream.
-    #[serde(rename="addTarget")]
-    pub add_target: Option<Target>,
-    /// Labels associated with this target change.
-    pub labels: Option<HashMap<String, String>>,
-    /// The ID of a target to remove from this stream.
-    #[serde(rename="removeTarget")]
-    pub remove_target: Option<i32>,
-}
-
-impl RequestValue for ListenRequest {}
-
-
-
-/// The result of applying a write.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct WriteResult {
-    /// The last update time of the document after applying the write. Not set
-    /// after a `delete`.
-    ///
-    /// If the write did not actually change the document, this will be the
-    /// previous update_time.
-    #[serde(rename="updateTime")]
-    pub update_time: Option<String>,
-    /// The results of applying each DocumentTransform.FieldTransform, in the
-    /// same order.
-    #[serde(rename="transformResults")]
-    pub transform_results: Option<Vec<Value>>,
-}
-
-impl Part for WriteResult {}
-
-
 /// The response for FirestoreAdmin.ListIndexes.
 ///
 /// # Activities
@@ -707,170 +280,6 @@ impl ResponseResult for GoogleFirestoreAdminV1ListIndexesResponse {}
 
 
 
-/// A digest of all the documents that match a given target.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct ExistenceFilter {
-    /// The total count of documents that match target_id.
-    ///
-    /// If different from the count of documents in the client that match, the
-    /// client must manually determine which documents no longer match the target.
-    pub count: Option<i32>,
-    /// The target ID to which this filter applies.
-    #[serde(rename="targetId")]
-    pub target_id: Option<i32>,
-}
-
-impl Part for ExistenceFilter {}
-
-
-/// The request for Firestore.BatchGetDocuments.
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents batch get projects](struct.ProjectDatabaseDocumentBatchGetCall.html) (request)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct BatchGetDocumentsRequest {
-    /// Starts a new transaction and reads the documents.
-    /// Defaults to a read-only transaction.
-    /// The new transaction ID will be returned as the first response in the
-    /// stream.
-    #[serde(rename="newTransaction")]
-    pub new_transaction: Option<TransactionOptions>,
-    /// Reads documents in a transaction.
-    pub transaction: Option<String>,
-    /// The fields to return. If not set, returns all fields.
-    ///
-    /// If a document has a field that is not present in this mask, that field will
-    /// not be returned in the response.
-    pub mask: Option<DocumentMask>,
-    /// The names of the documents to retrieve. In the format:
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    /// The request will fail if any of the document is not a child resource of the
-    /// given `database`. Duplicate names will be elided.
-    pub documents: Option<Vec<String>>,
-    /// Reads documents as they were at the given time.
-    /// This may not be older than 60 seconds.
-    #[serde(rename="readTime")]
-    pub read_time: Option<String>,
-}
-
-impl RequestValue for BatchGetDocumentsRequest {}
-
-
-/// The `Status` type defines a logical error model that is suitable for
-/// different programming environments, including REST APIs and RPC APIs. It is
-/// used by [gRPC](https://github.com/grpc). The error model is designed to be:
-///
-/// * Simple to use and understand for most users
-/// * Flexible enough to meet unexpected needs
-///
-/// # Overview
-///
-/// The `Status` message contains three pieces of data: error code, error
-/// message, and error details. The error code should be an enum value of
-/// google.rpc.Code, but it may accept additional error codes if needed.  The
-/// error message should be a developer-facing English message that helps
-/// developers *understand* and *resolve* the error. If a localized user-facing
-/// error message is needed, put the localized message in the error details or
-/// localize it in the client. The optional error details may contain arbitrary
-/// information about the error. There is a predefined set of error detail types
-/// in the package `google.rpc` that can be used for common error conditions.
-///
-/// # Language mapping
-///
-/// The `Status` message is the logical representation of the error model, but it
-/// is not necessarily the actual wire format. When the `Status` message is
-/// exposed in different client libraries and different wire protocols, it can be
-/// mapped differently. For example, it will likely be mapped to some exceptions
-/// in Java, but more likely mapped to some error codes in C.
-///
-/// # Other uses
-///
-/// The error model and the `Status` message can be used in a variety of
-/// environments, either with or without APIs, to provide a
-/// consistent developer experience across different environments.
-///
-/// Example uses of this error model include:
-///
-/// * Partial errors. If a service needs to return partial errors to the client,
-///   it may embed the `Status` in the normal response to indicate the partial
-///   errors.
-///
-/// * Workflow errors. A typical workflow has multiple steps. Each step may
-///   have a `Status` message for error reporting.
-///
-/// * Batch operations. If a client uses batch request and batch response, the
-///   `Status` message should be used directly inside batch response, one for
-///   each error sub-response.
-///
-/// * Asynchronous operations. If an API call embeds asynchronous operation
-///   results in its response, the status of those operations should be
-///   represented directly using the `Status` message.
-///
-/// * Logging. If some API errors are stored in logs, the message `Status` could
-///   be used directly after any stripping needed for security/privacy reasons.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct Status {
-    /// A developer-facing error message, which should be in English. Any
-    /// user-facing error message should be localized and sent in the
-    /// google.rpc.Status.details field, or localized by the client.
-    pub message: Option<String>,
-    /// The status code, which should be an enum value of google.rpc.Code.
-    pub code: Option<i32>,
-    /// A list of messages that carry the error details.  There is a common set of
-    /// message types for APIs to use.
-    pub details: Option<Vec<HashMap<String, String>>>,
-}
-
-impl Part for Status {}
-
-
-/// A specification of a set of documents to listen to.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct Target {
-    /// A target specified by a set of document names.
-    pub documents: Option<DocumentsTarget>,
-    /// If the target should be removed once it is current and consistent.
-    pub once: Option<bool>,
-    /// A target specified by a query.
-    pub query: Option<QueryTarget>,
-    /// A resume token from a prior TargetChange for an identical target.
-    ///
-    /// Using a resume token with a different target is unsupported and may fail.
-    #[serde(rename="resumeToken")]
-    pub resume_token: Option<String>,
-    /// A client provided target ID.
-    ///
-    /// If not set, the server will assign an ID for the target.
-    ///
-    /// Used for resuming a target without changing IDs. The IDs can either be
-    /// client-assigned or be server-assigned in a previous stream. All targets
-    /// with client provided IDs must be added before adding a target that needs
-    /// a server-assigned id.
-    #[serde(rename="targetId")]
-    pub target_id: Option<i32>,
-    /// Start listening after a specific `read_time`.
-    ///
-    /// The client must know the state of matching documents at this time.
-    #[serde(rename="readTime")]
-    pub read_time: Option<String>,
-}
-
-impl Part for Target {}
-
-
 /// Represents a single field in the database.
 ///
 /// Fields are grouped by their "Collection Group", which represent all
@@ -929,51 +338,6 @@ impl ResponseResult for GoogleFirestoreAdminV1Field {}
 
 
 
-/// Targets being watched have changed.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct TargetChange {
-    /// The type of change that occurred.
-    #[serde(rename="targetChangeType")]
-    pub target_change_type: Option<String>,
-    /// A token that can be used to resume the stream for the given `target_ids`,
-    /// or all targets if `target_ids` is empty.
-    ///
-    /// Not set on every target change.
-    #[serde(rename="resumeToken")]
-    pub resume_token: Option<String>,
-    /// The error that resulted in this change, if applicable.
-    pub cause: Option<Status>,
-    /// The target IDs of targets that have changed.
-    ///
-    /// If empty, the change applies to all targets.
-    ///
-    /// For `target_change_type=ADD`, the order of the target IDs matches the order
-    /// of the requests to add the targets. This allows clients to unambiguously
-    /// associate server-assigned target IDs with added targets.
-    ///
-    /// For other states, the order of the target IDs is not defined.
-    #[serde(rename="targetIds")]
-    pub target_ids: Option<Vec<i32>>,
-    /// The consistent `read_time` for the given `target_ids` (omitted when the
-    /// target_ids are not at a consistent snapshot).
-    ///
-    /// The stream is guaranteed to send a `read_time` with `target_ids` empty
-    /// whenever the entire stream reaches a new consistent snapshot. ADD,
-    /// CURRENT, and RESET messages are guaranteed to (eventually) result in a
-    /// new consistent snapshot (while NO_CHANGE and REMOVE messages are not).
-    ///
-    /// For a given stream, `read_time` is guaranteed to be monotonically
-    /// increasing.
-    #[serde(rename="readTime")]
-    pub read_time: Option<String>,
-}
-
-impl Part for TargetChange {}
-
-
 /// The request for FirestoreAdmin.ImportDocuments.
 ///
 /// # Activities
@@ -1023,30 +387,6 @@ pub struct GoogleFirestoreAdminV1ListFieldsResponse {
 impl ResponseResult for GoogleFirestoreAdminV1ListFieldsResponse {}
 
 
-/// The request for Firestore.Commit.
-///
-/// # Activities
-///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
-///
-/// * [databases documents commit projects](struct.ProjectDatabaseDocumentCommitCall.html) (request)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct CommitRequest {
-    /// The writes to apply.
-    ///
-    /// Always executed atomically and in order.
-    pub writes: Option<Vec<Write>>,
-    /// If set, applies all writes in this transaction, and commits it.
-    pub transaction: Option<String>,
-}
-
-impl RequestValue for CommitRequest {}
-
-
-
-
 /// This resource represents a long-running operation that is the result of a
 /// network API call.
 ///
@@ -1124,79 +464,12 @@ pub struct GoogleFirestoreAdminV1IndexConfig {
 impl Part for GoogleFirestoreAdminV1IndexConfig {}
 
 
-/// The request for Firestore.BeginTransaction.
-///
-/// # Activities
+/// The request for Firestore.Write.
 ///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+/// The first request creates a stream, or resumes an existing one from a token.
 ///
-/// * [databases documents begin transaction projects](struct.ProjectDatabaseDocumentBeginTransactionCall.html) (request)
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct BeginTransactionRequest {
-    /// The options for the transaction.
-    /// Defaults to a read-write transaction.
-    pub options: Option<TransactionOptions>,
-}
-
-impl RequestValue for BeginTransactionRequest {}
-
-
-/// A Document has been deleted.
-///
-/// May be the result of multiple writes, including updates, the
-/// last of which deleted the Document.
-///
-/// Multiple DocumentDelete messages may be returned for the same logical
-/// delete, if multiple targets are affected.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct DocumentDelete {
-    /// The resource name of the Document that was deleted.
-    pub document: Option<String>,
-    /// A set of target IDs for targets that previously matched this entity.
-    #[serde(rename="removedTargetIds")]
-    pub removed_target_ids: Option<Vec<i32>>,
-    /// The read timestamp at which the delete was observed.
-    ///
-    /// Greater or equal to the `commit_time` of the delete.
-    #[serde(rename="readTime")]
-    pub read_time: Option<String>,
-}
-
-impl Part for DocumentDelete {}
-
-
-/// A target specified by a query.
-///
-/// This type is not used in any activity, and only used as *part* of another schema.
-///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct QueryTarget {
-    /// A structured query.
-    #[serde(rename="structuredQuery")]
-    pub structured_query: Option<StructuredQuery>,
-    /// The parent resource name. In the format:
-    /// `projects/{project_id}/databases/{database_id}/documents` or
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    /// For example:
-    /// `projects/my-project/databases/my-database/documents` or
-    /// `projects/my-project/databases/my-database/documents/chatrooms/my-chatroom`
-    pub parent: Option<String>,
-}
-
-impl Part for QueryTarget {}
-
-
-/// The request for Firestore.Write.
-///
-/// The first request creates a stream, or resumes an existing one from a token.
-///
-/// When creating a new stream, the server replies with a response containing
-/// only an ID and a token, to use in the next request.
+/// When creating a new stream, the server replies with a response containing
+/// only an ID and a token, to use in the next request.
 ///
 /// When resuming a stream, the server first streams any responses later than the
 /// given token, then a response containing only an up-to-date token, to use in
@@ -1280,69 +553,6 @@ pub struct ProjectMethods<'a, C, A>
 impl<'a, C, A> MethodsBuilder for ProjectMethods<'a, C, A> {}
 
 impl<'a, C, A> ProjectMethods<'a, C, A> {
-/// Create a builder to help you perform the following task:
-    ///
-    /// Listens to changes.
-    ///
-    /// # Arguments
-    ///
-    /// * `request` - No description provided.
-    /// * `database` - The database name. In the format:
-    ///                `projects/{project_id}/databases/{database_id}`.
-    /*
-    pub fn databases_documents_listen(&self, request: ListenRequest, database: &str) -> ProjectDatabaseDocumentListenCall<'a, C, A> {
-        ProjectDatabaseDocumentListenCall {
-            hub: self.hub,
-            _request: request,
-            _database: database.to_string(),
-            _delegate: Default::default(),
-            _scopes: Default::default(),
-            _additional_params: Default::default(),
-        }
-    }
-    */
-/// Create a builder to help you perform the following task:
-    ///
-    /// Rolls back a transaction.
-    ///
-    /// # Arguments
-    ///
-    /// * `request` - No description provided.
-    /// * `database` - The database name. In the format:
-    ///                `projects/{project_id}/databases/{database_id}`.
-    /*
-    pub fn databases_documents_rollback(&self, request: RollbackRequest, database: &str) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
-        ProjectDatabaseDocumentRollbackCall {
-            hub: self.hub,
-            _request: request,
-            _database: database.to_string(),
-            _delegate: Default::default(),
-            _scopes: Default::default(),
-            _additional_params: Default::default(),
-        }
-    }
-    */
-/// Create a builder to help you perform the following task:
-    ///
-    /// Commits a transaction, while optionally updating documents.
-    ///
-    /// # Arguments
-    ///
-    /// * `request` - No description provided.
-    /// * `database` - The database name. In the format:
-    ///                `projects/{project_id}/databases/{database_id}`.
-    /*
-    pub fn databases_documents_commit(&self, request: CommitRequest, database: &str) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
-        ProjectDatabaseDocumentCommitCall {
-            hub: self.hub,
-            _request: request,
-            _database: database.to_string(),
-            _delegate: Default::default(),
-            _scopes: Default::default(),
-            _additional_params: Default::default(),
-        }
-    }
-    */
 /// Create a builder to help you perform the following task:
     ///
     /// Gets information about a location.
@@ -1471,51 +681,6 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
             _additional_params: Default::default(),
         }
     }
-/// Create a builder to help you perform the following task:
-    ///
-    /// Gets multiple documents.
-    ///
-    /// Documents returned by this method are not guaranteed to be returned in the
-    /// same order that they were requested.
-    ///
-    /// # Arguments
-    ///
-    /// * `request` - No description provided.
-    /// * `database` - The database name. In the format:
-    ///                `projects/{project_id}/databases/{database_id}`.
-    /*
-    pub fn databases_documents_batch_get(&self, request: BatchGetDocumentsRequest, database: &str) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
-        ProjectDatabaseDocumentBatchGetCall {
-            hub: self.hub,
-            _request: request,
-            _database: database.to_string(),
-            _delegate: Default::default(),
-            _scopes: Default::default(),
-            _additional_params: Default::default(),
-        }
-    }
-    */
-/// Create a builder to help you perform the following task:
-    ///
-    /// Starts a new transaction.
-    ///
-    /// # Arguments
-    ///
-    /// * `request` - No description provided.
-    /// * `database` - The database name. In the format:
-    ///                `projects/{project_id}/databases/{database_id}`.
-    /*
-    pub fn databases_documents_begin_transaction(&self, request: BeginTransactionRequest, database: &str) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
-        ProjectDatabaseDocumentBeginTransactionCall {
-            hub: self.hub,
-            _request: request,
-            _database: database.to_string(),
-            _delegate: Default::default(),
-            _scopes: Default::default(),
-            _additional_params: Default::default(),
-        }
-    }
-    */
 /// Create a builder to help you perform the following task:
     ///
     /// Lists all the collection IDs underneath a document.
@@ -1850,31 +1015,6 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
         }
     }
     */
-/// Create a builder to help you perform the following task:
-    ///
-    /// Updates or inserts a document.
-    ///
-    /// # Arguments
-    ///
-    /// * `request` - No description provided.
-    /// * `name` - The resource name of the document, for example
-    ///            `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    /*
-    pub fn databases_documents_patch(&self, request: Document, name: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
-        ProjectDatabaseDocumentPatchCall {
-            hub: self.hub,
-            _request: request,
-            _name: name.to_string(),
-            _update_mask_field_paths: Default::default(),
-            _mask_field_paths: Default::default(),
-            _current_document_update_time: Default::default(),
-            _current_document_exists: Default::default(),
-            _delegate: Default::default(),
-            _scopes: Default::default(),
-            _additional_params: Default::default(),
-        }
-    }
-    */
 /// Create a builder to help you perform the following task:
     ///
     /// Lists operations that match the specified filter in the request. If the
@@ -1911,9 +1051,9 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
 // CallBuilders   ###
 // #################
 
-/// Listens to changes.
+/// Gets information about a location.
 ///
-/// A builder for the *databases.documents.listen* method supported by a *project* resource.
+/// A builder for the *locations.get* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -1925,7 +1065,6 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::ListenRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -1936,1666 +1075,30 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = ListenRequest::default();
-///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_listen(req, "database")
+/// let result = hub.projects().locations_get("name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentListenCall<'a, C, A>
+pub struct ProjectLocationGetCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: ListenRequest,
-    _database: String,
+    _name: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentListenCall<'a, C, A> {}
-
-impl<'a, C, A> ProjectDatabaseDocumentListenCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
-
-
-    /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, ListenResponse)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
-        let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
-            Some(d) => d,
-            None => &mut dd
-        };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.listen",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("database", self._database.to_string()));
-        for &field in ["alt", "database"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+database}/documents:listen";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+database}", "database")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["database"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
-        }
-    }
-
-
-    ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: ListenRequest) -> ProjectDatabaseDocumentListenCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
-    /// The database name. In the format:
-    /// `projects/{project_id}/databases/{database_id}`.
-    ///
-    /// Sets the *database* path property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn database(mut self, new_value: &str) -> ProjectDatabaseDocumentListenCall<'a, C, A> {
-        self._database = new_value.to_string();
-        self
-    }
-    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
-    /// while executing the actual API request.
-    ///
-    /// It should be used to handle progress information, and to implement a certain level of resilience.
-    ///
-    /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentListenCall<'a, C, A> {
-        self._delegate = Some(new_value);
-        self
-    }
-
-    /// Set any additional parameter of the query string used in the request.
-    /// It should be used to set parameters which are not yet available through their own
-    /// setters.
-    ///
-    /// Please note that this method must not be used to set any of the known parameters
-    /// which have their own setter method. If done anyway, the request will fail.
-    ///
-    /// # Additional Parameters
-    ///
-    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
-    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
-    /// * *access_token* (query-string) - OAuth access token.
-    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
-    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
-    /// * *callback* (query-string) - JSONP
-    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
-    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
-    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
-    /// * *alt* (query-string) - Data format for response.
-    /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentListenCall<'a, C, A>
-                                                        where T: AsRef<str> {
-        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
-        self
-    }
-
-    /// Identifies the authorization scope for the method you are building.
-    ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
-    ///
-    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
-    /// tokens for more than one scope.
-    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
-    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
-    /// function for details).
-    ///
-    /// Usually there is more than one suitable scope to authorize an operation, some of which may
-    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
-    /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentListenCall<'a, C, A>
-                                                        where T: Into<Option<S>>,
-                                                              S: AsRef<str> {
-        match scope.into() {
-          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
-          None => None,
-        };
-        self
-    }
-}
-
-
-/// Rolls back a transaction.
-///
-/// A builder for the *databases.documents.rollback* method supported by a *project* resource.
-/// It is not used directly, but through a `ProjectMethods` instance.
-///
-/// # Example
-///
-/// Instantiate a resource method builder
-///
-/// ```test_harness,no_run
-/// # extern crate hyper;
-/// # extern crate hyper_rustls;
-/// # extern crate yup_oauth2 as oauth2;
-/// # extern crate google_firestore as firestore1;
-/// use firestore1::RollbackRequest;
-/// # #[test] fn egal() {
-/// # use std::default::Default;
-/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
-/// # use firestore1::Firestore;
-///
-/// # let secret: ApplicationSecret = Default::default();
-/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
-/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
-/// #                               <MemoryStorage as Default>::default(), None);
-/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = RollbackRequest::default();
-///
-/// // You can configure optional parameters by calling the respective setters at will, and
-/// // execute the final call using `doit()`.
-/// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_rollback(req, "database")
-///              .doit();
-/// # }
-/// ```
-pub struct ProjectDatabaseDocumentRollbackCall<'a, C, A>
-    where C: 'a, A: 'a {
-
-    hub: &'a Firestore<C, A>,
-    _request: RollbackRequest,
-    _database: String,
-    _delegate: Option<&'a mut dyn Delegate>,
-    _additional_params: HashMap<String, String>,
-    _scopes: BTreeMap<String, ()>
-}
-
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentRollbackCall<'a, C, A> {}
-
-impl<'a, C, A> ProjectDatabaseDocumentRollbackCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
-
-
-    /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
-        let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
-            Some(d) => d,
-            None => &mut dd
-        };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.rollback",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("database", self._database.to_string()));
-        for &field in ["alt", "database"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+database}/documents:rollback";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+database}", "database")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["database"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
-        }
-    }
-
-
-    ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: RollbackRequest) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
-    /// The database name. In the format:
-    /// `projects/{project_id}/databases/{database_id}`.
-    ///
-    /// Sets the *database* path property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn database(mut self, new_value: &str) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
-        self._database = new_value.to_string();
-        self
-    }
-    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
-    /// while executing the actual API request.
-    ///
-    /// It should be used to handle progress information, and to implement a certain level of resilience.
-    ///
-    /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
-        self._delegate = Some(new_value);
-        self
-    }
-
-    /// Set any additional parameter of the query string used in the request.
-    /// It should be used to set parameters which are not yet available through their own
-    /// setters.
-    ///
-    /// Please note that this method must not be used to set any of the known parameters
-    /// which have their own setter method. If done anyway, the request will fail.
-    ///
-    /// # Additional Parameters
-    ///
-    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
-    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
-    /// * *access_token* (query-string) - OAuth access token.
-    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
-    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
-    /// * *callback* (query-string) - JSONP
-    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
-    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
-    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
-    /// * *alt* (query-string) - Data format for response.
-    /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentRollbackCall<'a, C, A>
-                                                        where T: AsRef<str> {
-        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
-        self
-    }
-
-    /// Identifies the authorization scope for the method you are building.
-    ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
-    ///
-    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
-    /// tokens for more than one scope.
-    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
-    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
-    /// function for details).
-    ///
-    /// Usually there is more than one suitable scope to authorize an operation, some of which may
-    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
-    /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentRollbackCall<'a, C, A>
-                                                        where T: Into<Option<S>>,
-                                                              S: AsRef<str> {
-        match scope.into() {
-          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
-          None => None,
-        };
-        self
-    }
-}
-
-
-/// Commits a transaction, while optionally updating documents.
-///
-/// A builder for the *databases.documents.commit* method supported by a *project* resource.
-/// It is not used directly, but through a `ProjectMethods` instance.
-///
-/// # Example
-///
-/// Instantiate a resource method builder
-///
-/// ```test_harness,no_run
-/// # extern crate hyper;
-/// # extern crate hyper_rustls;
-/// # extern crate yup_oauth2 as oauth2;
-/// # extern crate google_firestore as firestore1;
-/// use firestore1::CommitRequest;
-/// # #[test] fn egal() {
-/// # use std::default::Default;
-/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
-/// # use firestore1::Firestore;
-///
-/// # let secret: ApplicationSecret = Default::default();
-/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
-/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
-/// #                               <MemoryStorage as Default>::default(), None);
-/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = CommitRequest::default();
-///
-/// // You can configure optional parameters by calling the respective setters at will, and
-/// // execute the final call using `doit()`.
-/// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_commit(req, "database")
-///              .doit();
-/// # }
-/// ```
-pub struct ProjectDatabaseDocumentCommitCall<'a, C, A>
-    where C: 'a, A: 'a {
-
-    hub: &'a Firestore<C, A>,
-    _request: CommitRequest,
-    _database: String,
-    _delegate: Option<&'a mut dyn Delegate>,
-    _additional_params: HashMap<String, String>,
-    _scopes: BTreeMap<String, ()>
-}
-
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentCommitCall<'a, C, A> {}
-
-impl<'a, C, A> ProjectDatabaseDocumentCommitCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
-
-
-    /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, CommitResponse)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
-        let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
-            Some(d) => d,
-            None => &mut dd
-        };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.commit",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("database", self._database.to_string()));
-        for &field in ["alt", "database"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+database}/documents:commit";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+database}", "database")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["database"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
-        }
-    }
-
-
-    ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: CommitRequest) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
-    /// The database name. In the format:
-    /// `projects/{project_id}/databases/{database_id}`.
-    ///
-    /// Sets the *database* path property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn database(mut self, new_value: &str) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
-        self._database = new_value.to_string();
-        self
-    }
-    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
-    /// while executing the actual API request.
-    ///
-    /// It should be used to handle progress information, and to implement a certain level of resilience.
-    ///
-    /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
-        self._delegate = Some(new_value);
-        self
-    }
-
-    /// Set any additional parameter of the query string used in the request.
-    /// It should be used to set parameters which are not yet available through their own
-    /// setters.
-    ///
-    /// Please note that this method must not be used to set any of the known parameters
-    /// which have their own setter method. If done anyway, the request will fail.
-    ///
-    /// # Additional Parameters
-    ///
-    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
-    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
-    /// * *access_token* (query-string) - OAuth access token.
-    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
-    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
-    /// * *callback* (query-string) - JSONP
-    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
-    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
-    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
-    /// * *alt* (query-string) - Data format for response.
-    /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentCommitCall<'a, C, A>
-                                                        where T: AsRef<str> {
-        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
-        self
-    }
-
-    /// Identifies the authorization scope for the method you are building.
-    ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
-    ///
-    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
-    /// tokens for more than one scope.
-    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
-    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
-    /// function for details).
-    ///
-    /// Usually there is more than one suitable scope to authorize an operation, some of which may
-    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
-    /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentCommitCall<'a, C, A>
-                                                        where T: Into<Option<S>>,
-                                                              S: AsRef<str> {
-        match scope.into() {
-          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
-          None => None,
-        };
-        self
-    }
-}
-
-
-/// Gets information about a location.
-///
-/// A builder for the *locations.get* method supported by a *project* resource.
-/// It is not used directly, but through a `ProjectMethods` instance.
-///
-/// # Example
-///
-/// Instantiate a resource method builder
-///
-/// ```test_harness,no_run
-/// # extern crate hyper;
-/// # extern crate hyper_rustls;
-/// # extern crate yup_oauth2 as oauth2;
-/// # extern crate google_firestore as firestore1;
-/// # #[test] fn egal() {
-/// # use std::default::Default;
-/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
-/// # use firestore1::Firestore;
-///
-/// # let secret: ApplicationSecret = Default::default();
-/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
-/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
-/// #                               <MemoryStorage as Default>::default(), None);
-/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // You can configure optional parameters by calling the respective setters at will, and
-/// // execute the final call using `doit()`.
-/// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().locations_get("name")
-///              .doit();
-/// # }
-/// ```
-pub struct ProjectLocationGetCall<'a, C, A>
-    where C: 'a, A: 'a {
-
-    hub: &'a Firestore<C, A>,
-    _name: String,
-    _delegate: Option<&'a mut dyn Delegate>,
-    _additional_params: HashMap<String, String>,
-    _scopes: BTreeMap<String, ()>
-}
-
-impl<'a, C, A> CallBuilder for ProjectLocationGetCall<'a, C, A> {}
-
-impl<'a, C, A> ProjectLocationGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
-
-
-    /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Location)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
-        let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
-            Some(d) => d,
-            None => &mut dd
-        };
-        dlg.begin(MethodInfo { id: "firestore.projects.locations.get",
-                               http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        for &field in ["alt", "name"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
-        }
-    }
-
-
-    /// Resource name for the location.
-    ///
-    /// Sets the *name* path property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectLocationGetCall<'a, C, A> {
-        self._name = new_value.to_string();
-        self
-    }
-    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
-    /// while executing the actual API request.
-    ///
-    /// It should be used to handle progress information, and to implement a certain level of resilience.
-    ///
-    /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectLocationGetCall<'a, C, A> {
-        self._delegate = Some(new_value);
-        self
-    }
-
-    /// Set any additional parameter of the query string used in the request.
-    /// It should be used to set parameters which are not yet available through their own
-    /// setters.
-    ///
-    /// Please note that this method must not be used to set any of the known parameters
-    /// which have their own setter method. If done anyway, the request will fail.
-    ///
-    /// # Additional Parameters
-    ///
-    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
-    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
-    /// * *access_token* (query-string) - OAuth access token.
-    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
-    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
-    /// * *callback* (query-string) - JSONP
-    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
-    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
-    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
-    /// * *alt* (query-string) - Data format for response.
-    /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectLocationGetCall<'a, C, A>
-                                                        where T: AsRef<str> {
-        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
-        self
-    }
-
-    /// Identifies the authorization scope for the method you are building.
-    ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
-    ///
-    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
-    /// tokens for more than one scope.
-    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
-    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
-    /// function for details).
-    ///
-    /// Usually there is more than one suitable scope to authorize an operation, some of which may
-    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
-    /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectLocationGetCall<'a, C, A>
-                                                        where T: Into<Option<S>>,
-                                                              S: AsRef<str> {
-        match scope.into() {
-          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
-          None => None,
-        };
-        self
-    }
-}
-
-
-/// Gets a composite index.
-///
-/// A builder for the *databases.collectionGroups.indexes.get* method supported by a *project* resource.
-/// It is not used directly, but through a `ProjectMethods` instance.
-///
-/// # Example
-///
-/// Instantiate a resource method builder
-///
-/// ```test_harness,no_run
-/// # extern crate hyper;
-/// # extern crate hyper_rustls;
-/// # extern crate yup_oauth2 as oauth2;
-/// # extern crate google_firestore as firestore1;
-/// # #[test] fn egal() {
-/// # use std::default::Default;
-/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
-/// # use firestore1::Firestore;
-///
-/// # let secret: ApplicationSecret = Default::default();
-/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
-/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
-/// #                               <MemoryStorage as Default>::default(), None);
-/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // You can configure optional parameters by calling the respective setters at will, and
-/// // execute the final call using `doit()`.
-/// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_collection_groups_indexes_get("name")
-///              .doit();
-/// # }
-/// ```
-pub struct ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A>
-    where C: 'a, A: 'a {
-
-    hub: &'a Firestore<C, A>,
-    _name: String,
-    _delegate: Option<&'a mut dyn Delegate>,
-    _additional_params: HashMap<String, String>,
-    _scopes: BTreeMap<String, ()>
-}
-
-impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> {}
-
-impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
-
-
-    /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1Index)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
-        let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
-            Some(d) => d,
-            None => &mut dd
-        };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.get",
-                               http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        for &field in ["alt", "name"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
-        }
-    }
-
-
-    /// A name of the form
-    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/indexes/{index_id}`
-    ///
-    /// Sets the *name* path property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> {
-        self._name = new_value.to_string();
-        self
-    }
-    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
-    /// while executing the actual API request.
-    ///
-    /// It should be used to handle progress information, and to implement a certain level of resilience.
-    ///
-    /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> {
-        self._delegate = Some(new_value);
-        self
-    }
-
-    /// Set any additional parameter of the query string used in the request.
-    /// It should be used to set parameters which are not yet available through their own
-    /// setters.
-    ///
-    /// Please note that this method must not be used to set any of the known parameters
-    /// which have their own setter method. If done anyway, the request will fail.
-    ///
-    /// # Additional Parameters
-    ///
-    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
-    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
-    /// * *access_token* (query-string) - OAuth access token.
-    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
-    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
-    /// * *callback* (query-string) - JSONP
-    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
-    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
-    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
-    /// * *alt* (query-string) - Data format for response.
-    /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A>
-                                                        where T: AsRef<str> {
-        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
-        self
-    }
-
-    /// Identifies the authorization scope for the method you are building.
-    ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
-    ///
-    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
-    /// tokens for more than one scope.
-    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
-    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
-    /// function for details).
-    ///
-    /// Usually there is more than one suitable scope to authorize an operation, some of which may
-    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
-    /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A>
-                                                        where T: Into<Option<S>>,
-                                                              S: AsRef<str> {
-        match scope.into() {
-          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
-          None => None,
-        };
-        self
-    }
-}
-
-
-/// Lists information about the supported locations for this service.
-///
-/// A builder for the *locations.list* method supported by a *project* resource.
-/// It is not used directly, but through a `ProjectMethods` instance.
-///
-/// # Example
-///
-/// Instantiate a resource method builder
-///
-/// ```test_harness,no_run
-/// # extern crate hyper;
-/// # extern crate hyper_rustls;
-/// # extern crate yup_oauth2 as oauth2;
-/// # extern crate google_firestore as firestore1;
-/// # #[test] fn egal() {
-/// # use std::default::Default;
-/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
-/// # use firestore1::Firestore;
-///
-/// # let secret: ApplicationSecret = Default::default();
-/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
-/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
-/// #                               <MemoryStorage as Default>::default(), None);
-/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // You can configure optional parameters by calling the respective setters at will, and
-/// // execute the final call using `doit()`.
-/// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().locations_list("name")
-///              .page_token("labore")
-///              .page_size(-9)
-///              .filter("nonumy")
-///              .doit();
-/// # }
-/// ```
-pub struct ProjectLocationListCall<'a, C, A>
-    where C: 'a, A: 'a {
-
-    hub: &'a Firestore<C, A>,
-    _name: String,
-    _page_token: Option<String>,
-    _page_size: Option<i32>,
-    _filter: Option<String>,
-    _delegate: Option<&'a mut dyn Delegate>,
-    _additional_params: HashMap<String, String>,
-    _scopes: BTreeMap<String, ()>
-}
-
-impl<'a, C, A> CallBuilder for ProjectLocationListCall<'a, C, A> {}
-
-impl<'a, C, A> ProjectLocationListCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
-
-
-    /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, ListLocationsResponse)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
-        let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
-            Some(d) => d,
-            None => &mut dd
-        };
-        dlg.begin(MethodInfo { id: "firestore.projects.locations.list",
-                               http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        if let Some(value) = self._page_token {
-            params.push(("pageToken", value.to_string()));
-        }
-        if let Some(value) = self._page_size {
-            params.push(("pageSize", value.to_string()));
-        }
-        if let Some(value) = self._filter {
-            params.push(("filter", value.to_string()));
-        }
-        for &field in ["alt", "name", "pageToken", "pageSize", "filter"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+name}/locations";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
-        }
-    }
-
-
-    /// The resource that owns the locations collection, if applicable.
-    ///
-    /// Sets the *name* path property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectLocationListCall<'a, C, A> {
-        self._name = new_value.to_string();
-        self
-    }
-    /// The standard list page token.
-    ///
-    /// Sets the *page token* query property to the given value.
-    pub fn page_token(mut self, new_value: &str) -> ProjectLocationListCall<'a, C, A> {
-        self._page_token = Some(new_value.to_string());
-        self
-    }
-    /// The standard list page size.
-    ///
-    /// Sets the *page size* query property to the given value.
-    pub fn page_size(mut self, new_value: i32) -> ProjectLocationListCall<'a, C, A> {
-        self._page_size = Some(new_value);
-        self
-    }
-    /// The standard list filter.
-    ///
-    /// Sets the *filter* query property to the given value.
-    pub fn filter(mut self, new_value: &str) -> ProjectLocationListCall<'a, C, A> {
-        self._filter = Some(new_value.to_string());
-        self
-    }
-    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
-    /// while executing the actual API request.
-    ///
-    /// It should be used to handle progress information, and to implement a certain level of resilience.
-    ///
-    /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectLocationListCall<'a, C, A> {
-        self._delegate = Some(new_value);
-        self
-    }
-
-    /// Set any additional parameter of the query string used in the request.
-    /// It should be used to set parameters which are not yet available through their own
-    /// setters.
-    ///
-    /// Please note that this method must not be used to set any of the known parameters
-    /// which have their own setter method. If done anyway, the request will fail.
-    ///
-    /// # Additional Parameters
-    ///
-    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
-    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
-    /// * *access_token* (query-string) - OAuth access token.
-    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
-    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
-    /// * *callback* (query-string) - JSONP
-    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
-    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
-    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
-    /// * *alt* (query-string) - Data format for response.
-    /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectLocationListCall<'a, C, A>
-                                                        where T: AsRef<str> {
-        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
-        self
-    }
-
-    /// Identifies the authorization scope for the method you are building.
-    ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
-    ///
-    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
-    /// tokens for more than one scope.
-    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
-    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
-    /// function for details).
-    ///
-    /// Usually there is more than one suitable scope to authorize an operation, some of which may
-    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
-    /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectLocationListCall<'a, C, A>
-                                                        where T: Into<Option<S>>,
-                                                              S: AsRef<str> {
-        match scope.into() {
-          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
-          None => None,
-        };
-        self
-    }
-}
-
-
-/// Starts asynchronous cancellation on a long-running operation.  The server
-/// makes a best effort to cancel the operation, but success is not
-/// guaranteed.  If the server doesn't support this method, it returns
-/// `google.rpc.Code.UNIMPLEMENTED`.  Clients can use
-/// Operations.GetOperation or
-/// other methods to check whether the cancellation succeeded or whether the
-/// operation completed despite cancellation. On successful cancellation,
-/// the operation is not deleted; instead, it becomes an operation with
-/// an Operation.error value with a google.rpc.Status.code of 1,
-/// corresponding to `Code.CANCELLED`.
-///
-/// A builder for the *databases.operations.cancel* method supported by a *project* resource.
-/// It is not used directly, but through a `ProjectMethods` instance.
-///
-/// # Example
-///
-/// Instantiate a resource method builder
-///
-/// ```test_harness,no_run
-/// # extern crate hyper;
-/// # extern crate hyper_rustls;
-/// # extern crate yup_oauth2 as oauth2;
-/// # extern crate google_firestore as firestore1;
-/// use firestore1::GoogleLongrunningCancelOperationRequest;
-/// # #[test] fn egal() {
-/// # use std::default::Default;
-/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
-/// # use firestore1::Firestore;
-///
-/// # let secret: ApplicationSecret = Default::default();
-/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
-/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
-/// #                               <MemoryStorage as Default>::default(), None);
-/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = GoogleLongrunningCancelOperationRequest::default();
-///
-/// // You can configure optional parameters by calling the respective setters at will, and
-/// // execute the final call using `doit()`.
-/// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_operations_cancel(req, "name")
-///              .doit();
-/// # }
-/// ```
-pub struct ProjectDatabaseOperationCancelCall<'a, C, A>
-    where C: 'a, A: 'a {
-
-    hub: &'a Firestore<C, A>,
-    _request: GoogleLongrunningCancelOperationRequest,
-    _name: String,
-    _delegate: Option<&'a mut dyn Delegate>,
-    _additional_params: HashMap<String, String>,
-    _scopes: BTreeMap<String, ()>
-}
-
-impl<'a, C, A> CallBuilder for ProjectDatabaseOperationCancelCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectLocationGetCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectLocationGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, Location)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -3604,9 +1107,9 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.operations.cancel",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
+        dlg.begin(MethodInfo { id: "firestore.projects.locations.get",
+                               http_method: hyper::method::Method::Get });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
         params.push(("name", self._name.to_string()));
         for &field in ["alt", "name"].iter() {
             if self._additional_params.contains_key(field) {
@@ -3620,7 +1123,7 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}:cancel";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
@@ -3652,17 +1155,6 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -3673,21 +1165,17 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
+                    .header(auth_header.clone());
 
                 dlg.pre_request();
                 req.send()
@@ -3738,22 +1226,13 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
     }
 
 
-    ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: GoogleLongrunningCancelOperationRequest) -> ProjectDatabaseOperationCancelCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
-    /// The name of the operation resource to be cancelled.
+    /// Resource name for the location.
     ///
     /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseOperationCancelCall<'a, C, A> {
+    pub fn name(mut self, new_value: &str) -> ProjectLocationGetCall<'a, C, A> {
         self._name = new_value.to_string();
         self
     }
@@ -3763,7 +1242,7 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseOperationCancelCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectLocationGetCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -3788,7 +1267,7 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseOperationCancelCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectLocationGetCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -3808,7 +1287,7 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseOperationCancelCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectLocationGetCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -3820,14 +1299,9 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
 }
 
 
-
-
-/// Gets multiple documents.
-///
-/// Documents returned by this method are not guaranteed to be returned in the
-/// same order that they were requested.
+/// Gets a composite index.
 ///
-/// A builder for the *databases.documents.batchGet* method supported by a *project* resource.
+/// A builder for the *databases.collectionGroups.indexes.get* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -3839,7 +1313,6 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::BatchGetDocumentsRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -3850,36 +1323,30 @@ impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<h
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = BatchGetDocumentsRequest::default();
-///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_batch_get(req, "database")
+/// let result = hub.projects().databases_collection_groups_indexes_get("name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentBatchGetCall<'a, C, A>
+pub struct ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: BatchGetDocumentsRequest,
-    _database: String,
+    _name: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentBatchGetCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, BatchGetDocumentsResponse)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1Index)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -3888,11 +1355,11 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.batchGet",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("database", self._database.to_string()));
-        for &field in ["alt", "database"].iter() {
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.get",
+                               http_method: hyper::method::Method::Get });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
+        params.push(("name", self._name.to_string()));
+        for &field in ["alt", "name"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -3904,12 +1371,12 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+database}/documents:batchGet";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+database}", "database")].iter() {
+        for &(find_this, param_name) in [("{+name}", "name")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -3924,7 +1391,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["database"].iter() {
+            for param_name in ["name"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -3936,17 +1403,6 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -3957,21 +1413,17 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
+                    .header(auth_header.clone());
 
                 dlg.pre_request();
                 req.send()
@@ -4022,24 +1474,15 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
     }
 
 
+    /// A name of the form
+    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/indexes/{index_id}`
     ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: BatchGetDocumentsRequest) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
-    /// The database name. In the format:
-    /// `projects/{project_id}/databases/{database_id}`.
-    ///
-    /// Sets the *database* path property to the given value.
+    /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn database(mut self, new_value: &str) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
-        self._database = new_value.to_string();
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> {
+        self._name = new_value.to_string();
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -4048,7 +1491,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -4073,7 +1516,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -4093,7 +1536,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeGetCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -4105,9 +1548,9 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
 }
 
 
-/// Starts a new transaction.
+/// Lists information about the supported locations for this service.
 ///
-/// A builder for the *databases.documents.beginTransaction* method supported by a *project* resource.
+/// A builder for the *locations.list* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -4119,7 +1562,6 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::BeginTransactionRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -4130,36 +1572,36 @@ impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: BorrowMut<
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = BeginTransactionRequest::default();
-///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_begin_transaction(req, "database")
+/// let result = hub.projects().locations_list("name")
+///              .page_token("labore")
+///              .page_size(-9)
+///              .filter("nonumy")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentBeginTransactionCall<'a, C, A>
+pub struct ProjectLocationListCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: BeginTransactionRequest,
-    _database: String,
+    _name: String,
+    _page_token: Option<String>,
+    _page_size: Option<i32>,
+    _filter: Option<String>,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectLocationListCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectLocationListCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, BeginTransactionResponse)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, ListLocationsResponse)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -4168,11 +1610,20 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.beginTransaction",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("database", self._database.to_string()));
-        for &field in ["alt", "database"].iter() {
+        dlg.begin(MethodInfo { id: "firestore.projects.locations.list",
+                               http_method: hyper::method::Method::Get });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(6 + self._additional_params.len());
+        params.push(("name", self._name.to_string()));
+        if let Some(value) = self._page_token {
+            params.push(("pageToken", value.to_string()));
+        }
+        if let Some(value) = self._page_size {
+            params.push(("pageSize", value.to_string()));
+        }
+        if let Some(value) = self._filter {
+            params.push(("filter", value.to_string()));
+        }
+        for &field in ["alt", "name", "pageToken", "pageSize", "filter"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -4184,12 +1635,12 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+database}/documents:beginTransaction";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}/locations";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+database}", "database")].iter() {
+        for &(find_this, param_name) in [("{+name}", "name")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -4204,7 +1655,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["database"].iter() {
+            for param_name in ["name"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -4216,17 +1667,6 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -4237,21 +1677,17 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
+                    .header(auth_header.clone());
 
                 dlg.pre_request();
                 req.send()
@@ -4302,24 +1738,35 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
     }
 
 
+    /// The resource that owns the locations collection, if applicable.
     ///
-    /// Sets the *request* property to the given value.
+    /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: BeginTransactionRequest) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
-        self._request = new_value;
+    pub fn name(mut self, new_value: &str) -> ProjectLocationListCall<'a, C, A> {
+        self._name = new_value.to_string();
         self
     }
-    /// The database name. In the format:
-    /// `projects/{project_id}/databases/{database_id}`.
+    /// The standard list page token.
     ///
-    /// Sets the *database* path property to the given value.
+    /// Sets the *page token* query property to the given value.
+    pub fn page_token(mut self, new_value: &str) -> ProjectLocationListCall<'a, C, A> {
+        self._page_token = Some(new_value.to_string());
+        self
+    }
+    /// The standard list page size.
     ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn database(mut self, new_value: &str) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
-        self._database = new_value.to_string();
+    /// Sets the *page size* query property to the given value.
+    pub fn page_size(mut self, new_value: i32) -> ProjectLocationListCall<'a, C, A> {
+        self._page_size = Some(new_value);
+        self
+    }
+    /// The standard list filter.
+    ///
+    /// Sets the *filter* query property to the given value.
+    pub fn filter(mut self, new_value: &str) -> ProjectLocationListCall<'a, C, A> {
+        self._filter = Some(new_value.to_string());
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -4328,7 +1775,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectLocationListCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -4353,7 +1800,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectLocationListCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -4373,7 +1820,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectLocationListCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -4385,9 +1832,18 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
 }
 
 
-/// Lists all the collection IDs underneath a document.
+/// Starts asynchronous cancellation on a long-running operation.  The server
+/// makes a best effort to cancel the operation, but success is not
+/// guaranteed.  If the server doesn't support this method, it returns
+/// `google.rpc.Code.UNIMPLEMENTED`.  Clients can use
+/// Operations.GetOperation or
+/// other methods to check whether the cancellation succeeded or whether the
+/// operation completed despite cancellation. On successful cancellation,
+/// the operation is not deleted; instead, it becomes an operation with
+/// an Operation.error value with a google.rpc.Status.code of 1,
+/// corresponding to `Code.CANCELLED`.
 ///
-/// A builder for the *databases.documents.listCollectionIds* method supported by a *project* resource.
+/// A builder for the *databases.operations.cancel* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -4399,7 +1855,7 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::ListCollectionIdsRequest;
+/// use firestore1::GoogleLongrunningCancelOperationRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -4413,33 +1869,33 @@ impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Bo
 /// // As the method needs a request, you would usually fill it with the desired information
 /// // into the respective structure. Some of the parts shown here might not be applicable !
 /// // Values shown here are possibly random and not representative !
-/// let mut req = ListCollectionIdsRequest::default();
+/// let mut req = GoogleLongrunningCancelOperationRequest::default();
 ///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_list_collection_ids(req, "parent")
+/// let result = hub.projects().databases_operations_cancel(req, "name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentListCollectionIdCall<'a, C, A>
+pub struct ProjectDatabaseOperationCancelCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: ListCollectionIdsRequest,
-    _parent: String,
+    _request: GoogleLongrunningCancelOperationRequest,
+    _name: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseOperationCancelCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseOperationCancelCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, ListCollectionIdsResponse)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -4448,11 +1904,11 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.listCollectionIds",
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.operations.cancel",
                                http_method: hyper::method::Method::Post });
         let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("parent", self._parent.to_string()));
-        for &field in ["alt", "parent"].iter() {
+        params.push(("name", self._name.to_string()));
+        for &field in ["alt", "name"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -4464,12 +1920,12 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+parent}:listCollectionIds";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}:cancel";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+parent}", "parent")].iter() {
+        for &(find_this, param_name) in [("{+name}", "name")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -4484,7 +1940,7 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["parent"].iter() {
+            for param_name in ["name"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -4517,7 +1973,7 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
@@ -4587,21 +2043,18 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: ListCollectionIdsRequest) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {
+    pub fn request(mut self, new_value: GoogleLongrunningCancelOperationRequest) -> ProjectDatabaseOperationCancelCall<'a, C, A> {
         self._request = new_value;
         self
     }
-    /// The parent document. In the format:
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    /// For example:
-    /// `projects/my-project/databases/my-database/documents/chatrooms/my-chatroom`
+    /// The name of the operation resource to be cancelled.
     ///
-    /// Sets the *parent* path property to the given value.
+    /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {
-        self._parent = new_value.to_string();
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseOperationCancelCall<'a, C, A> {
+        self._name = new_value.to_string();
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -4610,7 +2063,7 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseOperationCancelCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -4635,7 +2088,7 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseOperationCancelCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -4655,7 +2108,7 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseOperationCancelCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -4667,21 +2120,11 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
 }
 
 
-/// Updates a field configuration. Currently, field updates apply only to
-/// single field index configuration. However, calls to
-/// FirestoreAdmin.UpdateField should provide a field mask to avoid
-/// changing any configuration that the caller isn't aware of. The field mask
-/// should be specified as: `{ paths: "index_config" }`.
-///
-/// This call returns a google.longrunning.Operation which may be used to
-/// track the status of the field update. The metadata for
-/// the operation will be the type FieldOperationMetadata.
-///
-/// To configure the default field settings for the database, use
-/// the special `Field` with resource name:
-/// `projects/{project_id}/databases/{database_id}/collectionGroups/__default__/fields/*`.
+
+
+/// Lists all the collection IDs underneath a document.
 ///
-/// A builder for the *databases.collectionGroups.fields.patch* method supported by a *project* resource.
+/// A builder for the *databases.documents.listCollectionIds* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -4693,7 +2136,7 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::GoogleFirestoreAdminV1Field;
+/// use firestore1::ListCollectionIdsRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -4707,35 +2150,33 @@ impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: Bo
 /// // As the method needs a request, you would usually fill it with the desired information
 /// // into the respective structure. Some of the parts shown here might not be applicable !
 /// // Values shown here are possibly random and not representative !
-/// let mut req = GoogleFirestoreAdminV1Field::default();
+/// let mut req = ListCollectionIdsRequest::default();
 ///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_collection_groups_fields_patch(req, "name")
-///              .update_mask("diam")
+/// let result = hub.projects().databases_documents_list_collection_ids(req, "parent")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A>
+pub struct ProjectDatabaseDocumentListCollectionIdCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: GoogleFirestoreAdminV1Field,
-    _name: String,
-    _update_mask: Option<String>,
+    _request: ListCollectionIdsRequest,
+    _parent: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, ListCollectionIdsResponse)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -4744,14 +2185,11 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.fields.patch",
-                               http_method: hyper::method::Method::Patch });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(5 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        if let Some(value) = self._update_mask {
-            params.push(("updateMask", value.to_string()));
-        }
-        for &field in ["alt", "name", "updateMask"].iter() {
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.listCollectionIds",
+                               http_method: hyper::method::Method::Post });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
+        params.push(("parent", self._parent.to_string()));
+        for &field in ["alt", "parent"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -4763,12 +2201,12 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
+        let mut url = self.hub._base_url.clone() + "v1/{+parent}:listCollectionIds";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
+        for &(find_this, param_name) in [("{+parent}", "parent")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -4783,7 +2221,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
+            for param_name in ["parent"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -4816,7 +2254,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
@@ -4825,7 +2263,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
             request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Patch, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
                     .header(auth_header.clone())
                     .header(ContentType(json_mime_type.clone()))
@@ -4886,51 +2324,21 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: GoogleFirestoreAdminV1Field) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
+    pub fn request(mut self, new_value: ListCollectionIdsRequest) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {
         self._request = new_value;
         self
     }
-    /// A field name of the form
-    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/fields/{field_path}`
-    ///
-    /// A field path may be a simple field name, e.g. `address` or a path to fields
-    /// within map_value , e.g. `address.city`,
-    /// or a special field path. The only valid special field is `*`, which
-    /// represents any field.
-    ///
-    /// Field paths may be quoted using ` (backtick). The only character that needs
-    /// to be escaped within a quoted field path is the backtick character itself,
-    /// escaped using a backslash. Special characters in field paths that
-    /// must be quoted include: `*`, `.`,
-    /// ``` (backtick), `[`, `]`, as well as any ascii symbolic characters.
-    ///
-    /// Examples:
-    /// (Note: Comments here are written in markdown syntax, so there is an
-    ///  additional layer of backticks to represent a code block)
-    /// `\`address.city\`` represents a field named `address.city`, not the map key
-    /// `city` in the field `address`.
-    /// `\`*\`` represents a field named `*`, not any field.
-    ///
-    /// A special `Field` contains the default indexing settings for all fields.
-    /// This field's resource name is:
-    /// `projects/{project_id}/databases/{database_id}/collectionGroups/__default__/fields/*`
-    /// Indexes defined on this `Field` will be applied to all fields which do not
-    /// have their own `Field` index configuration.
+    /// The parent document. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    /// For example:
+    /// `projects/my-project/databases/my-database/documents/chatrooms/my-chatroom`
     ///
-    /// Sets the *name* path property to the given value.
+    /// Sets the *parent* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
-        self._name = new_value.to_string();
-        self
-    }
-    /// A mask, relative to the field. If specified, only configuration specified
-    /// by this field_mask will be updated in the field.
-    ///
-    /// Sets the *update mask* query property to the given value.
-    pub fn update_mask(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
-        self._update_mask = Some(new_value.to_string());
+    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {
+        self._parent = new_value.to_string();
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -4939,7 +2347,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -4964,7 +2372,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -4984,7 +2392,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentListCollectionIdCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -4996,9 +2404,21 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
 }
 
 
-/// Lists composite indexes.
+/// Updates a field configuration. Currently, field updates apply only to
+/// single field index configuration. However, calls to
+/// FirestoreAdmin.UpdateField should provide a field mask to avoid
+/// changing any configuration that the caller isn't aware of. The field mask
+/// should be specified as: `{ paths: "index_config" }`.
 ///
-/// A builder for the *databases.collectionGroups.indexes.list* method supported by a *project* resource.
+/// This call returns a google.longrunning.Operation which may be used to
+/// track the status of the field update. The metadata for
+/// the operation will be the type FieldOperationMetadata.
+///
+/// To configure the default field settings for the database, use
+/// the special `Field` with resource name:
+/// `projects/{project_id}/databases/{database_id}/collectionGroups/__default__/fields/*`.
+///
+/// A builder for the *databases.collectionGroups.fields.patch* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -5010,6 +2430,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
+/// use firestore1::GoogleFirestoreAdminV1Field;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -5020,36 +2441,38 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: B
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = GoogleFirestoreAdminV1Field::default();
+///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_collection_groups_indexes_list("parent")
-///              .page_token("Lorem")
-///              .page_size(-21)
-///              .filter("duo")
+/// let result = hub.projects().databases_collection_groups_fields_patch(req, "name")
+///              .update_mask("diam")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A>
+pub struct ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _parent: String,
-    _page_token: Option<String>,
-    _page_size: Option<i32>,
-    _filter: Option<String>,
+    _request: GoogleFirestoreAdminV1Field,
+    _name: String,
+    _update_mask: Option<String>,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1ListIndexesResponse)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -5058,20 +2481,14 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.list",
-                               http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6 + self._additional_params.len());
-        params.push(("parent", self._parent.to_string()));
-        if let Some(value) = self._page_token {
-            params.push(("pageToken", value.to_string()));
-        }
-        if let Some(value) = self._page_size {
-            params.push(("pageSize", value.to_string()));
-        }
-        if let Some(value) = self._filter {
-            params.push(("filter", value.to_string()));
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.fields.patch",
+                               http_method: hyper::method::Method::Patch });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(5 + self._additional_params.len());
+        params.push(("name", self._name.to_string()));
+        if let Some(value) = self._update_mask {
+            params.push(("updateMask", value.to_string()));
         }
-        for &field in ["alt", "parent", "pageToken", "pageSize", "filter"].iter() {
+        for &field in ["alt", "name", "updateMask"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -5083,12 +2500,12 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+parent}/indexes";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+parent}", "parent")].iter() {
+        for &(find_this, param_name) in [("{+name}", "name")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -5103,7 +2520,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["parent"].iter() {
+            for param_name in ["name"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -5115,6 +2532,17 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
+        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
+        let mut request_value_reader =
+            {
+                let mut value = json::value::to_value(&self._request).expect("serde to work");
+                remove_json_null_values(&mut value);
+                let mut dst = io::Cursor::new(Vec::with_capacity(128));
+                json::to_writer(&mut dst, &value).unwrap();
+                dst
+            };
+        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
+        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -5125,17 +2553,21 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
+            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Patch, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
+                    .header(auth_header.clone())
+                    .header(ContentType(json_mime_type.clone()))
+                    .header(ContentLength(request_size as u64))
+                    .body(&mut request_value_reader);
 
                 dlg.pre_request();
                 req.send()
@@ -5186,38 +2618,56 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
     }
 
 
-    /// A parent name of the form
-    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}`
     ///
-    /// Sets the *parent* path property to the given value.
+    /// Sets the *request* property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
-        self._parent = new_value.to_string();
+    pub fn request(mut self, new_value: GoogleFirestoreAdminV1Field) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
+        self._request = new_value;
         self
     }
-    /// A page token, returned from a previous call to
-    /// FirestoreAdmin.ListIndexes, that may be used to get the next
-    /// page of results.
+    /// A field name of the form
+    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/fields/{field_path}`
     ///
-    /// Sets the *page token* query property to the given value.
-    pub fn page_token(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
-        self._page_token = Some(new_value.to_string());
-        self
-    }
-    /// The number of results to return.
+    /// A field path may be a simple field name, e.g. `address` or a path to fields
+    /// within map_value , e.g. `address.city`,
+    /// or a special field path. The only valid special field is `*`, which
+    /// represents any field.
+    ///
+    /// Field paths may be quoted using ` (backtick). The only character that needs
+    /// to be escaped within a quoted field path is the backtick character itself,
+    /// escaped using a backslash. Special characters in field paths that
+    /// must be quoted include: `*`, `.`,
+    /// ``` (backtick), `[`, `]`, as well as any ascii symbolic characters.
+    ///
+    /// Examples:
+    /// (Note: Comments here are written in markdown syntax, so there is an
+    ///  additional layer of backticks to represent a code block)
+    /// `\`address.city\`` represents a field named `address.city`, not the map key
+    /// `city` in the field `address`.
+    /// `\`*\`` represents a field named `*`, not any field.
+    ///
+    /// A special `Field` contains the default indexing settings for all fields.
+    /// This field's resource name is:
+    /// `projects/{project_id}/databases/{database_id}/collectionGroups/__default__/fields/*`
+    /// Indexes defined on this `Field` will be applied to all fields which do not
+    /// have their own `Field` index configuration.
     ///
-    /// Sets the *page size* query property to the given value.
-    pub fn page_size(mut self, new_value: i32) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
-        self._page_size = Some(new_value);
+    /// Sets the *name* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
+        self._name = new_value.to_string();
         self
     }
-    /// The filter to apply to list results.
+    /// A mask, relative to the field. If specified, only configuration specified
+    /// by this field_mask will be updated in the field.
     ///
-    /// Sets the *filter* query property to the given value.
-    pub fn filter(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
-        self._filter = Some(new_value.to_string());
+    /// Sets the *update mask* query property to the given value.
+    pub fn update_mask(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
+        self._update_mask = Some(new_value.to_string());
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -5226,7 +2676,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -5251,7 +2701,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -5271,7 +2721,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupFieldPatchCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -5282,9 +2732,10 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
     }
 }
 
-/// Gets the metadata and configuration for a Field.
+
+/// Lists composite indexes.
 ///
-/// A builder for the *databases.collectionGroups.fields.get* method supported by a *project* resource.
+/// A builder for the *databases.collectionGroups.indexes.list* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -5309,27 +2760,33 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: B
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_collection_groups_fields_get("name")
+/// let result = hub.projects().databases_collection_groups_indexes_list("parent")
+///              .page_token("Lorem")
+///              .page_size(-21)
+///              .filter("duo")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A>
+pub struct ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _name: String,
+    _parent: String,
+    _page_token: Option<String>,
+    _page_size: Option<i32>,
+    _filter: Option<String>,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1Field)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1ListIndexesResponse)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -5338,11 +2795,20 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.fields.get",
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.list",
                                http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        for &field in ["alt", "name"].iter() {
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(6 + self._additional_params.len());
+        params.push(("parent", self._parent.to_string()));
+        if let Some(value) = self._page_token {
+            params.push(("pageToken", value.to_string()));
+        }
+        if let Some(value) = self._page_size {
+            params.push(("pageSize", value.to_string()));
+        }
+        if let Some(value) = self._filter {
+            params.push(("filter", value.to_string()));
+        }
+        for &field in ["alt", "parent", "pageToken", "pageSize", "filter"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -5354,12 +2820,12 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
+        let mut url = self.hub._base_url.clone() + "v1/{+parent}/indexes";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
+        for &(find_this, param_name) in [("{+parent}", "parent")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -5374,7 +2840,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
+            for param_name in ["parent"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -5396,7 +2862,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
@@ -5457,15 +2923,38 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
     }
 
 
-    /// A name of the form
-    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/fields/{field_id}`
+    /// A parent name of the form
+    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}`
     ///
-    /// Sets the *name* path property to the given value.
+    /// Sets the *parent* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> {
-        self._name = new_value.to_string();
+    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
+        self._parent = new_value.to_string();
+        self
+    }
+    /// A page token, returned from a previous call to
+    /// FirestoreAdmin.ListIndexes, that may be used to get the next
+    /// page of results.
+    ///
+    /// Sets the *page token* query property to the given value.
+    pub fn page_token(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
+        self._page_token = Some(new_value.to_string());
+        self
+    }
+    /// The number of results to return.
+    ///
+    /// Sets the *page size* query property to the given value.
+    pub fn page_size(mut self, new_value: i32) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
+        self._page_size = Some(new_value);
+        self
+    }
+    /// The filter to apply to list results.
+    ///
+    /// Sets the *filter* query property to the given value.
+    pub fn filter(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
+        self._filter = Some(new_value.to_string());
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -5474,7 +2963,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -5499,7 +2988,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -5519,7 +3008,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeListCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -5530,13 +3019,9 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
     }
 }
 
-
-/// Deletes a long-running operation. This method indicates that the client is
-/// no longer interested in the operation result. It does not cancel the
-/// operation. If the server doesn't support this method, it returns
-/// `google.rpc.Code.UNIMPLEMENTED`.
+/// Gets the metadata and configuration for a Field.
 ///
-/// A builder for the *databases.operations.delete* method supported by a *project* resource.
+/// A builder for the *databases.collectionGroups.fields.get* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -5561,11 +3046,11 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: Bor
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_operations_delete("name")
+/// let result = hub.projects().databases_collection_groups_fields_get("name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseOperationDeleteCall<'a, C, A>
+pub struct ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
@@ -5575,13 +3060,13 @@ pub struct ProjectDatabaseOperationDeleteCall<'a, C, A>
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseOperationDeleteCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1Field)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -5590,8 +3075,8 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.operations.delete",
-                               http_method: hyper::method::Method::Delete });
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.fields.get",
+                               http_method: hyper::method::Method::Get });
         let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
         params.push(("name", self._name.to_string()));
         for &field in ["alt", "name"].iter() {
@@ -5648,7 +3133,7 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
@@ -5656,7 +3141,7 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
             let auth_header = Authorization(Bearer { token: token.access_token });
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Delete, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
                     .header(auth_header.clone());
 
@@ -5709,13 +3194,14 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
     }
 
 
-    /// The name of the operation resource to be deleted.
+    /// A name of the form
+    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/fields/{field_id}`
     ///
     /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseOperationDeleteCall<'a, C, A> {
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> {
         self._name = new_value.to_string();
         self
     }
@@ -5725,7 +3211,7 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseOperationDeleteCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -5750,7 +3236,7 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseOperationDeleteCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -5770,7 +3256,7 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseOperationDeleteCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupFieldGetCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -5782,13 +3268,12 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
 }
 
 
-/// Imports documents into Google Cloud Firestore. Existing documents with the
-/// same name are overwritten. The import occurs in the background and its
-/// progress can be monitored and managed via the Operation resource that is
-/// created. If an ImportDocuments operation is cancelled, it is possible
-/// that a subset of the data has already been imported to Cloud Firestore.
+/// Deletes a long-running operation. This method indicates that the client is
+/// no longer interested in the operation result. It does not cancel the
+/// operation. If the server doesn't support this method, it returns
+/// `google.rpc.Code.UNIMPLEMENTED`.
 ///
-/// A builder for the *databases.importDocuments* method supported by a *project* resource.
+/// A builder for the *databases.operations.delete* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -5800,7 +3285,6 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::GoogleFirestoreAdminV1ImportDocumentsRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -5811,36 +3295,30 @@ impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<h
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = GoogleFirestoreAdminV1ImportDocumentsRequest::default();
-///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_import_documents(req, "name")
+/// let result = hub.projects().databases_operations_delete("name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseImportDocumentCall<'a, C, A>
+pub struct ProjectDatabaseOperationDeleteCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: GoogleFirestoreAdminV1ImportDocumentsRequest,
     _name: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseImportDocumentCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseOperationDeleteCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseOperationDeleteCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -5849,9 +3327,9 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.importDocuments",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.operations.delete",
+                               http_method: hyper::method::Method::Delete });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
         params.push(("name", self._name.to_string()));
         for &field in ["alt", "name"].iter() {
             if self._additional_params.contains_key(field) {
@@ -5865,7 +3343,7 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}:importDocuments";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
@@ -5897,17 +3375,6 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -5918,21 +3385,17 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Delete, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
+                    .header(auth_header.clone());
 
                 dlg.pre_request();
                 req.send()
@@ -5983,23 +3446,13 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
     }
 
 
-    ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: GoogleFirestoreAdminV1ImportDocumentsRequest) -> ProjectDatabaseImportDocumentCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
-    /// Database to import into. Should be of the form:
-    /// `projects/{project_id}/databases/{database_id}`.
+    /// The name of the operation resource to be deleted.
     ///
     /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseImportDocumentCall<'a, C, A> {
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseOperationDeleteCall<'a, C, A> {
         self._name = new_value.to_string();
         self
     }
@@ -6009,7 +3462,7 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseImportDocumentCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseOperationDeleteCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -6034,7 +3487,7 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseImportDocumentCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseOperationDeleteCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -6054,7 +3507,7 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseImportDocumentCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseOperationDeleteCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -6066,9 +3519,13 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
 }
 
 
-/// Deletes a composite index.
+/// Imports documents into Google Cloud Firestore. Existing documents with the
+/// same name are overwritten. The import occurs in the background and its
+/// progress can be monitored and managed via the Operation resource that is
+/// created. If an ImportDocuments operation is cancelled, it is possible
+/// that a subset of the data has already been imported to Cloud Firestore.
 ///
-/// A builder for the *databases.collectionGroups.indexes.delete* method supported by a *project* resource.
+/// A builder for the *databases.importDocuments* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -6080,6 +3537,7 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
+/// use firestore1::GoogleFirestoreAdminV1ImportDocumentsRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -6090,30 +3548,36 @@ impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hy
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = GoogleFirestoreAdminV1ImportDocumentsRequest::default();
+///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_collection_groups_indexes_delete("name")
+/// let result = hub.projects().databases_import_documents(req, "name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A>
+pub struct ProjectDatabaseImportDocumentCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
+    _request: GoogleFirestoreAdminV1ImportDocumentsRequest,
     _name: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseImportDocumentCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseImportDocumentCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -6122,9 +3586,9 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.delete",
-                               http_method: hyper::method::Method::Delete });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.importDocuments",
+                               http_method: hyper::method::Method::Post });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
         params.push(("name", self._name.to_string()));
         for &field in ["alt", "name"].iter() {
             if self._additional_params.contains_key(field) {
@@ -6138,7 +3602,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}:importDocuments";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
@@ -6170,6 +3634,17 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
+        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
+        let mut request_value_reader =
+            {
+                let mut value = json::value::to_value(&self._request).expect("serde to work");
+                remove_json_null_values(&mut value);
+                let mut dst = io::Cursor::new(Vec::with_capacity(128));
+                json::to_writer(&mut dst, &value).unwrap();
+                dst
+            };
+        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
+        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -6180,17 +3655,21 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
+            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Delete, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
+                    .header(auth_header.clone())
+                    .header(ContentType(json_mime_type.clone()))
+                    .header(ContentLength(request_size as u64))
+                    .body(&mut request_value_reader);
 
                 dlg.pre_request();
                 req.send()
@@ -6241,14 +3720,23 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
     }
 
 
-    /// A name of the form
-    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/indexes/{index_id}`
+    ///
+    /// Sets the *request* property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn request(mut self, new_value: GoogleFirestoreAdminV1ImportDocumentsRequest) -> ProjectDatabaseImportDocumentCall<'a, C, A> {
+        self._request = new_value;
+        self
+    }
+    /// Database to import into. Should be of the form:
+    /// `projects/{project_id}/databases/{database_id}`.
     ///
     /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> {
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseImportDocumentCall<'a, C, A> {
         self._name = new_value.to_string();
         self
     }
@@ -6258,7 +3746,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseImportDocumentCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -6283,7 +3771,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseImportDocumentCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -6303,7 +3791,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseImportDocumentCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -6315,11 +3803,9 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
 }
 
 
-/// Gets the latest state of a long-running operation.  Clients can use this
-/// method to poll the operation result at intervals as recommended by the API
-/// service.
+/// Deletes a composite index.
 ///
-/// A builder for the *databases.operations.get* method supported by a *project* resource.
+/// A builder for the *databases.collectionGroups.indexes.delete* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -6344,11 +3830,11 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C:
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_operations_get("name")
+/// let result = hub.projects().databases_collection_groups_indexes_delete("name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseOperationGetCall<'a, C, A>
+pub struct ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
@@ -6358,13 +3844,13 @@ pub struct ProjectDatabaseOperationGetCall<'a, C, A>
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseOperationGetCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -6373,8 +3859,8 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.operations.get",
-                               http_method: hyper::method::Method::Get });
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.delete",
+                               http_method: hyper::method::Method::Delete });
         let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
         params.push(("name", self._name.to_string()));
         for &field in ["alt", "name"].iter() {
@@ -6431,7 +3917,7 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
@@ -6439,7 +3925,7 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
             let auth_header = Authorization(Bearer { token: token.access_token });
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Delete, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
                     .header(auth_header.clone());
 
@@ -6492,13 +3978,14 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
     }
 
 
-    /// The name of the operation resource.
+    /// A name of the form
+    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}/indexes/{index_id}`
     ///
     /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseOperationGetCall<'a, C, A> {
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> {
         self._name = new_value.to_string();
         self
     }
@@ -6508,7 +3995,7 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseOperationGetCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -6533,7 +4020,7 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseOperationGetCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -6553,7 +4040,7 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseOperationGetCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeDeleteCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -6565,16 +4052,11 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
 }
 
 
-/// Exports a copy of all or a subset of documents from Google Cloud Firestore
-/// to another storage system, such as Google Cloud Storage. Recent updates to
-/// documents may not be reflected in the export. The export occurs in the
-/// background and its progress can be monitored and managed via the
-/// Operation resource that is created. The output of an export may only be
-/// used once the associated operation is done. If an export operation is
-/// cancelled before completion it may leave partial data behind in Google
-/// Cloud Storage.
+/// Gets the latest state of a long-running operation.  Clients can use this
+/// method to poll the operation result at intervals as recommended by the API
+/// service.
 ///
-/// A builder for the *databases.exportDocuments* method supported by a *project* resource.
+/// A builder for the *databases.operations.get* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -6586,7 +4068,6 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::GoogleFirestoreAdminV1ExportDocumentsRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -6597,32 +4078,26 @@ impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hype
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = GoogleFirestoreAdminV1ExportDocumentsRequest::default();
-///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_export_documents(req, "name")
+/// let result = hub.projects().databases_operations_get("name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseExportDocumentCall<'a, C, A>
+pub struct ProjectDatabaseOperationGetCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: GoogleFirestoreAdminV1ExportDocumentsRequest,
     _name: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseExportDocumentCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseOperationGetCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseOperationGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
@@ -6635,9 +4110,9 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.exportDocuments",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.operations.get",
+                               http_method: hyper::method::Method::Get });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(3 + self._additional_params.len());
         params.push(("name", self._name.to_string()));
         for &field in ["alt", "name"].iter() {
             if self._additional_params.contains_key(field) {
@@ -6651,7 +4126,7 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}:exportDocuments";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
@@ -6683,17 +4158,6 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -6704,21 +4168,17 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
+                    .header(auth_header.clone());
 
                 dlg.pre_request();
                 req.send()
@@ -6769,23 +4229,13 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
     }
 
 
-    ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: GoogleFirestoreAdminV1ExportDocumentsRequest) -> ProjectDatabaseExportDocumentCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
-    /// Database to export. Should be of the form:
-    /// `projects/{project_id}/databases/{database_id}`.
+    /// The name of the operation resource.
     ///
     /// Sets the *name* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseExportDocumentCall<'a, C, A> {
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseOperationGetCall<'a, C, A> {
         self._name = new_value.to_string();
         self
     }
@@ -6795,7 +4245,7 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseExportDocumentCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseOperationGetCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -6820,7 +4270,7 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseExportDocumentCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseOperationGetCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -6840,7 +4290,7 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseExportDocumentCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseOperationGetCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -6852,14 +4302,16 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
 }
 
 
-/// Lists the field configuration and metadata for this database.
-///
-/// Currently, FirestoreAdmin.ListFields only supports listing fields
-/// that have been explicitly overridden. To issue this query, call
-/// FirestoreAdmin.ListFields with the filter set to
-/// `indexConfig.usesAncestorConfig:false`.
+/// Exports a copy of all or a subset of documents from Google Cloud Firestore
+/// to another storage system, such as Google Cloud Storage. Recent updates to
+/// documents may not be reflected in the export. The export occurs in the
+/// background and its progress can be monitored and managed via the
+/// Operation resource that is created. The output of an export may only be
+/// used once the associated operation is done. If an export operation is
+/// cancelled before completion it may leave partial data behind in Google
+/// Cloud Storage.
 ///
-/// A builder for the *databases.collectionGroups.fields.list* method supported by a *project* resource.
+/// A builder for the *databases.exportDocuments* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -6871,6 +4323,7 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
+/// use firestore1::GoogleFirestoreAdminV1ExportDocumentsRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -6881,36 +4334,36 @@ impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hy
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = GoogleFirestoreAdminV1ExportDocumentsRequest::default();
+///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_collection_groups_fields_list("parent")
-///              .page_token("no")
-///              .page_size(-36)
-///              .filter("eirmod")
+/// let result = hub.projects().databases_export_documents(req, "name")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseCollectionGroupFieldListCall<'a, C, A>
+pub struct ProjectDatabaseExportDocumentCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _parent: String,
-    _page_token: Option<String>,
-    _page_size: Option<i32>,
-    _filter: Option<String>,
+    _request: GoogleFirestoreAdminV1ExportDocumentsRequest,
+    _name: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseExportDocumentCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseExportDocumentCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1ListFieldsResponse)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -6919,20 +4372,11 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.fields.list",
-                               http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6 + self._additional_params.len());
-        params.push(("parent", self._parent.to_string()));
-        if let Some(value) = self._page_token {
-            params.push(("pageToken", value.to_string()));
-        }
-        if let Some(value) = self._page_size {
-            params.push(("pageSize", value.to_string()));
-        }
-        if let Some(value) = self._filter {
-            params.push(("filter", value.to_string()));
-        }
-        for &field in ["alt", "parent", "pageToken", "pageSize", "filter"].iter() {
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.exportDocuments",
+                               http_method: hyper::method::Method::Post });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
+        params.push(("name", self._name.to_string()));
+        for &field in ["alt", "name"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -6944,12 +4388,12 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+parent}/fields";
+        let mut url = self.hub._base_url.clone() + "v1/{+name}:exportDocuments";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+parent}", "parent")].iter() {
+        for &(find_this, param_name) in [("{+name}", "name")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -6964,7 +4408,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["parent"].iter() {
+            for param_name in ["name"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -6976,6 +4420,17 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
+        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
+        let mut request_value_reader =
+            {
+                let mut value = json::value::to_value(&self._request).expect("serde to work");
+                remove_json_null_values(&mut value);
+                let mut dst = io::Cursor::new(Vec::with_capacity(128));
+                json::to_writer(&mut dst, &value).unwrap();
+                dst
+            };
+        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
+        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -6986,17 +4441,21 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
+            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
+                    .header(auth_header.clone())
+                    .header(ContentType(json_mime_type.clone()))
+                    .header(ContentLength(request_size as u64))
+                    .body(&mut request_value_reader);
 
                 dlg.pre_request();
                 req.send()
@@ -7047,42 +4506,24 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
     }
 
 
-    /// A parent name of the form
-    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}`
     ///
-    /// Sets the *parent* path property to the given value.
+    /// Sets the *request* property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
-        self._parent = new_value.to_string();
-        self
-    }
-    /// A page token, returned from a previous call to
-    /// FirestoreAdmin.ListFields, that may be used to get the next
-    /// page of results.
-    ///
-    /// Sets the *page token* query property to the given value.
-    pub fn page_token(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
-        self._page_token = Some(new_value.to_string());
-        self
-    }
-    /// The number of results to return.
-    ///
-    /// Sets the *page size* query property to the given value.
-    pub fn page_size(mut self, new_value: i32) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
-        self._page_size = Some(new_value);
+    /// we provide this method for API completeness.
+    pub fn request(mut self, new_value: GoogleFirestoreAdminV1ExportDocumentsRequest) -> ProjectDatabaseExportDocumentCall<'a, C, A> {
+        self._request = new_value;
         self
     }
-    /// The filter to apply to list results. Currently,
-    /// FirestoreAdmin.ListFields only supports listing fields
-    /// that have been explicitly overridden. To issue this query, call
-    /// FirestoreAdmin.ListFields with the filter set to
-    /// `indexConfig.usesAncestorConfig:false`.
+    /// Database to export. Should be of the form:
+    /// `projects/{project_id}/databases/{database_id}`.
     ///
-    /// Sets the *filter* query property to the given value.
-    pub fn filter(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
-        self._filter = Some(new_value.to_string());
+    /// Sets the *name* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn name(mut self, new_value: &str) -> ProjectDatabaseExportDocumentCall<'a, C, A> {
+        self._name = new_value.to_string();
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -7091,7 +4532,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseExportDocumentCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -7116,7 +4557,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseExportDocumentCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -7136,7 +4577,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseExportDocumentCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -7148,11 +4589,14 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
 }
 
 
-/// Creates a composite index. This returns a google.longrunning.Operation
-/// which may be used to track the status of the creation. The metadata for
-/// the operation will be the type IndexOperationMetadata.
+/// Lists the field configuration and metadata for this database.
 ///
-/// A builder for the *databases.collectionGroups.indexes.create* method supported by a *project* resource.
+/// Currently, FirestoreAdmin.ListFields only supports listing fields
+/// that have been explicitly overridden. To issue this query, call
+/// FirestoreAdmin.ListFields with the filter set to
+/// `indexConfig.usesAncestorConfig:false`.
+///
+/// A builder for the *databases.collectionGroups.fields.list* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -7164,7 +4608,6 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::GoogleFirestoreAdminV1Index;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -7175,36 +4618,36 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: Bo
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
-/// // As the method needs a request, you would usually fill it with the desired information
-/// // into the respective structure. Some of the parts shown here might not be applicable !
-/// // Values shown here are possibly random and not representative !
-/// let mut req = GoogleFirestoreAdminV1Index::default();
-///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_collection_groups_indexes_create(req, "parent")
+/// let result = hub.projects().databases_collection_groups_fields_list("parent")
+///              .page_token("no")
+///              .page_size(-36)
+///              .filter("eirmod")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A>
+pub struct ProjectDatabaseCollectionGroupFieldListCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: GoogleFirestoreAdminV1Index,
     _parent: String,
+    _page_token: Option<String>,
+    _page_size: Option<i32>,
+    _filter: Option<String>,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleFirestoreAdminV1ListFieldsResponse)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -7213,11 +4656,20 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.create",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.fields.list",
+                               http_method: hyper::method::Method::Get });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(6 + self._additional_params.len());
         params.push(("parent", self._parent.to_string()));
-        for &field in ["alt", "parent"].iter() {
+        if let Some(value) = self._page_token {
+            params.push(("pageToken", value.to_string()));
+        }
+        if let Some(value) = self._page_size {
+            params.push(("pageSize", value.to_string()));
+        }
+        if let Some(value) = self._filter {
+            params.push(("filter", value.to_string()));
+        }
+        for &field in ["alt", "parent", "pageToken", "pageSize", "filter"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -7229,7 +4681,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+parent}/indexes";
+        let mut url = self.hub._base_url.clone() + "v1/{+parent}/fields";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
@@ -7261,17 +4713,6 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
 
         let url = hyper::Url::parse_with_params(&url, params).unwrap();
 
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
 
         loop {
@@ -7282,21 +4723,17 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
             };
             let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
+                    .header(auth_header.clone());
 
                 dlg.pre_request();
                 req.send()
@@ -7347,15 +4784,6 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
     }
 
 
-    ///
-    /// Sets the *request* property to the given value.
-    ///
-    /// Even though the property as already been set when instantiating this call,
-    /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: GoogleFirestoreAdminV1Index) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {
-        self._request = new_value;
-        self
-    }
     /// A parent name of the form
     /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}`
     ///
@@ -7363,17 +4791,44 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {
+    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
         self._parent = new_value.to_string();
         self
     }
+    /// A page token, returned from a previous call to
+    /// FirestoreAdmin.ListFields, that may be used to get the next
+    /// page of results.
+    ///
+    /// Sets the *page token* query property to the given value.
+    pub fn page_token(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
+        self._page_token = Some(new_value.to_string());
+        self
+    }
+    /// The number of results to return.
+    ///
+    /// Sets the *page size* query property to the given value.
+    pub fn page_size(mut self, new_value: i32) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
+        self._page_size = Some(new_value);
+        self
+    }
+    /// The filter to apply to list results. Currently,
+    /// FirestoreAdmin.ListFields only supports listing fields
+    /// that have been explicitly overridden. To issue this query, call
+    /// FirestoreAdmin.ListFields with the filter set to
+    /// `indexConfig.usesAncestorConfig:false`.
+    ///
+    /// Sets the *filter* query property to the given value.
+    pub fn filter(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
+        self._filter = Some(new_value.to_string());
+        self
+    }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
     /// while executing the actual API request.
     ///
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -7398,7 +4853,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -7418,7 +4873,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupFieldListCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -7430,10 +4885,11 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
 }
 
 
-
-/// Streams batches of document updates and deletes, in order.
+/// Creates a composite index. This returns a google.longrunning.Operation
+/// which may be used to track the status of the creation. The metadata for
+/// the operation will be the type IndexOperationMetadata.
 ///
-/// A builder for the *databases.documents.write* method supported by a *project* resource.
+/// A builder for the *databases.collectionGroups.indexes.create* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -7445,7 +4901,7 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::WriteRequest;
+/// use firestore1::GoogleFirestoreAdminV1Index;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -7459,33 +4915,33 @@ impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C:
 /// // As the method needs a request, you would usually fill it with the desired information
 /// // into the respective structure. Some of the parts shown here might not be applicable !
 /// // Values shown here are possibly random and not representative !
-/// let mut req = WriteRequest::default();
+/// let mut req = GoogleFirestoreAdminV1Index::default();
 ///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_write(req, "database")
+/// let result = hub.projects().databases_collection_groups_indexes_create(req, "parent")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentWriteCall<'a, C, A>
+pub struct ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: WriteRequest,
-    _database: String,
+    _request: GoogleFirestoreAdminV1Index,
+    _parent: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentWriteCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, WriteResponse)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, GoogleLongrunningOperation)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -7494,11 +4950,11 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.write",
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.collectionGroups.indexes.create",
                                http_method: hyper::method::Method::Post });
         let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("database", self._database.to_string()));
-        for &field in ["alt", "database"].iter() {
+        params.push(("parent", self._parent.to_string()));
+        for &field in ["alt", "parent"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -7510,12 +4966,12 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+database}/documents:write";
+        let mut url = self.hub._base_url.clone() + "v1/{+parent}/indexes";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+database}", "database")].iter() {
+        for &(find_this, param_name) in [("{+parent}", "parent")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -7530,7 +4986,7 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["database"].iter() {
+            for param_name in ["parent"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -7563,7 +5019,7 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
@@ -7633,20 +5089,19 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: WriteRequest) -> ProjectDatabaseDocumentWriteCall<'a, C, A> {
+    pub fn request(mut self, new_value: GoogleFirestoreAdminV1Index) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {
         self._request = new_value;
         self
     }
-    /// The database name. In the format:
-    /// `projects/{project_id}/databases/{database_id}`.
-    /// This is only required in the first message.
+    /// A parent name of the form
+    /// `projects/{project_id}/databases/{database_id}/collectionGroups/{collection_id}`
     ///
-    /// Sets the *database* path property to the given value.
+    /// Sets the *parent* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn database(mut self, new_value: &str) -> ProjectDatabaseDocumentWriteCall<'a, C, A> {
-        self._database = new_value.to_string();
+    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {
+        self._parent = new_value.to_string();
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -7655,7 +5110,7 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentWriteCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -7680,7 +5135,7 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentWriteCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -7700,7 +5155,7 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentWriteCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseCollectionGroupIndexeCreateCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -7712,9 +5167,10 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
 }
 
 
-/// Updates or inserts a document.
+
+/// Streams batches of document updates and deletes, in order.
 ///
-/// A builder for the *databases.documents.patch* method supported by a *project* resource.
+/// A builder for the *databases.documents.write* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -7726,7 +5182,7 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::Document;
+/// use firestore1::WriteRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -7740,41 +5196,33 @@ impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyp
 /// // As the method needs a request, you would usually fill it with the desired information
 /// // into the respective structure. Some of the parts shown here might not be applicable !
 /// // Values shown here are possibly random and not representative !
-/// let mut req = Document::default();
+/// let mut req = WriteRequest::default();
 ///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_patch(req, "name")
-///              .add_update_mask_field_paths("ea")
-///              .add_mask_field_paths("sed")
-///              .current_document_update_time("dolor")
-///              .current_document_exists(true)
+/// let result = hub.projects().databases_documents_write(req, "database")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentPatchCall<'a, C, A>
+pub struct ProjectDatabaseDocumentWriteCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: Document,
-    _name: String,
-    _update_mask_field_paths: Vec<String>,
-    _mask_field_paths: Vec<String>,
-    _current_document_update_time: Option<String>,
-    _current_document_exists: Option<bool>,
+    _request: WriteRequest,
+    _database: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentPatchCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentWriteCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseDocumentWriteCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Document)> {
+    pub fn doit(mut self) -> Result<(hyper::client::Response, WriteResponse)> {
         use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
         use std::io::{Read, Seek};
         use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
@@ -7783,27 +5231,11 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.patch",
-                               http_method: hyper::method::Method::Patch });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(8 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        if self._update_mask_field_paths.len() > 0 {
-            for f in self._update_mask_field_paths.iter() {
-                params.push(("updateMask.fieldPaths", f.to_string()));
-            }
-        }
-        if self._mask_field_paths.len() > 0 {
-            for f in self._mask_field_paths.iter() {
-                params.push(("mask.fieldPaths", f.to_string()));
-            }
-        }
-        if let Some(value) = self._current_document_update_time {
-            params.push(("currentDocument.updateTime", value.to_string()));
-        }
-        if let Some(value) = self._current_document_exists {
-            params.push(("currentDocument.exists", value.to_string()));
-        }
-        for &field in ["alt", "name", "updateMask.fieldPaths", "mask.fieldPaths", "currentDocument.updateTime", "currentDocument.exists"].iter() {
+        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.write",
+                               http_method: hyper::method::Method::Post });
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
+        params.push(("database", self._database.to_string()));
+        for &field in ["alt", "database"].iter() {
             if self._additional_params.contains_key(field) {
                 dlg.finished(false);
                 return Err(Error::FieldClash(field));
@@ -7815,12 +5247,12 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
 
         params.push(("alt", "json".to_string()));
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
+        let mut url = self.hub._base_url.clone() + "v1/{+database}/documents:write";
         if self._scopes.len() == 0 {
             self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
         }
 
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
+        for &(find_this, param_name) in [("{+database}", "database")].iter() {
             let mut replace_with = String::new();
             for &(name, ref value) in params.iter() {
                 if name == param_name {
@@ -7835,7 +5267,7 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
         }
         {
             let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
+            for param_name in ["database"].iter() {
                 if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
                     indices_for_removal.push(index);
                 }
@@ -7868,7 +5300,7 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
@@ -7877,7 +5309,7 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
             request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
             let mut req_result = {
                 let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Patch, url.clone())
+                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
                     .header(UserAgent(self.hub._user_agent.clone()))
                     .header(auth_header.clone())
                     .header(ContentType(json_mime_type.clone()))
@@ -7938,53 +5370,20 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn request(mut self, new_value: Document) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+    pub fn request(mut self, new_value: WriteRequest) -> ProjectDatabaseDocumentWriteCall<'a, C, A> {
         self._request = new_value;
         self
     }
-    /// The resource name of the document, for example
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    /// This is only required in the first message.
     ///
-    /// Sets the *name* path property to the given value.
+    /// Sets the *database* path property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
-        self._name = new_value.to_string();
-        self
-    }
-    /// The list of field paths in the mask. See Document.fields for a field
-    /// path syntax reference.
-    ///
-    /// Append the given value to the *update mask.field paths* query property.
-    /// Each appended value will retain its original ordering and be '/'-separated in the URL's parameters.
-    pub fn add_update_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
-        self._update_mask_field_paths.push(new_value.to_string());
-        self
-    }
-    /// The list of field paths in the mask. See Document.fields for a field
-    /// path syntax reference.
-    ///
-    /// Append the given value to the *mask.field paths* query property.
-    /// Each appended value will retain its original ordering and be '/'-separated in the URL's parameters.
-    pub fn add_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
-        self._mask_field_paths.push(new_value.to_string());
-        self
-    }
-    /// When set, the target document must exist and have been last updated at
-    /// that time.
-    ///
-    /// Sets the *current document.update time* query property to the given value.
-    pub fn current_document_update_time(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
-        self._current_document_update_time = Some(new_value.to_string());
-        self
-    }
-    /// When set to `true`, the target document must exist.
-    /// When set to `false`, the target document must not exist.
-    ///
-    /// Sets the *current document.exists* query property to the given value.
-    pub fn current_document_exists(mut self, new_value: bool) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
-        self._current_document_exists = Some(new_value);
+    pub fn database(mut self, new_value: &str) -> ProjectDatabaseDocumentWriteCall<'a, C, A> {
+        self._database = new_value.to_string();
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -7993,7 +5392,7 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentWriteCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -8018,7 +5417,7 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentPatchCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentWriteCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -8038,7 +5437,7 @@ impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: BorrowMut<hyp
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentPatchCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentWriteCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -8188,7 +5587,7 @@ impl<'a, C, A> ProjectDatabaseOperationListCall<'a, C, A> where C: BorrowMut<hyp
                         Some(token) => token,
                         None => {
                             dlg.finished(false);
-                            return Err(Error::MissingToken(err))
+                            return Err(Error::MissingToken(err.to_string().into()))
                         }
                     }
                 }
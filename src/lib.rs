@@ -13,6 +13,13 @@ extern crate serde_json;
 extern crate yup_oauth2 as oauth2;
 extern crate mime;
 extern crate url;
+extern crate base64;
+#[cfg(feature = "async")]
+extern crate reqwest;
+#[cfg(feature = "async")]
+extern crate tokio;
+#[cfg(feature = "openssl")]
+extern crate openssl;
 
 mod cmn;
 mod firestore;
@@ -31,9 +38,63 @@ pub use query::*;
 mod collection;
 pub use collection::*;
 
+mod path;
+pub use path::*;
+
+#[cfg(feature = "blocking")]
+mod reference;
+#[cfg(feature = "blocking")]
+pub use reference::*;
+
+mod emulator;
+pub use emulator::*;
+
+#[cfg(feature = "openssl")]
+mod service_account;
+#[cfg(feature = "openssl")]
+pub use service_account::*;
+
+mod status;
+pub use status::*;
+
+#[cfg(feature = "blocking")]
+mod retry;
+#[cfg(feature = "blocking")]
+pub use retry::*;
+
+#[cfg(feature = "blocking")]
+pub mod testing;
+
+mod write;
+pub use write::*;
+
+mod convert;
+pub use convert::*;
+
+mod listen;
+pub use listen::*;
+
+mod transport;
+pub use transport::*;
+
+#[cfg(feature = "blocking")]
+mod stream;
+#[cfg(feature = "blocking")]
+pub use stream::*;
+
+mod method;
+
+#[cfg(feature = "blocking")]
+mod executor;
+
+#[cfg(feature = "blocking")]
 mod projectmethods;
+#[cfg(feature = "blocking")]
 pub use projectmethods::*;
 
+#[cfg(feature = "async")]
+pub mod asynchronous;
+
 use std::collections::HashMap;
 use std::cell::RefCell;
 use std::borrow::BorrowMut;
@@ -0,0 +1,733 @@
+use std::collections::BTreeMap;
+use std::mem;
+use std::thread::sleep;
+use std::time::Duration;
+use crate::cmn::*;
+use crate::firestore::*;
+use crate::document::*;
+use crate::query::*;
+use crate::listen::*;
+use crate::status::*;
+use crate::transport::Transport;
+
+/// The id of the single target a `Listener` adds to its stream.
+const TARGET_ID: i32 = 1;
+
+/// The maximum amount of consecutive attempts to reconnect a `Listener` without
+/// receiving a response.
+pub const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+
+/// The delay before the second attempt to reconnect, doubled for every further attempt.
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// The state of the target of a `Listener`, as reported by `TargetChange`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TargetState {
+    /// The target was requested, but not yet acknowledged by the server.
+    Pending,
+    /// The server added the target, or reset it, and is sending its documents.
+    Added,
+    /// All documents of the target were sent, and changes to them follow as they happen.
+    Current,
+}
+
+/// How a document changed between two snapshots.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// A document that changed between two snapshots.
+#[derive(Clone, Debug)]
+pub struct SnapshotChange {
+    pub kind: ChangeKind,
+    /// The new state of the document, or its last known state if it was removed.
+    pub document: Document,
+}
+
+/// A consistent view of the documents matching a listener's target.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    /// The time at which the documents were read.
+    pub read_time: String,
+    /// All documents matching the target, ordered by name.
+    pub documents: Vec<Document>,
+    /// The changes since the previous snapshot. For the first snapshot every document
+    /// is reported as added.
+    pub changes: Vec<SnapshotChange>,
+}
+
+/// A connection to the `Listen` method of Firestore.
+///
+/// Firestore serves `Listen` only over gRPC and WebChannel, not over the REST API the
+/// rest of this crate uses, and this crate implements neither protocol. A `Listener`
+/// therefore opens its streams through a `ListenChannel` supplied by the caller, usually
+/// a thin wrapper around a gRPC client of `google.firestore.v1.Firestore/Listen`.
+pub trait ListenChannel {
+    /// The responses of a stream, in the order the server sent them. It ends once the
+    /// server closed the stream, and after the first error.
+    type Responses: Iterator<Item = Result<ListenResponse>>;
+
+    /// Opens a new stream on `database`, in the format
+    /// `projects/{project_id}/databases/{database_id}`, and sends `request` as its first
+    /// message.
+    fn open(&mut self, database: &str, request: ListenRequest) -> Result<Self::Responses>;
+}
+
+/// Listens to the changes of a single document or the results of a query.
+///
+/// It is created by `Firestore::listen_document()` or `Firestore::listen_query()`,
+/// opens its streams through a `ListenChannel`, and yields a `Snapshot` whenever the server reached a consistent state in which
+/// documents changed. The first snapshot is always yielded, even if nothing matches.
+///
+/// If the stream is closed or interrupted, the listener reconnects and resumes from
+/// the last snapshot, so no change is missed. It gives up after `MAX_RECONNECT_ATTEMPTS`
+/// consecutive attempts which did not receive a response, yielding the error of the last
+/// attempt, or `Error::ListenStreamClosed` if the server kept closing the stream without
+/// an error. Other errors, and the removal of the target by the server, end the iteration
+/// as well.
+///
+/// If the server reports a different count of matching documents than the listener
+/// tracks, the listener drops its resume token and listens again, so the next snapshot
+/// is computed from a full resync.
+pub struct Listener<L>
+    where L: ListenChannel {
+
+    channel: L,
+    database: String,
+    target: Target,
+    stream: Option<L::Responses>,
+    state: TargetState,
+    resume_token: Option<String>,
+    read_time: Option<String>,
+    /// The documents as of the last snapshot.
+    documents: BTreeMap<String, Document>,
+    /// The documents changed since the last snapshot, `None` if they were removed.
+    changes: BTreeMap<String, Option<Document>>,
+    /// Whether the server resets the target, sending all of its documents again.
+    reset: bool,
+    first_snapshot: bool,
+    /// The consecutive attempts to connect without receiving a response.
+    attempts: u32,
+    reconnect_delay: Duration,
+    /// The error the last stream was closed with.
+    error: Option<Error>,
+    closed: bool,
+}
+
+/// Returns the database of a document or parent resource name, i.e. the part before
+/// `/documents`.
+fn database_of(name: &str) -> Result<String> {
+    let segments: Vec<&str> = name.split('/').collect();
+    if segments.len() < 5 || segments[0] != "projects" || segments[2] != "databases" || segments[4] != "documents" {
+        return Err(Error::InvalidPath(name.to_string()))
+    }
+    Ok(segments[..4].join("/"))
+}
+
+/// Whether reconnecting may succeed after `err`.
+fn is_transient(err: &Error) -> bool {
+    match *err {
+        Error::HttpError(_) | Error::JsonDecodeError(..) => true,
+        Error::Firestore(ref err) =>
+            matches!(err.code, Code::Unavailable | Code::ResourceExhausted | Code::DeadlineExceeded | Code::Internal),
+        _ => false,
+    }
+}
+
+fn targets(target_ids: &Option<Vec<i32>>, or_empty: bool) -> bool {
+    match *target_ids {
+        Some(ref ids) if !ids.is_empty() => ids.contains(&TARGET_ID),
+        _ => or_empty,
+    }
+}
+
+impl<L> Listener<L>
+    where L: ListenChannel {
+
+    fn new(channel: L, database: String, target: Target) -> Result<Listener<L>> {
+        let mut listener = Listener {
+            channel,
+            database,
+            target,
+            stream: None,
+            state: TargetState::Pending,
+            resume_token: None,
+            read_time: None,
+            documents: BTreeMap::new(),
+            changes: BTreeMap::new(),
+            reset: false,
+            first_snapshot: true,
+            attempts: 0,
+            reconnect_delay: RECONNECT_DELAY,
+            error: None,
+            closed: false,
+        };
+        listener.connect()?;
+        Ok(listener)
+    }
+
+    /// Opens a new stream adding the target, resuming from the last consistent
+    /// snapshot if there was one.
+    fn connect(&mut self) -> Result<()> {
+        let target = Target {
+            target_id: Some(TARGET_ID),
+            resume_token: self.resume_token.clone(),
+            ..self.target.clone()
+        };
+        let request = ListenRequest {
+            add_target: Some(target),
+            ..Default::default()
+        };
+        self.state = TargetState::Pending;
+        self.stream = Some(self.channel.open(&self.database, request)?);
+        Ok(())
+    }
+
+    /// Connects again after the stream was closed, discarding the changes since the
+    /// last snapshot, which the server sends again.
+    ///
+    /// `error` is the error the stream was closed with, if any. It is returned if the
+    /// listener gives up before it could connect again, `Error::ListenStreamClosed` if
+    /// there was none.
+    fn reconnect(&mut self, mut error: Option<Error>) -> Result<()> {
+        loop {
+            if self.attempts >= MAX_RECONNECT_ATTEMPTS {
+                self.closed = true;
+                return Err(error.unwrap_or(Error::ListenStreamClosed(self.attempts)))
+            }
+            if self.attempts > 0 {
+                sleep(self.reconnect_delay * 2u32.pow(self.attempts - 1));
+            }
+            self.attempts += 1;
+            self.changes.clear();
+            self.reset = self.resume_token.is_none();
+            match self.connect() {
+                Ok(()) => return Ok(()),
+                Err(err) => if is_transient(&err) {
+                    error = Some(err);
+                } else {
+                    self.closed = true;
+                    return Err(err)
+                },
+            }
+        }
+    }
+
+    /// The amount of documents matching the target if the pending changes were applied.
+    fn matching_documents(&self) -> usize {
+        let mut count = if self.reset { 0 } else { self.documents.len() };
+        for (name, document) in &self.changes {
+            let known = !self.reset && self.documents.contains_key(name);
+            match (document.is_some(), known) {
+                (true, false) => count += 1,
+                (false, true) => count -= 1,
+                _ => {},
+            }
+        }
+        count
+    }
+
+    /// The current state of the target.
+    pub fn state(&self) -> TargetState {
+        self.state
+    }
+
+    /// The token to resume listening after the last snapshot.
+    pub fn resume_token(&self) -> Option<&str> {
+        self.resume_token.as_deref()
+    }
+
+    /// The read time of the last snapshot.
+    pub fn read_time(&self) -> Option<&str> {
+        self.read_time.as_deref()
+    }
+
+    /// Applies a response to the target state, returning a snapshot if one is due.
+    fn apply(&mut self, response: ListenResponse) -> Result<Option<Snapshot>> {
+        if let Some(change) = response.target_change {
+            return self.apply_target_change(change)
+        }
+        if let Some(change) = response.document_change {
+            if let Some(document) = change.document {
+                let name = document.name.clone().unwrap_or_default();
+                if targets(&change.target_ids, false) {
+                    self.changes.insert(name, Some(document));
+                } else if targets(&change.removed_target_ids, false) {
+                    self.changes.insert(name, None);
+                }
+            }
+        } else if let Some(delete) = response.document_delete {
+            if let (Some(name), true) = (delete.document, targets(&delete.removed_target_ids, true)) {
+                self.changes.insert(name, None);
+            }
+        } else if let Some(remove) = response.document_remove {
+            if let (Some(name), true) = (remove.document, targets(&remove.removed_target_ids, true)) {
+                self.changes.insert(name, None);
+            }
+        } else if let Some(filter) = response.filter {
+            let count = filter.count.unwrap_or(0) as usize;
+            if filter.target_id == Some(TARGET_ID) && count != self.matching_documents() {
+                // Some removals were not sent, so listen again from scratch.
+                self.resume_token = None;
+                self.stream = None;
+            }
+        }
+        Ok(None)
+    }
+
+    fn apply_target_change(&mut self, change: TargetChange) -> Result<Option<Snapshot>> {
+        if !targets(&change.target_ids, true) {
+            return Ok(None)
+        }
+        match change.target_change_type.as_ref().map_or("NO_CHANGE", String::as_str) {
+            "ADD" => self.state = TargetState::Added,
+            "CURRENT" => self.state = TargetState::Current,
+            "RESET" => {
+                self.state = TargetState::Added;
+                self.reset = true;
+                self.changes.clear();
+            },
+            "REMOVE" => {
+                self.stream = None;
+                self.closed = true;
+                return Err(Error::TargetRemoved(change.cause))
+            },
+            _ => {
+                let global = change.target_ids.as_ref().map_or(0, Vec::len) == 0;
+                if let (true, TargetState::Current, Some(read_time)) = (global, self.state, change.read_time) {
+                    if change.resume_token.is_some() {
+                        self.resume_token = change.resume_token;
+                    }
+                    return Ok(self.snapshot(read_time))
+                }
+            },
+        }
+        Ok(None)
+    }
+
+    /// Applies the pending changes, returning a snapshot if any document changed.
+    fn snapshot(&mut self, read_time: String) -> Option<Snapshot> {
+        let previous = mem::take(&mut self.documents);
+        let mut documents = if self.reset { BTreeMap::new() } else { previous.clone() };
+        self.reset = false;
+        for (name, document) in mem::take(&mut self.changes) {
+            match document {
+                Some(document) => documents.insert(name, document),
+                None => documents.remove(&name),
+            };
+        }
+
+        let mut changes = Vec::new();
+        for (name, document) in &documents {
+            let kind = match previous.get(name) {
+                None => ChangeKind::Added,
+                Some(old) if old.update_time != document.update_time => ChangeKind::Modified,
+                Some(_) => continue,
+            };
+            changes.push(SnapshotChange { kind, document: document.clone() });
+        }
+        for (name, document) in &previous {
+            if !documents.contains_key(name) {
+                changes.push(SnapshotChange { kind: ChangeKind::Removed, document: document.clone() });
+            }
+        }
+        self.documents = documents;
+        self.read_time = Some(read_time.clone());
+
+        if changes.is_empty() && !self.first_snapshot {
+            return None
+        }
+        self.first_snapshot = false;
+        Some(Snapshot {
+            read_time,
+            documents: self.documents.values().cloned().collect(),
+            changes,
+        })
+    }
+}
+
+impl<L> Iterator for Listener<L>
+    where L: ListenChannel {
+    type Item = Result<Snapshot>;
+
+    fn next(&mut self) -> Option<Result<Snapshot>> {
+        loop {
+            if self.closed {
+                return None
+            }
+            if self.stream.is_none() {
+                let error = self.error.take();
+                if let Err(err) = self.reconnect(error) {
+                    return Some(Err(err))
+                }
+                continue
+            }
+            let response = match self.stream.as_mut().and_then(Iterator::next) {
+                Some(Ok(response)) => response,
+                Some(Err(err)) => {
+                    self.stream = None;
+                    if !is_transient(&err) {
+                        self.closed = true;
+                        return Some(Err(err))
+                    }
+                    self.error = Some(err);
+                    continue
+                },
+                None => {
+                    self.stream = None;
+                    continue
+                },
+            };
+            self.attempts = 0;
+            match self.apply(response) {
+                Ok(Some(snapshot)) => return Some(Ok(snapshot)),
+                Ok(None) => continue,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+impl<C, A> Firestore<C, A>
+    where C: Transport, A: oauth2::GetToken {
+
+    /// Listens through `channel` to the changes of the document with the given resource
+    /// name. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub fn listen_document<L, N>(&self, channel: L, name: N) -> Result<Listener<L>>
+        where L: ListenChannel, N: Into<String> {
+        let name = name.into();
+        let database = database_of(&name)?;
+        let target = Target {
+            documents: Some(DocumentsTarget { documents: Some(vec![name]) }),
+            ..Default::default()
+        };
+        Listener::new(channel, database, target)
+    }
+
+    /// Listens through `channel` to the results of `query` run on `parent`. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents` or
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub fn listen_query<L, P>(&self, channel: L, parent: P, query: StructuredQuery) -> Result<Listener<L>>
+        where L: ListenChannel, P: Into<String> {
+        let parent = parent.into();
+        let database = database_of(&parent)?;
+        let target = Target {
+            query: Some(QueryTarget {
+                structured_query: Some(query),
+                parent: Some(parent),
+            }),
+            ..Default::default()
+        };
+        Listener::new(channel, database, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+    use std::time::Duration;
+    use hyper::status::StatusCode;
+    use serde_json as json;
+    use crate::cmn::*;
+    use crate::emulator::EmulatorAuth;
+    use crate::firestore::Firestore;
+    use crate::listen::*;
+    use crate::query::*;
+    use crate::status::{Code, FirestoreError};
+    use crate::transport::InMemoryTransport;
+
+    const DATABASE: &str = "projects/p/databases/(default)";
+    const DOCUMENT: &str = "projects/p/databases/(default)/documents/users/alice";
+
+    /// What a `FakeChannel` answers to an attempt to open a stream.
+    enum Open {
+        Stream(Vec<Result<ListenResponse>>),
+        Fail(Error),
+    }
+
+    /// Answers the attempts to open a stream in the order they were scripted, and closes
+    /// every further stream right away.
+    #[derive(Clone, Default)]
+    struct FakeChannel {
+        script: Rc<RefCell<VecDeque<Open>>>,
+        requests: Rc<RefCell<Vec<ListenRequest>>>,
+    }
+
+    impl FakeChannel {
+        fn new(script: Vec<Open>) -> FakeChannel {
+            FakeChannel {
+                script: Rc::new(RefCell::new(script.into_iter().collect())),
+                ..Default::default()
+            }
+        }
+
+        /// The resume tokens of the targets added by the streams opened so far.
+        fn resume_tokens(&self) -> Vec<Option<String>> {
+            self.requests.borrow().iter()
+                .map(|r| r.add_target.as_ref().and_then(|t| t.resume_token.clone()))
+                .collect()
+        }
+    }
+
+    impl ListenChannel for FakeChannel {
+        type Responses = std::vec::IntoIter<Result<ListenResponse>>;
+
+        fn open(&mut self, database: &str, request: ListenRequest) -> Result<Self::Responses> {
+            assert_eq!(database, DATABASE);
+            self.requests.borrow_mut().push(request);
+            match self.script.borrow_mut().pop_front() {
+                Some(Open::Stream(responses)) => Ok(responses.into_iter()),
+                Some(Open::Fail(err)) => Err(err),
+                None => Ok(Vec::new().into_iter()),
+            }
+        }
+    }
+
+    fn response(value: json::Value) -> Result<ListenResponse> {
+        Ok(json::from_value(value).unwrap())
+    }
+
+    fn target_change(change_type: &str) -> Result<ListenResponse> {
+        response(json::json!({"targetChange": {"targetChangeType": change_type, "targetIds": [1]}}))
+    }
+
+    /// The global target change telling the client the target is consistent at `read_time`.
+    fn consistent(read_time: &str, resume_token: &str) -> Result<ListenResponse> {
+        response(json::json!({"targetChange": {"targetChangeType": "NO_CHANGE", "readTime": read_time,
+                                               "resumeToken": resume_token}}))
+    }
+
+    fn document_change(name: &str, update_time: &str) -> Result<ListenResponse> {
+        response(json::json!({"documentChange": {"document": {"name": name, "updateTime": update_time},
+                                                 "targetIds": [1]}}))
+    }
+
+    fn unavailable() -> Error {
+        Error::Firestore(FirestoreError::from_response(StatusCode::ServiceUnavailable,
+                                                       r#"{"error": {"status": "UNAVAILABLE"}}"#))
+    }
+
+    fn hub() -> Firestore<InMemoryTransport, EmulatorAuth> {
+        Firestore::new(InMemoryTransport::new(|_| panic!("no request is sent over REST")), EmulatorAuth)
+    }
+
+    fn listen(channel: &FakeChannel) -> Listener<FakeChannel> {
+        let mut listener = hub().listen_document(channel.clone(), DOCUMENT).unwrap();
+        listener.reconnect_delay = Duration::from_millis(0);
+        listener
+    }
+
+    #[test]
+    fn listen_through_references() {
+        let channel = FakeChannel::new(Vec::new());
+        let mut hub = hub();
+        assert!(matches!(hub.collection("users"), Err(Error::MissingDatabase)));
+        hub.database(DATABASE.parse().unwrap());
+        let users = hub.collection("users").unwrap();
+        users.doc("alice").unwrap().listen(channel.clone()).unwrap();
+        users.listen(channel.clone(), Query::collection("other").limit(3).into_structured_query()).unwrap();
+
+        let requests = channel.requests.borrow();
+        let document = requests[0].add_target.as_ref().and_then(|t| t.documents.as_ref()).unwrap();
+        assert_eq!(document.documents, Some(vec![DOCUMENT.to_string()]));
+        let query = requests[1].add_target.as_ref().and_then(|t| t.query.as_ref()).unwrap();
+        assert_eq!(query.parent.as_deref(), Some("projects/p/databases/(default)/documents"));
+        let structured_query = query.structured_query.as_ref().unwrap();
+        assert_eq!(structured_query.limit, Some(3));
+        let from = structured_query.from.as_ref().unwrap();
+        assert_eq!(from[0].collection_id.as_deref(), Some("users"));
+    }
+
+    #[test]
+    fn snapshots() {
+        let channel = FakeChannel::new(vec![Open::Stream(vec![
+            target_change("ADD"),
+            document_change(DOCUMENT, "1"),
+            target_change("CURRENT"),
+            consistent("10", "a"),
+            document_change(DOCUMENT, "2"),
+            consistent("11", "b"),
+            consistent("12", "c"),
+            response(json::json!({"documentDelete": {"document": DOCUMENT}})),
+            consistent("13", "d"),
+        ])]);
+        let mut listener = listen(&channel);
+
+        let snapshot = listener.next().unwrap().unwrap();
+        assert_eq!(snapshot.read_time, "10");
+        assert_eq!(snapshot.documents.len(), 1);
+        assert_eq!(snapshot.changes[0].kind, ChangeKind::Added);
+        assert_eq!(listener.state(), TargetState::Current);
+
+        let snapshot = listener.next().unwrap().unwrap();
+        assert_eq!(snapshot.read_time, "11");
+        assert_eq!(snapshot.changes[0].kind, ChangeKind::Modified);
+        assert_eq!(snapshot.changes[0].document.update_time.as_deref(), Some("2"));
+
+        // Nothing changed at "12", so the next snapshot is the deletion.
+        let snapshot = listener.next().unwrap().unwrap();
+        assert_eq!(snapshot.read_time, "13");
+        assert!(snapshot.documents.is_empty());
+        assert_eq!(snapshot.changes[0].kind, ChangeKind::Removed);
+        assert_eq!(listener.resume_token(), Some("d"));
+    }
+
+    #[test]
+    fn resumes_after_disconnects() {
+        let bob = "projects/p/databases/(default)/documents/users/bob";
+        let channel = FakeChannel::new(vec![
+            Open::Stream(vec![
+                target_change("ADD"),
+                document_change(DOCUMENT, "1"),
+                target_change("CURRENT"),
+                consistent("10", "a"),
+                document_change(bob, "1"),
+                Err(unavailable()),
+            ]),
+            Open::Fail(unavailable()),
+            Open::Stream(vec![
+                target_change("ADD"),
+                target_change("CURRENT"),
+                document_change(bob, "1"),
+                document_change(DOCUMENT, "2"),
+                consistent("11", "b"),
+            ]),
+        ]);
+        let mut listener = listen(&channel);
+        assert_eq!(listener.next().unwrap().unwrap().read_time, "10");
+
+        // The change of bob was not consistent yet when the stream broke, so it is
+        // discarded and sent again by the resumed stream.
+        let snapshot = listener.next().unwrap().unwrap();
+        assert_eq!(snapshot.read_time, "11");
+        assert_eq!(snapshot.documents.len(), 2);
+        let kinds: Vec<_> = snapshot.changes.iter()
+            .map(|c| (c.document.name.clone().unwrap(), c.kind))
+            .collect();
+        assert_eq!(kinds, vec![(DOCUMENT.to_string(), ChangeKind::Modified), (bob.to_string(), ChangeKind::Added)]);
+        assert_eq!(channel.resume_tokens(), vec![None, Some("a".to_string()), Some("a".to_string())]);
+    }
+
+    #[test]
+    fn resyncs_on_existence_filter_mismatch() {
+        let bob = "projects/p/databases/(default)/documents/users/bob";
+        let channel = FakeChannel::new(vec![
+            Open::Stream(vec![
+                target_change("ADD"),
+                document_change(DOCUMENT, "1"),
+                document_change(bob, "1"),
+                target_change("CURRENT"),
+                consistent("10", "a"),
+                response(json::json!({"filter": {"targetId": 1, "count": 2}})),
+                consistent("11", "b"),
+                // The removal of bob was missed.
+                response(json::json!({"filter": {"targetId": 1, "count": 1}})),
+                document_change(DOCUMENT, "3"),
+                consistent("12", "c"),
+            ]),
+            Open::Stream(vec![
+                target_change("ADD"),
+                document_change(DOCUMENT, "1"),
+                target_change("CURRENT"),
+                consistent("13", "d"),
+            ]),
+        ]);
+        let mut listener = listen(&channel);
+        assert_eq!(listener.next().unwrap().unwrap().documents.len(), 2);
+
+        // The matching filter kept the stream, the mismatch dropped it together with the
+        // resume token, so the documents are read again from scratch.
+        let snapshot = listener.next().unwrap().unwrap();
+        assert_eq!(snapshot.read_time, "13");
+        assert_eq!(snapshot.documents.len(), 1);
+        assert_eq!(snapshot.changes.len(), 1);
+        assert_eq!(snapshot.changes[0].kind, ChangeKind::Removed);
+        assert_eq!(snapshot.changes[0].document.name.as_deref(), Some(bob));
+        assert_eq!(listener.resume_token(), Some("d"));
+        assert_eq!(channel.resume_tokens(), vec![None, None]);
+    }
+
+    #[test]
+    fn resets_target() {
+        let bob = "projects/p/databases/(default)/documents/users/bob";
+        let channel = FakeChannel::new(vec![Open::Stream(vec![
+            target_change("ADD"),
+            document_change(DOCUMENT, "1"),
+            document_change(bob, "1"),
+            target_change("CURRENT"),
+            consistent("10", "a"),
+            target_change("RESET"),
+            document_change(bob, "1"),
+            target_change("CURRENT"),
+            consistent("11", "b"),
+        ])]);
+        let mut listener = listen(&channel);
+        assert_eq!(listener.next().unwrap().unwrap().documents.len(), 2);
+
+        let snapshot = listener.next().unwrap().unwrap();
+        assert_eq!(snapshot.documents.len(), 1);
+        assert_eq!(snapshot.changes[0].kind, ChangeKind::Removed);
+        assert_eq!(snapshot.changes[0].document.name.as_deref(), Some(DOCUMENT));
+    }
+
+    #[test]
+    fn gives_up_after_clean_closes() {
+        let channel = FakeChannel::new(Vec::new());
+        let mut listener = listen(&channel);
+        match listener.next() {
+            Some(Err(Error::ListenStreamClosed(attempts))) => assert_eq!(attempts, MAX_RECONNECT_ATTEMPTS),
+            other => panic!("unexpected item: {:?}", other.map(|r| r.map_err(|e| e.to_string()))),
+        }
+        assert!(listener.next().is_none());
+        assert_eq!(channel.requests.borrow().len(), 1 + MAX_RECONNECT_ATTEMPTS as usize);
+    }
+
+    #[test]
+    fn gives_up_with_last_error() {
+        let mut script = vec![Open::Stream(Vec::new())];
+        script.extend((0..MAX_RECONNECT_ATTEMPTS).map(|_| Open::Fail(unavailable())));
+        let channel = FakeChannel::new(script);
+        let mut listener = listen(&channel);
+        match listener.next() {
+            Some(Err(err)) => assert_eq!(err.code(), Some(Code::Unavailable)),
+            other => panic!("unexpected item: {:?}", other.map(|r| r.map_err(|e| e.to_string()))),
+        }
+        assert!(listener.next().is_none());
+    }
+
+    #[test]
+    fn stops_on_permanent_errors() {
+        let permission_denied = Error::Firestore(FirestoreError::from_response(StatusCode::Forbidden, ""));
+        let channel = FakeChannel::new(vec![Open::Stream(Vec::new()), Open::Fail(permission_denied)]);
+        let mut listener = listen(&channel);
+        match listener.next() {
+            Some(Err(err)) => assert_eq!(err.code(), Some(Code::PermissionDenied)),
+            other => panic!("unexpected item: {:?}", other.map(|r| r.map_err(|e| e.to_string()))),
+        }
+        assert!(listener.next().is_none());
+        assert_eq!(channel.resume_tokens(), vec![None, None]);
+    }
+
+    #[test]
+    fn stops_on_permanent_stream_errors() {
+        let permission_denied = Error::Firestore(FirestoreError::from_response(StatusCode::Forbidden,
+                                                                               r#"{"error": {"status": "PERMISSION_DENIED"}}"#));
+        let channel = FakeChannel::new(vec![
+            Open::Stream(vec![target_change("ADD"), Err(permission_denied)]),
+            Open::Stream(vec![target_change("ADD")]),
+        ]);
+        let mut listener = listen(&channel);
+        match listener.next() {
+            Some(Err(err)) => assert_eq!(err.code(), Some(Code::PermissionDenied)),
+            other => panic!("unexpected item: {:?}", other.map(|r| r.map_err(|e| e.to_string()))),
+        }
+        assert!(listener.next().is_none());
+        assert_eq!(channel.resume_tokens(), vec![None]);
+    }
+}
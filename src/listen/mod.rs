@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+use crate::cmn::*;
+use crate::document::*;
+use crate::query::*;
+
+#[cfg(feature = "blocking")]
+mod listener;
+#[cfg(feature = "blocking")]
+pub use listener::*;
+
+/// A request for Firestore.Listen
+///
+/// Sent by a `Listener` to open a stream, see `ListenChannel::open()`.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct ListenRequest {
+    /// A target to add to this stream.
+    #[serde(rename="addTarget")]
+    pub add_target: Option<Target>,
+    /// Labels associated with this target change.
+    pub labels: Option<HashMap<String, String>>,
+    /// The ID of a target to remove from this stream.
+    #[serde(rename="removeTarget")]
+    pub remove_target: Option<i32>,
+}
+
+impl RequestValue for ListenRequest {}
+
+
+/// The response for Firestore.Listen.
+///
+/// Received by a `Listener` on a stream opened by a `ListenChannel`.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct ListenResponse {
+    /// A filter to apply to the set of documents previously returned for the
+    /// given target.
+    ///
+    /// Returned when documents may have been removed from the given target, but
+    /// the exact documents are unknown.
+    pub filter: Option<ExistenceFilter>,
+    /// A Document has been deleted.
+    #[serde(rename="documentDelete")]
+    pub document_delete: Option<DocumentDelete>,
+    /// A Document has changed.
+    #[serde(rename="documentChange")]
+    pub document_change: Option<DocumentChange>,
+    /// A Document has been removed from a target (because it is no longer
+    /// relevant to that target).
+    #[serde(rename="documentRemove")]
+    pub document_remove: Option<DocumentRemove>,
+    /// Targets have changed.
+    #[serde(rename="targetChange")]
+    pub target_change: Option<TargetChange>,
+}
+
+impl ResponseResult for ListenResponse {}
+
+
+/// A specification of a set of documents to listen to.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Target {
+    /// A target specified by a set of document names.
+    pub documents: Option<DocumentsTarget>,
+    /// If the target should be removed once it is current and consistent.
+    pub once: Option<bool>,
+    /// A target specified by a query.
+    pub query: Option<QueryTarget>,
+    /// A resume token from a prior TargetChange for an identical target.
+    ///
+    /// Using a resume token with a different target is unsupported and may fail.
+    #[serde(rename="resumeToken")]
+    pub resume_token: Option<String>,
+    /// A client provided target ID.
+    ///
+    /// If not set, the server will assign an ID for the target.
+    ///
+    /// Used for resuming a target without changing IDs. The IDs can either be
+    /// client-assigned or be server-assigned in a previous stream. All targets
+    /// with client provided IDs must be added before adding a target that needs
+    /// a server-assigned id.
+    #[serde(rename="targetId")]
+    pub target_id: Option<i32>,
+    /// Start listening after a specific `read_time`.
+    ///
+    /// The client must know the state of matching documents at this time.
+    #[serde(rename="readTime")]
+    pub read_time: Option<String>,
+}
+
+impl Part for Target {}
+
+
+/// A target specified by a set of documents names.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct DocumentsTarget {
+    /// The names of the documents to retrieve. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    /// The request will fail if any of the document is not a child resource of
+    /// the given `database`. Duplicate names will be elided.
+    pub documents: Option<Vec<String>>,
+}
+
+impl Part for DocumentsTarget {}
+
+
+/// A target specified by a query.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct QueryTarget {
+    /// A structured query.
+    #[serde(rename="structuredQuery")]
+    pub structured_query: Option<StructuredQuery>,
+    /// The parent resource name. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents` or
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    /// For example:
+    /// `projects/my-project/databases/my-database/documents` or
+    /// `projects/my-project/databases/my-database/documents/chatrooms/my-chatroom`
+    pub parent: Option<String>,
+}
+
+impl Part for QueryTarget {}
+
+
+/// Targets being watched have changed.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct TargetChange {
+    /// The type of change that occurred.
+    #[serde(rename="targetChangeType")]
+    pub target_change_type: Option<String>,
+    /// A token that can be used to resume the stream for the given `target_ids`,
+    /// or all targets if `target_ids` is empty.
+    ///
+    /// Not set on every target change.
+    #[serde(rename="resumeToken")]
+    pub resume_token: Option<String>,
+    /// The error that resulted in this change, if applicable.
+    pub cause: Option<Status>,
+    /// The target IDs of targets that have changed.
+    ///
+    /// If empty, the change applies to all targets.
+    ///
+    /// For `target_change_type=ADD`, the order of the target IDs matches the order
+    /// of the requests to add the targets. This allows clients to unambiguously
+    /// associate server-assigned target IDs with added targets.
+    ///
+    /// For other states, the order of the target IDs is not defined.
+    #[serde(rename="targetIds")]
+    pub target_ids: Option<Vec<i32>>,
+    /// The consistent `read_time` for the given `target_ids` (omitted when the
+    /// target_ids are not at a consistent snapshot).
+    ///
+    /// The stream is guaranteed to send a `read_time` with `target_ids` empty
+    /// whenever the entire stream reaches a new consistent snapshot. ADD,
+    /// CURRENT, and RESET messages are guaranteed to (eventually) result in a
+    /// new consistent snapshot (while NO_CHANGE and REMOVE messages are not).
+    ///
+    /// For a given stream, `read_time` is guaranteed to be monotonically
+    /// increasing.
+    #[serde(rename="readTime")]
+    pub read_time: Option<String>,
+}
+
+impl Part for TargetChange {}
+
+
+/// A Document has changed.
+///
+/// May be the result of multiple writes, including deletes, that
+/// ultimately resulted in a new value for the Document.
+///
+/// Multiple DocumentChange messages may be returned for the same logical
+/// change, if multiple targets are affected.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct DocumentChange {
+    /// A set of target IDs for targets that no longer match this document.
+    #[serde(rename="removedTargetIds")]
+    pub removed_target_ids: Option<Vec<i32>>,
+    /// The new state of the Document.
+    ///
+    /// If `mask` is set, contains only fields that were updated or added.
+    pub document: Option<Document>,
+    /// A set of target IDs of targets that match this document.
+    #[serde(rename="targetIds")]
+    pub target_ids: Option<Vec<i32>>,
+}
+
+impl Part for DocumentChange {}
+
+
+/// A Document has been removed from the view of the targets.
+///
+/// Sent if the document is no longer relevant to a target and is out of view.
+/// Can be sent instead of a DocumentDelete or a DocumentChange if the server
+/// can not send the new value of the document.
+///
+/// Multiple DocumentRemove messages may be returned for the same logical
+/// write or delete, if multiple targets are affected.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct DocumentRemove {
+    /// The resource name of the Document that has gone out of view.
+    pub document: Option<String>,
+    /// A set of target IDs for targets that previously matched this document.
+    #[serde(rename="removedTargetIds")]
+    pub removed_target_ids: Option<Vec<i32>>,
+    /// The read timestamp at which the remove was observed.
+    ///
+    /// Greater or equal to the `commit_time` of the change/delete/remove.
+    #[serde(rename="readTime")]
+    pub read_time: Option<String>,
+}
+
+impl Part for DocumentRemove {}
+
+
+/// A Document has been deleted.
+///
+/// May be the result of multiple writes, including updates, the
+/// last of which deleted the Document.
+///
+/// Multiple DocumentDelete messages may be returned for the same logical
+/// delete, if multiple targets are affected.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct DocumentDelete {
+    /// The resource name of the Document that was deleted.
+    pub document: Option<String>,
+    /// A set of target IDs for targets that previously matched this entity.
+    #[serde(rename="removedTargetIds")]
+    pub removed_target_ids: Option<Vec<i32>>,
+    /// The read timestamp at which the delete was observed.
+    ///
+    /// Greater or equal to the `commit_time` of the delete.
+    #[serde(rename="readTime")]
+    pub read_time: Option<String>,
+}
+
+impl Part for DocumentDelete {}
+
+
+/// A digest of all the documents that match a given target.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct ExistenceFilter {
+    /// The total count of documents that match target_id.
+    ///
+    /// If different from the count of documents in the client that match, the
+    /// client must manually determine which documents no longer match the target.
+    pub count: Option<i32>,
+    /// The target ID to which this filter applies.
+    #[serde(rename="targetId")]
+    pub target_id: Option<i32>,
+}
+
+impl Part for ExistenceFilter {}
+
+
+/// The `Status` type defines a logical error model that is suitable for
+/// different programming environments, including REST APIs and RPC APIs. It is
+/// used by [gRPC](https://github.com/grpc). The error model is designed to be:
+///
+/// * Simple to use and understand for most users
+/// * Flexible enough to meet unexpected needs
+///
+/// # Overview
+///
+/// The `Status` message contains three pieces of data: error code, error
+/// message, and error details. The error code should be an enum value of
+/// google.rpc.Code, but it may accept additional error codes if needed.  The
+/// error message should be a developer-facing English message that helps
+/// developers *understand* and *resolve* the error. If a localized user-facing
+/// error message is needed, put the localized message in the error details or
+/// localize it in the client. The optional error details may contain arbitrary
+/// information about the error. There is a predefined set of error detail types
+/// in the package `google.rpc` that can be used for common error conditions.
+///
+/// # Language mapping
+///
+/// The `Status` message is the logical representation of the error model, but it
+/// is not necessarily the actual wire format. When the `Status` message is
+/// exposed in different client libraries and different wire protocols, it can be
+/// mapped differently. For example, it will likely be mapped to some exceptions
+/// in Java, but more likely mapped to some error codes in C.
+///
+/// # Other uses
+///
+/// The error model and the `Status` message can be used in a variety of
+/// environments, either with or without APIs, to provide a
+/// consistent developer experience across different environments.
+///
+/// Example uses of this error model include:
+///
+/// * Partial errors. If a service needs to return partial errors to the client,
+///   it may embed the `Status` in the normal response to indicate the partial
+///   errors.
+///
+/// * Workflow errors. A typical workflow has multiple steps. Each step may
+///   have a `Status` message for error reporting.
+///
+/// * Batch operations. If a client uses batch request and batch response, the
+///   `Status` message should be used directly inside batch response, one for
+///   each error sub-response.
+///
+/// * Asynchronous operations. If an API call embeds asynchronous operation
+///   results in its response, the status of those operations should be
+///   represented directly using the `Status` message.
+///
+/// * Logging. If some API errors are stored in logs, the message `Status` could
+///   be used directly after any stripping needed for security/privacy reasons.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Status {
+    /// A developer-facing error message, which should be in English. Any
+    /// user-facing error message should be localized and sent in the
+    /// google.rpc.Status.details field, or localized by the client.
+    pub message: Option<String>,
+    /// The status code, which should be an enum value of google.rpc.Code.
+    pub code: Option<i32>,
+    /// A list of messages that carry the error details.  There is a common set of
+    /// message types for APIs to use.
+    pub details: Option<Vec<HashMap<String, String>>>,
+}
+
+impl Part for Status {}
@@ -0,0 +1,146 @@
+use std::collections::{BTreeMap, HashMap};
+use hyper::method::Method;
+use serde::Serialize;
+use serde_json as json;
+use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
+use crate::cmn::*;
+
+/// The properties of an API method which are the same for all of its calls.
+pub(crate) struct ApiMethod {
+    /// The id of the method, e.g. `firestore.projects.databases.documents.get`.
+    pub id: &'static str,
+    pub http_method: hyper::method::Method,
+    /// The url relative to the base url, with parameters like `{+name}` which are
+    /// replaced by the `url_params` of a call.
+    pub url: &'static str,
+    /// The parameters which have their own setters, and may not be set with `param()`.
+    pub params: &'static [&'static str],
+}
+
+/// The request of a single call of an `ApiMethod`, as built by its call builder.
+pub(crate) struct CallRequest {
+    pub method: &'static ApiMethod,
+    /// The values of the parameters in the url of the method, e.g. `("{+name}", name)`.
+    pub url_params: Vec<(&'static str, String)>,
+    /// The query parameters set with the setters of the call builder.
+    pub params: Vec<(&'static str, String)>,
+    /// The query parameters set with `param()`.
+    pub additional_params: HashMap<String, String>,
+    /// The scopes set with `add_scope()`. The scopes of the hub are used if it is empty.
+    pub scopes: BTreeMap<String, ()>,
+    /// The JSON body, if the method has one.
+    pub body: Option<Vec<u8>>,
+}
+
+impl CallRequest {
+
+    /// Fails with `Error::FieldClash` if a parameter with its own setter was set with `param()`.
+    pub fn check_params(&self) -> Result<()> {
+        for &field in self.method.params {
+            if self.additional_params.contains_key(field) {
+                return Err(Error::FieldClash(field));
+            }
+        }
+        Ok(())
+    }
+
+    /// The url of the request below `base_url`, with all of its query parameters.
+    pub fn url(&self, base_url: &str) -> hyper::Url {
+        let mut params: Vec<(&str, &str)> = Vec::with_capacity(self.params.len() + self.additional_params.len() + 1);
+        for &(name, ref value) in self.params.iter() {
+            params.push((name, value));
+        }
+        for (name, value) in self.additional_params.iter() {
+            params.push((name, value));
+        }
+        params.push(("alt", "json"));
+
+        let mut url = base_url.to_string() + self.method.url;
+        for &(find_this, ref value) in self.url_params.iter() {
+            let replace_with = if find_this.as_bytes()[1] == b'+' {
+                percent_encode(value.as_bytes(), DEFAULT_ENCODE_SET).to_string()
+            } else {
+                value.to_string()
+            };
+            url = url.replace(find_this, &replace_with);
+        }
+        hyper::Url::parse_with_params(&url, params).unwrap()
+    }
+}
+
+/// Encodes the request value of a call, leaving out all fields which are not set.
+pub(crate) fn json_body<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut value = json::value::to_value(value).expect("serde to work");
+    remove_json_null_values(&mut value);
+    json::to_vec(&value).expect("serde to work")
+}
+
+pub(crate) const GET: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.get",
+    http_method: Method::Get,
+    url: "v1/{+name}",
+    params: &["alt", "name", "transaction", "readTime", "mask.fieldPaths"],
+};
+
+pub(crate) const RUN_QUERY: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.runQuery",
+    http_method: Method::Post,
+    url: "v1/{+parent}:runQuery",
+    params: &["alt", "parent"],
+};
+
+pub(crate) const BATCH_GET: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.batchGet",
+    http_method: Method::Post,
+    url: "v1/{+database}/documents:batchGet",
+    params: &["alt", "database"],
+};
+
+pub(crate) const BEGIN_TRANSACTION: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.beginTransaction",
+    http_method: Method::Post,
+    url: "v1/{+database}/documents:beginTransaction",
+    params: &["alt", "database"],
+};
+
+pub(crate) const COMMIT: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.commit",
+    http_method: Method::Post,
+    url: "v1/{+database}/documents:commit",
+    params: &["alt", "database"],
+};
+
+pub(crate) const ROLLBACK: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.rollback",
+    http_method: Method::Post,
+    url: "v1/{+database}/documents:rollback",
+    params: &["alt", "database"],
+};
+
+pub(crate) const DELETE: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.delete",
+    http_method: Method::Delete,
+    url: "v1/{+name}",
+    params: &["alt", "name", "currentDocument.updateTime", "currentDocument.exists"],
+};
+
+pub(crate) const CREATE_DOCUMENT: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.createDocument",
+    http_method: Method::Post,
+    url: "v1/{+parent}/{collectionId}",
+    params: &["alt", "parent", "collectionId", "mask.fieldPaths", "documentId"],
+};
+
+pub(crate) const LIST: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.list",
+    http_method: Method::Get,
+    url: "v1/{+parent}/{collectionId}",
+    params: &["alt", "parent", "collectionId", "transaction", "showMissing", "readTime", "pageToken", "pageSize", "orderBy", "mask.fieldPaths"],
+};
+
+pub(crate) const PATCH: ApiMethod = ApiMethod {
+    id: "firestore.projects.databases.documents.patch",
+    http_method: Method::Patch,
+    url: "v1/{+name}",
+    params: &["alt", "name", "updateMask.fieldPaths", "mask.fieldPaths", "currentDocument.updateTime", "currentDocument.exists"],
+};
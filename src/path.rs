@@ -0,0 +1,363 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use crate::cmn::*;
+
+/// Checks that `id` can be used as a single segment of a resource name.
+fn validate_id(id: &str, path: &dyn Fn() -> String) -> Result<()> {
+    let reserved = id.len() >= 4 && id.starts_with("__") && id.ends_with("__");
+    if id.is_empty() || id == "." || id == ".." || id.contains('/') || reserved {
+        return Err(Error::InvalidPath(path()))
+    }
+    Ok(())
+}
+
+/// The resource name of a database, `projects/{project_id}/databases/{database_id}`.
+///
+/// It is accepted wherever a call takes the name of a database.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DatabasePath {
+    project_id: String,
+    database_id: String,
+}
+
+impl DatabasePath {
+
+    /// The database with the given id in the given project.
+    pub fn new(project_id: &str, database_id: &str) -> Result<DatabasePath> {
+        let path = DatabasePath {
+            project_id: project_id.to_string(),
+            database_id: database_id.to_string(),
+        };
+        if project_id.is_empty() || project_id.contains('/') || database_id.is_empty() || database_id.contains('/') {
+            return Err(Error::InvalidPath(path.to_string()))
+        }
+        Ok(path)
+    }
+
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+
+    pub fn database_id(&self) -> &str {
+        &self.database_id
+    }
+
+    /// The id of the database, its last segment. The same as `database_id()`.
+    pub fn id(&self) -> &str {
+        &self.database_id
+    }
+
+    /// The resource name of the root of all documents, which is the parent of the
+    /// root collections. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents`.
+    pub fn documents(&self) -> String {
+        format!("{}/documents", self)
+    }
+
+    /// The root collection with the given id.
+    pub fn collection(&self, id: &str) -> Result<CollectionPath> {
+        validate_id(id, &|| format!("{}/{}", self.documents(), id))?;
+        Ok(CollectionPath {
+            database: self.clone(),
+            segments: vec![id.to_string()],
+        })
+    }
+}
+
+impl Display for DatabasePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "projects/{}/databases/{}", self.project_id, self.database_id)
+    }
+}
+
+impl FromStr for DatabasePath {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<DatabasePath> {
+        match *name.split('/').collect::<Vec<_>>() {
+            ["projects", project_id, "databases", database_id] => DatabasePath::new(project_id, database_id),
+            _ => Err(Error::InvalidPath(name.to_string())),
+        }
+    }
+}
+
+/// Splits a document or collection name into its database and the segments after
+/// `documents`, checking each of them.
+fn parse_segments(name: &str) -> Result<(DatabasePath, Vec<String>)> {
+    let segments: Vec<&str> = name.split('/').collect();
+    if segments.len() < 6 || segments[4] != "documents" {
+        return Err(Error::InvalidPath(name.to_string()))
+    }
+    let database = segments[..4].join("/").parse()
+        .map_err(|_| Error::InvalidPath(name.to_string()))?;
+    for id in &segments[5..] {
+        validate_id(id, &|| name.to_string())?;
+    }
+    Ok((database, segments[5..].iter().map(|s| s.to_string()).collect()))
+}
+
+fn fmt_segments(database: &DatabasePath, segments: &[String], f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}/documents/{}", database, segments.join("/"))
+}
+
+/// The resource name of a collection. In the format:
+/// `projects/{project_id}/databases/{database_id}/documents/{collection_path}`.
+///
+/// The collection path has an odd number of segments, alternating between
+/// collection and document ids.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CollectionPath {
+    database: DatabasePath,
+    segments: Vec<String>,
+}
+
+impl CollectionPath {
+
+    pub fn database(&self) -> &DatabasePath {
+        &self.database
+    }
+
+    /// The id of the collection, its last segment.
+    pub fn id(&self) -> &str {
+        self.segments.last().map_or("", String::as_str)
+    }
+
+    /// The document containing this collection, or `None` for a root collection.
+    pub fn parent(&self) -> Option<DocumentPath> {
+        if self.segments.len() < 2 {
+            return None
+        }
+        Some(DocumentPath {
+            database: self.database.clone(),
+            segments: self.segments[..self.segments.len() - 1].to_vec(),
+        })
+    }
+
+    /// The resource name expected as `parent` by calls taking a parent and a collection
+    /// id. It is the name of the parent document, or the root of all documents.
+    pub fn parent_name(&self) -> String {
+        match self.parent() {
+            Some(parent) => parent.to_string(),
+            None => self.database.documents(),
+        }
+    }
+
+    /// The document with the given id in this collection.
+    pub fn doc(&self, id: &str) -> Result<DocumentPath> {
+        validate_id(id, &|| format!("{}/{}", self, id))?;
+        let mut segments = self.segments.clone();
+        segments.push(id.to_string());
+        Ok(DocumentPath {
+            database: self.database.clone(),
+            segments,
+        })
+    }
+}
+
+impl Display for CollectionPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_segments(&self.database, &self.segments, f)
+    }
+}
+
+impl FromStr for CollectionPath {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<CollectionPath> {
+        let (database, segments) = parse_segments(name)?;
+        if segments.len() % 2 == 0 {
+            return Err(Error::InvalidPath(name.to_string()))
+        }
+        Ok(CollectionPath { database, segments })
+    }
+}
+
+/// The resource name of a document. In the format:
+/// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+///
+/// The document path has an even number of segments, alternating between
+/// collection and document ids. It is accepted wherever a call takes the name of
+/// a document, or the name of a parent document.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentPath {
+    database: DatabasePath,
+    segments: Vec<String>,
+}
+
+impl DocumentPath {
+
+    pub fn database(&self) -> &DatabasePath {
+        &self.database
+    }
+
+    /// The id of the document, its last segment.
+    pub fn id(&self) -> &str {
+        self.segments.last().map_or("", String::as_str)
+    }
+
+    /// The collection containing this document.
+    pub fn parent(&self) -> CollectionPath {
+        CollectionPath {
+            database: self.database.clone(),
+            segments: self.segments[..self.segments.len() - 1].to_vec(),
+        }
+    }
+
+    /// The subcollection with the given id of this document.
+    pub fn collection(&self, id: &str) -> Result<CollectionPath> {
+        validate_id(id, &|| format!("{}/{}", self, id))?;
+        let mut segments = self.segments.clone();
+        segments.push(id.to_string());
+        Ok(CollectionPath {
+            database: self.database.clone(),
+            segments,
+        })
+    }
+}
+
+impl Display for DocumentPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_segments(&self.database, &self.segments, f)
+    }
+}
+
+impl FromStr for DocumentPath {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<DocumentPath> {
+        let (database, segments) = parse_segments(name)?;
+        if segments.len() % 2 == 1 {
+            return Err(Error::InvalidPath(name.to_string()))
+        }
+        Ok(DocumentPath { database, segments })
+    }
+}
+
+impl From<DatabasePath> for String {
+    fn from(path: DatabasePath) -> String {
+        path.to_string()
+    }
+}
+
+impl<'a> From<&'a DatabasePath> for String {
+    fn from(path: &'a DatabasePath) -> String {
+        path.to_string()
+    }
+}
+
+impl From<CollectionPath> for String {
+    fn from(path: CollectionPath) -> String {
+        path.to_string()
+    }
+}
+
+impl<'a> From<&'a CollectionPath> for String {
+    fn from(path: &'a CollectionPath) -> String {
+        path.to_string()
+    }
+}
+
+impl<'a> From<&'a CollectionPath> for CollectionPath {
+    fn from(path: &'a CollectionPath) -> CollectionPath {
+        path.clone()
+    }
+}
+
+impl From<DocumentPath> for String {
+    fn from(path: DocumentPath) -> String {
+        path.to_string()
+    }
+}
+
+impl<'a> From<&'a DocumentPath> for String {
+    fn from(path: &'a DocumentPath) -> String {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cmn::*;
+    use super::*;
+
+    fn invalid<T: FromStr<Err = Error>>(name: &str) -> bool {
+        match name.parse::<T>() {
+            Err(Error::InvalidPath(ref path)) => path == name,
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn database_paths() {
+        let database: DatabasePath = "projects/p/databases/(default)".parse().unwrap();
+        assert_eq!(database.project_id(), "p");
+        assert_eq!(database.id(), "(default)");
+        assert_eq!(database.documents(), "projects/p/databases/(default)/documents");
+        assert_eq!(String::from(&database), "projects/p/databases/(default)");
+
+        for name in &["", "projects/p", "projects/p/databases", "projects/p/databases/d/documents",
+                      "projects//databases/d", "projects/p/databases/", "project/p/databases/d"] {
+            assert!(invalid::<DatabasePath>(name), "{:?} was accepted", name);
+        }
+        assert!(DatabasePath::new("p", "").is_err());
+        assert!(DatabasePath::new("", "d").is_err());
+        assert!(DatabasePath::new("p/q", "d").is_err());
+    }
+
+    #[test]
+    fn collection_and_document_paths() {
+        let name = "projects/p/databases/d/documents/users/alice/posts";
+        let posts: CollectionPath = name.parse().unwrap();
+        assert_eq!(posts.id(), "posts");
+        assert_eq!(String::from(&posts), name);
+        assert_eq!(posts.parent_name(), "projects/p/databases/d/documents/users/alice");
+
+        let users = posts.parent().unwrap().parent();
+        assert_eq!(users.id(), "users");
+        assert!(users.parent().is_none());
+        assert_eq!(users.parent_name(), "projects/p/databases/d/documents");
+        assert_eq!(users.database().collection("users").unwrap(), users);
+
+        let post = posts.doc("first").unwrap();
+        assert_eq!(String::from(post.clone()), format!("{}/first", name));
+        assert_eq!(post.to_string().parse::<DocumentPath>().unwrap(), post);
+        assert_eq!(post.collection("comments").unwrap().to_string(), format!("{}/first/comments", name));
+    }
+
+    #[test]
+    fn bad_segment_counts() {
+        // Collections have an odd, documents an even number of segments after `documents`.
+        assert!(invalid::<CollectionPath>("projects/p/databases/d/documents"));
+        assert!(invalid::<CollectionPath>("projects/p/databases/d/documents/users/alice"));
+        assert!(invalid::<DocumentPath>("projects/p/databases/d/documents"));
+        assert!(invalid::<DocumentPath>("projects/p/databases/d/documents/users"));
+        assert!(invalid::<DocumentPath>("projects/p/databases/d/documents/users/alice/posts"));
+        assert!(invalid::<DocumentPath>("projects/p/databases/d/users/alice"));
+        assert!(invalid::<DocumentPath>("projects/p/documents/users/alice"));
+    }
+
+    #[test]
+    fn empty_ids() {
+        assert!(invalid::<CollectionPath>("projects/p/databases/d/documents/"));
+        assert!(invalid::<DocumentPath>("projects/p/databases/d/documents/users/"));
+        assert!(invalid::<DocumentPath>("projects/p/databases/d/documents//alice"));
+        assert!(invalid::<DocumentPath>("projects/p/databases//documents/users/alice"));
+        let database = DatabasePath::new("p", "d").unwrap();
+        assert!(database.collection("").is_err());
+        assert!(database.collection("users").unwrap().doc("").is_err());
+    }
+
+    #[test]
+    fn reserved_ids() {
+        for id in &["__name__", "__x__", "____", ".", ".."] {
+            assert!(invalid::<DocumentPath>(&format!("projects/p/databases/d/documents/users/{}", id)),
+                    "{:?} was accepted", id);
+            assert!(DatabasePath::new("p", "d").unwrap().collection(id).is_err(), "{:?} was accepted", id);
+        }
+        let users = DatabasePath::new("p", "d").unwrap().collection("users").unwrap();
+        for id in &["__", "___", "__x", "x__", "_x_"] {
+            assert_eq!(users.doc(id).unwrap().id(), *id);
+        }
+        assert!(users.doc("a/b").is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::firestore::*;
 use crate::cmn::*;
 use crate::*;
+use crate::method::*;
 
 pub struct ProjectMethods<'a, C, A>
     where C: 'a, A: 'a {
@@ -19,10 +20,10 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
     ///
     /// * `name` - The resource name of the Document to get. In the format:
     ///            `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    pub fn databases_documents_get(&self, name: &str) -> ProjectDatabaseDocumentGetCall<'a, C, A> {
+    pub fn databases_documents_get<N: Into<String>>(&self, name: N) -> ProjectDatabaseDocumentGetCall<'a, C, A> {
         ProjectDatabaseDocumentGetCall {
             hub: self.hub,
-            _name: name.to_string(),
+            _name: name.into(),
             _transaction: Default::default(),
             _read_time: Default::default(),
             _mask_field_paths: Default::default(),
@@ -44,11 +45,11 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
     ///              For example:
     ///              `projects/my-project/databases/my-database/documents` or
     ///              `projects/my-project/databases/my-database/documents/chatrooms/my-chatroom`
-    pub fn databases_documents_run_query(&self, request: RunQueryRequest, parent: &str) -> ProjectDatabaseDocumentRunQueryCall<'a, C, A> {
+    pub fn databases_documents_run_query<P: Into<String>>(&self, request: RunQueryRequest, parent: P) -> ProjectDatabaseDocumentRunQueryCall<'a, C, A> {
         ProjectDatabaseDocumentRunQueryCall {
             hub: self.hub,
             _request: request,
-            _parent: parent.to_string(),
+            _parent: parent.into(),
             _delegate: Default::default(),
             _scopes: Default::default(),
             _additional_params: Default::default(),
@@ -66,18 +67,16 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
     /// * `request` - No description provided.
     /// * `database` - The database name. In the format:
     ///                `projects/{project_id}/databases/{database_id}`.
-    /*
-    pub fn databases_documents_batch_get(&self, request: BatchGetDocumentsRequest, database: &str) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+    pub fn databases_documents_batch_get<D: Into<String>>(&self, request: BatchGetDocumentsRequest, database: D) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
         ProjectDatabaseDocumentBatchGetCall {
             hub: self.hub,
             _request: request,
-            _database: database.to_string(),
+            _database: database.into(),
             _delegate: Default::default(),
             _scopes: Default::default(),
             _additional_params: Default::default(),
         }
     }
-    */
 /// Create a builder to help you perform the following task:
     ///
     /// Deletes a document.
@@ -86,10 +85,10 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
     ///
     /// * `name` - The resource name of the Document to delete. In the format:
     ///            `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    pub fn databases_documents_delete(&self, name: &str) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
+    pub fn databases_documents_delete<N: Into<String>>(&self, name: N) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
         ProjectDatabaseDocumentDeleteCall {
             hub: self.hub,
-            _name: name.to_string(),
+            _name: name.into(),
             _current_document_update_time: Default::default(),
             _current_document_exists: Default::default(),
             _delegate: Default::default(),
@@ -108,11 +107,11 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
     ///              `projects/{project_id}/databases/{database_id}/documents` or
     ///              `projects/{project_id}/databases/{database_id}/documents/chatrooms/{chatroom_id}`
     /// * `collectionId` - The collection ID, relative to `parent`, to list. For example: `chatrooms`.
-    pub fn databases_documents_create_document(&self, request: Document, parent: &str, collection_id: &str) -> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {
+    pub fn databases_documents_create_document<P: Into<String>>(&self, request: Document, parent: P, collection_id: &str) -> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {
         ProjectDatabaseDocumentCreateDocumentCall {
             hub: self.hub,
             _request: request,
-            _parent: parent.to_string(),
+            _parent: parent.into(),
             _collection_id: collection_id.to_string(),
             _mask_field_paths: Default::default(),
             _document_id: Default::default(),
@@ -121,6 +120,14 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
             _additional_params: Default::default(),
         }
     }
+/// Create a builder to help you perform the following task:
+    ///
+    /// Creates a new document in `collection`, like `databases_documents_create_document()`
+    /// with the parent and id of the collection.
+    pub fn databases_documents_create_document_in<P: Into<CollectionPath>>(&self, request: Document, collection: P) -> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {
+        let collection = collection.into();
+        self.databases_documents_create_document(request, collection.parent_name(), collection.id())
+    }
 /// Create a builder to help you perform the following task:
     ///
     /// Lists documents.
@@ -135,10 +142,10 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
     ///              `projects/my-project/databases/my-database/documents/chatrooms/my-chatroom`
     /// * `collectionId` - The collection ID, relative to `parent`, to list. For example: `chatrooms`
     ///                    or `messages`.
-    pub fn databases_documents_list(&self, parent: &str, collection_id: &str) -> ProjectDatabaseDocumentListCall<'a, C, A> {
+    pub fn databases_documents_list<P: Into<String>>(&self, parent: P, collection_id: &str) -> ProjectDatabaseDocumentListCall<'a, C, A> {
         ProjectDatabaseDocumentListCall {
             hub: self.hub,
-            _parent: parent.to_string(),
+            _parent: parent.into(),
             _collection_id: collection_id.to_string(),
             _transaction: Default::default(),
             _show_missing: Default::default(),
@@ -152,9 +159,15 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
             _additional_params: Default::default(),
         }
     }
-
-    /*
-    /// Create a builder to help you perform the following task:
+/// Create a builder to help you perform the following task:
+    ///
+    /// Lists the documents of `collection`, like `databases_documents_list()` with the
+    /// parent and id of the collection.
+    pub fn databases_documents_list_in<P: Into<CollectionPath>>(&self, collection: P) -> ProjectDatabaseDocumentListCall<'a, C, A> {
+        let collection = collection.into();
+        self.databases_documents_list(collection.parent_name(), collection.id())
+    }
+/// Create a builder to help you perform the following task:
     ///
     /// Updates or inserts a document.
     ///
@@ -163,11 +176,11 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
     /// * `request` - No description provided.
     /// * `name` - The resource name of the document, for example
     ///            `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
-    pub fn databases_documents_patch(&self, request: Document, name: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+    pub fn databases_documents_patch<N: Into<String>>(&self, request: Document, name: N) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
         ProjectDatabaseDocumentPatchCall {
             hub: self.hub,
             _request: request,
-            _name: name.to_string(),
+            _name: name.into(),
             _update_mask_field_paths: Default::default(),
             _mask_field_paths: Default::default(),
             _current_document_update_time: Default::default(),
@@ -177,7 +190,63 @@ impl<'a, C, A> ProjectMethods<'a, C, A> {
             _additional_params: Default::default(),
         }
     }
-    */
+/// Create a builder to help you perform the following task:
+    ///
+    /// Starts a new transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn databases_documents_begin_transaction<D: Into<String>>(&self, request: BeginTransactionRequest, database: D) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
+        ProjectDatabaseDocumentBeginTransactionCall {
+            hub: self.hub,
+            _request: request,
+            _database: database.into(),
+            _delegate: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+/// Create a builder to help you perform the following task:
+    ///
+    /// Commits a transaction, while optionally updating documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn databases_documents_commit<D: Into<String>>(&self, request: CommitRequest, database: D) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
+        ProjectDatabaseDocumentCommitCall {
+            hub: self.hub,
+            _request: request,
+            _database: database.into(),
+            _delegate: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
+/// Create a builder to help you perform the following task:
+    ///
+    /// Rolls back a transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - No description provided.
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn databases_documents_rollback<D: Into<String>>(&self, request: RollbackRequest, database: D) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
+        ProjectDatabaseDocumentRollbackCall {
+            hub: self.hub,
+            _request: request,
+            _database: database.into(),
+            _delegate: Default::default(),
+            _scopes: Default::default(),
+            _additional_params: Default::default(),
+        }
+    }
 }
 
 /// Gets a single document.
@@ -229,145 +298,51 @@ pub struct ProjectDatabaseDocumentGetCall<'a, C, A>
 
 impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentGetCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentGetCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseDocumentGetCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Document)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
+    pub fn doit(self) -> Result<(HttpResponse, Document)> {
+        let request = self.call_request();
         let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
+        let dlg: &mut dyn Delegate = match self._delegate {
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.get",
-                               http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        if let Some(value) = self._transaction {
-            params.push(("transaction", value.to_string()));
-        }
-        if let Some(value) = self._read_time {
-            params.push(("readTime", value.to_string()));
-        }
-        if self._mask_field_paths.len() > 0 {
-            for f in self._mask_field_paths.iter() {
-                params.push(("mask.fieldPaths", f.to_string()));
-            }
-        }
-        for &field in ["alt", "name", "transaction", "readTime", "mask.fieldPaths"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
+        self.hub.execute_json(request, dlg)
+    }
 
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
+    /// Perform the operation you have build so far, returning `None` if the document
+    /// does not exist.
+    ///
+    /// Unlike `doit()`, a `NOT_FOUND` response is not an error. Any other failure is.
+    pub fn doit_optional(self) -> Result<Option<(HttpResponse, Document)>> {
+        match self.doit() {
+            Ok(result) => Ok(Some(result)),
+            Err(ref err) if err.is_not_found() => Ok(None),
+            Err(err) => Err(err),
         }
+    }
 
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        let mut params = Vec::new();
+        if let Some(ref value) = self._transaction {
+            params.push(("transaction", value.to_string()));
         }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
+        if let Some(ref value) = self._read_time {
+            params.push(("readTime", value.to_string()));
         }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
+        for f in self._mask_field_paths.iter() {
+            params.push(("mask.fieldPaths", f.to_string()));
+        }
+        CallRequest {
+            method: &GET,
+            url_params: vec![("{+name}", self._name.clone())],
+            params,
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: None,
         }
     }
 
@@ -379,8 +354,8 @@ impl<'a, C, A> ProjectDatabaseDocumentGetCall<'a, C, A> where C: BorrowMut<hyper
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseDocumentGetCall<'a, C, A> {
-        self._name = new_value.to_string();
+    pub fn name<N: Into<String>>(mut self, new_value: N) -> ProjectDatabaseDocumentGetCall<'a, C, A> {
+        self._name = new_value.into();
         self
     }
     /// Reads the document in a transaction.
@@ -446,8 +421,8 @@ impl<'a, C, A> ProjectDatabaseDocumentGetCall<'a, C, A> where C: BorrowMut<hyper
 
     /// Identifies the authorization scope for the method you are building.
     ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
     ///
     /// The `scope` will be added to a set of scopes. This is important as one can maintain access
     /// tokens for more than one scope.
@@ -520,149 +495,49 @@ pub struct ProjectDatabaseDocumentRunQueryCall<'a, C, A>
 
 impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentRunQueryCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentRunQueryCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseDocumentRunQueryCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Vec<RunQueryResponse>)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
+    pub fn doit(self) -> Result<(HttpResponse, Vec<RunQueryResponse>)> {
+        let request = self.call_request();
         let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
+        let dlg: &mut dyn Delegate = match self._delegate {
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.runQuery",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(4 + self._additional_params.len());
-        params.push(("parent", self._parent.to_string()));
-        for &field in ["alt", "parent"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+parent}:runQuery";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+parent}", "parent")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["parent"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
+        self.hub.execute_json(request, dlg)
+    }
 
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
 
+    /// Perform the operation you have build so far, decoding the responses one by one
+    /// while they are read from the connection.
+    ///
+    /// Unlike `doit()`, this never holds more than a single `RunQueryResponse` in memory.
+    /// Errors occurring before the server started responding are returned right away,
+    /// errors while reading the responses are yielded by the stream, which ends after them.
+    /// The delegate is only consulted until the response headers were received.
+    pub fn doit_stream(self) -> Result<RunQueryStream> {
+        let request = self.call_request();
+        let mut dd = DefaultDelegate;
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(d) => d,
+            None => &mut dd
+        };
+        let res = self.hub.execute(request, dlg)?;
+        dlg.finished(true);
+        Ok(RunQueryStream::new(res))
+    }
 
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        CallRequest {
+            method: &RUN_QUERY,
+            url_params: vec![("{+parent}", self._parent.clone())],
+            params: Vec::new(),
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: Some(json_body(&self._request)),
         }
     }
 
@@ -687,8 +562,8 @@ impl<'a, C, A> ProjectDatabaseDocumentRunQueryCall<'a, C, A> where C: BorrowMut<
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseDocumentRunQueryCall<'a, C, A> {
-        self._parent = new_value.to_string();
+    pub fn parent<P: Into<String>>(mut self, new_value: P) -> ProjectDatabaseDocumentRunQueryCall<'a, C, A> {
+        self._parent = new_value.into();
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -730,8 +605,8 @@ impl<'a, C, A> ProjectDatabaseDocumentRunQueryCall<'a, C, A> where C: BorrowMut<
 
     /// Identifies the authorization scope for the method you are building.
     ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
     ///
     /// The `scope` will be added to a set of scopes. This is important as one can maintain access
     /// tokens for more than one scope.
@@ -753,9 +628,12 @@ impl<'a, C, A> ProjectDatabaseDocumentRunQueryCall<'a, C, A> where C: BorrowMut<
     }
 }
 
-/// Deletes a document.
+/// Gets multiple documents.
 ///
-/// A builder for the *databases.documents.delete* method supported by a *project* resource.
+/// Documents returned by this method are not guaranteed to be returned in the
+/// same order that they were requested.
+///
+/// A builder for the *databases.documents.batchGet* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -767,6 +645,7 @@ impl<'a, C, A> ProjectDatabaseDocumentRunQueryCall<'a, C, A> where C: BorrowMut<
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
+/// use firestore1::BatchGetDocumentsRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -777,192 +656,131 @@ impl<'a, C, A> ProjectDatabaseDocumentRunQueryCall<'a, C, A> where C: BorrowMut<
 /// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
 /// #                               <MemoryStorage as Default>::default(), None);
 /// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = BatchGetDocumentsRequest::default();
+///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_delete("name")
-///              .current_document_update_time("sea")
-///              .current_document_exists(false)
-///              .doit();
+/// let result = hub.projects().databases_documents_batch_get(req, "database")
+///              .add_documents("projects/p/databases/(default)/documents/users/alice")
+///              .read_time("stet")
+///              .doit_stream();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentDeleteCall<'a, C, A>
+pub struct ProjectDatabaseDocumentBatchGetCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _name: String,
-    _current_document_update_time: Option<String>,
-    _current_document_exists: Option<bool>,
+    _request: BatchGetDocumentsRequest,
+    _database: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentDeleteCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentBatchGetCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseDocumentBatchGetCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Empty)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
+    pub fn doit(self) -> Result<(HttpResponse, Vec<BatchGetDocumentsResponse>)> {
+        let request = self.call_request();
         let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
+        let dlg: &mut dyn Delegate = match self._delegate {
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.delete",
-                               http_method: hyper::method::Method::Delete });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(5 + self._additional_params.len());
-        params.push(("name", self._name.to_string()));
-        if let Some(value) = self._current_document_update_time {
-            params.push(("currentDocument.updateTime", value.to_string()));
-        }
-        if let Some(value) = self._current_document_exists {
-            params.push(("currentDocument.exists", value.to_string()));
-        }
-        for &field in ["alt", "name", "currentDocument.updateTime", "currentDocument.exists"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+name}";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+name}", "name")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(1);
-            for param_name in ["name"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
+        self.hub.execute_json(request, dlg)
+    }
 
+    /// Perform the operation you have build so far, decoding the responses one by one
+    /// while they are read from the connection.
+    ///
+    /// Each `BatchGetDocumentsResponse` reports one document as `found` or `missing`, so
+    /// this never holds more than one of them in memory. Errors occurring before the
+    /// server started responding are returned right away, errors while reading the
+    /// responses are yielded by the stream, which ends after them.
+    /// The delegate is only consulted until the response headers were received.
+    pub fn doit_stream(self) -> Result<ResponseStream<BatchGetDocumentsResponse>> {
+        let request = self.call_request();
+        let mut dd = DefaultDelegate;
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(d) => d,
+            None => &mut dd
+        };
+        let res = self.hub.execute(request, dlg)?;
+        dlg.finished(true);
+        Ok(ResponseStream::new(res))
+    }
 
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Delete, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        CallRequest {
+            method: &BATCH_GET,
+            url_params: vec![("{+database}", self._database.clone())],
+            params: Vec::new(),
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: Some(json_body(&self._request)),
         }
     }
 
 
-    /// The resource name of the Document to delete. In the format:
-    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
     ///
-    /// Sets the *name* path property to the given value.
+    /// Sets the *request* property to the given value.
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn name(mut self, new_value: &str) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
-        self._name = new_value.to_string();
+    pub fn request(mut self, new_value: BatchGetDocumentsRequest) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+        self._request = new_value;
         self
     }
-    /// When set, the target document must exist and have been last updated at
-    /// that time.
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
     ///
-    /// Sets the *current document.update time* query property to the given value.
-    pub fn current_document_update_time(mut self, new_value: &str) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
-        self._current_document_update_time = Some(new_value.to_string());
+    /// Sets the *database* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+        self._database = new_value.into();
         self
     }
-    /// When set to `true`, the target document must exist.
-    /// When set to `false`, the target document must not exist.
+    /// The name of a document to retrieve. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
     ///
-    /// Sets the *current document.exists* query property to the given value.
-    pub fn current_document_exists(mut self, new_value: bool) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
-        self._current_document_exists = Some(new_value);
+    /// Append the given value to the *documents* property of the request.
+    pub fn add_documents<N: Into<String>>(mut self, new_value: N) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+        self._request.documents.get_or_insert_with(Vec::new).push(new_value.into());
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *mask.field paths* property of the request.
+    pub fn add_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+        self._request.mask.get_or_insert_with(Default::default)
+            .field_paths.get_or_insert_with(Vec::new).push(new_value.to_string());
+        self
+    }
+    /// Reads documents in a transaction.
+    ///
+    /// Sets the *transaction* property of the request, and clears its *new transaction*.
+    pub fn transaction(mut self, new_value: &str) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+        self._request.transaction = Some(new_value.to_string());
+        self._request.new_transaction = None;
+        self
+    }
+    /// Reads documents as they were at the given time.
+    /// This may not be older than 60 seconds.
+    ///
+    /// Sets the *read time* property of the request.
+    pub fn read_time(mut self, new_value: &str) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
+        self._request.read_time = Some(new_value.to_string());
         self
     }
     /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
@@ -971,7 +789,7 @@ impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hy
     /// It should be used to handle progress information, and to implement a certain level of resilience.
     ///
     /// Sets the *delegate* property to the given value.
-    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A> {
         self._delegate = Some(new_value);
         self
     }
@@ -996,7 +814,7 @@ impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hy
     /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
     /// * *alt* (query-string) - Data format for response.
     /// * *$.xgafv* (query-string) - V1 error format.
-    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentDeleteCall<'a, C, A>
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A>
                                                         where T: AsRef<str> {
         self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
         self
@@ -1004,8 +822,8 @@ impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hy
 
     /// Identifies the authorization scope for the method you are building.
     ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
     ///
     /// The `scope` will be added to a set of scopes. This is important as one can maintain access
     /// tokens for more than one scope.
@@ -1016,7 +834,7 @@ impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hy
     /// Usually there is more than one suitable scope to authorize an operation, some of which may
     /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
     /// sufficient, a read-write scope will do as well.
-    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentDeleteCall<'a, C, A>
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentBatchGetCall<'a, C, A>
                                                         where T: Into<Option<S>>,
                                                               S: AsRef<str> {
         match scope.into() {
@@ -1027,9 +845,9 @@ impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hy
     }
 }
 
-/// Creates a new document.
+/// Starts a new transaction.
 ///
-/// A builder for the *databases.documents.createDocument* method supported by a *project* resource.
+/// A builder for the *databases.documents.beginTransaction* method supported by a *project* resource.
 /// It is not used directly, but through a `ProjectMethods` instance.
 ///
 /// # Example
@@ -1041,7 +859,7 @@ impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hy
 /// # extern crate hyper_rustls;
 /// # extern crate yup_oauth2 as oauth2;
 /// # extern crate google_firestore as firestore1;
-/// use firestore1::Document;
+/// use firestore1::BeginTransactionRequest;
 /// # #[test] fn egal() {
 /// # use std::default::Default;
 /// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
@@ -1055,185 +873,710 @@ impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: BorrowMut<hy
 /// // As the method needs a request, you would usually fill it with the desired information
 /// // into the respective structure. Some of the parts shown here might not be applicable !
 /// // Values shown here are possibly random and not representative !
-/// let mut req = Document::default();
+/// let mut req = BeginTransactionRequest::default();
 ///
 /// // You can configure optional parameters by calling the respective setters at will, and
 /// // execute the final call using `doit()`.
 /// // Values shown here are possibly random and not representative !
-/// let result = hub.projects().databases_documents_create_document(req, "parent", "collectionId")
-///              .add_mask_field_paths("accusam")
-///              .document_id("Lorem")
+/// let result = hub.projects().databases_documents_begin_transaction(req, "database")
 ///              .doit();
 /// # }
 /// ```
-pub struct ProjectDatabaseDocumentCreateDocumentCall<'a, C, A>
+pub struct ProjectDatabaseDocumentBeginTransactionCall<'a, C, A>
     where C: 'a, A: 'a {
 
     hub: &'a Firestore<C, A>,
-    _request: Document,
-    _parent: String,
-    _collection_id: String,
-    _mask_field_paths: Vec<String>,
-    _document_id: Option<String>,
+    _request: BeginTransactionRequest,
+    _database: String,
     _delegate: Option<&'a mut dyn Delegate>,
     _additional_params: HashMap<String, String>,
     _scopes: BTreeMap<String, ()>
 }
 
-impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {}
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, Document)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
+    pub fn doit(self) -> Result<(HttpResponse, BeginTransactionResponse)> {
+        let request = self.call_request();
         let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
+        let dlg: &mut dyn Delegate = match self._delegate {
             Some(d) => d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.createDocument",
-                               http_method: hyper::method::Method::Post });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(7 + self._additional_params.len());
-        params.push(("parent", self._parent.to_string()));
-        params.push(("collectionId", self._collection_id.to_string()));
-        if self._mask_field_paths.len() > 0 {
-            for f in self._mask_field_paths.iter() {
-                params.push(("mask.fieldPaths", f.to_string()));
-            }
-        }
-        if let Some(value) = self._document_id {
-            params.push(("documentId", value.to_string()));
-        }
-        for &field in ["alt", "parent", "collectionId", "mask.fieldPaths", "documentId"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
-        }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+parent}/{collectionId}";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+parent}", "parent"), ("{collectionId}", "collectionId")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(2);
-            for param_name in ["collectionId", "parent"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-        let mut json_mime_type = mime::Mime(mime::TopLevel::Application, mime::SubLevel::Json, Default::default());
-        let mut request_value_reader =
-            {
-                let mut value = json::value::to_value(&self._request).expect("serde to work");
-                remove_json_null_values(&mut value);
-                let mut dst = io::Cursor::new(Vec::with_capacity(128));
-                json::to_writer(&mut dst, &value).unwrap();
-                dst
-            };
-        let request_size = request_value_reader.seek(io::SeekFrom::End(0)).unwrap();
-        request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-
+        self.hub.execute_json(request, dlg)
+    }
 
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            request_value_reader.seek(io::SeekFrom::Start(0)).unwrap();
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Post, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone())
-                    .header(ContentType(json_mime_type.clone()))
-                    .header(ContentLength(request_size as u64))
-                    .body(&mut request_value_reader);
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        CallRequest {
+            method: &BEGIN_TRANSACTION,
+            url_params: vec![("{+database}", self._database.clone())],
+            params: Vec::new(),
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: Some(json_body(&self._request)),
+        }
+    }
+
+
+    ///
+    /// Sets the *request* property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn request(mut self, new_value: BeginTransactionRequest) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
+        self._request = new_value;
+        self
+    }
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    ///
+    /// Sets the *database* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
+        self._database = new_value.into();
+        self
+    }
+    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
+    /// while executing the actual API request.
+    ///
+    /// It should be used to handle progress information, and to implement a certain level of resilience.
+    ///
+    /// Sets the *delegate* property to the given value.
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A> {
+        self._delegate = Some(new_value);
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request.
+    /// It should be used to set parameters which are not yet available through their own
+    /// setters.
+    ///
+    /// Please note that this method must not be used to set any of the known parameters
+    /// which have their own setter method. If done anyway, the request will fail.
+    ///
+    /// # Additional Parameters
+    ///
+    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
+    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
+    /// * *access_token* (query-string) - OAuth access token.
+    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
+    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
+    /// * *callback* (query-string) - JSONP
+    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
+    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
+    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
+    /// * *alt* (query-string) - Data format for response.
+    /// * *$.xgafv* (query-string) - V1 error format.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A>
+                                                        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    ///
+    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
+    /// tokens for more than one scope.
+    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
+    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
+    /// function for details).
+    ///
+    /// Usually there is more than one suitable scope to authorize an operation, some of which may
+    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
+    /// sufficient, a read-write scope will do as well.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentBeginTransactionCall<'a, C, A>
+                                                        where T: Into<Option<S>>,
+                                                              S: AsRef<str> {
+        match scope.into() {
+          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
+          None => None,
+        };
+        self
+    }
+}
+
+/// Commits a transaction, while optionally updating documents.
+///
+/// A builder for the *databases.documents.commit* method supported by a *project* resource.
+/// It is not used directly, but through a `ProjectMethods` instance.
+///
+/// # Example
+///
+/// Instantiate a resource method builder
+///
+/// ```test_harness,no_run
+/// # extern crate hyper;
+/// # extern crate hyper_rustls;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// use firestore1::CommitRequest;
+/// # #[test] fn egal() {
+/// # use std::default::Default;
+/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
+/// # use firestore1::Firestore;
+///
+/// # let secret: ApplicationSecret = Default::default();
+/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
+/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
+/// #                               <MemoryStorage as Default>::default(), None);
+/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = CommitRequest::default();
+///
+/// // You can configure optional parameters by calling the respective setters at will, and
+/// // execute the final call using `doit()`.
+/// // Values shown here are possibly random and not representative !
+/// let result = hub.projects().databases_documents_commit(req, "database")
+///              .doit();
+/// # }
+/// ```
+pub struct ProjectDatabaseDocumentCommitCall<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    _request: CommitRequest,
+    _database: String,
+    _delegate: Option<&'a mut dyn Delegate>,
+    _additional_params: HashMap<String, String>,
+    _scopes: BTreeMap<String, ()>
+}
+
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentCommitCall<'a, C, A> {}
+
+impl<'a, C, A> ProjectDatabaseDocumentCommitCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
+
+
+    /// Perform the operation you have build so far.
+    pub fn doit(self) -> Result<(HttpResponse, CommitResponse)> {
+        let request = self.call_request();
+        let mut dd = DefaultDelegate;
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(d) => d,
+            None => &mut dd
+        };
+        self.hub.execute_json(request, dlg)
+    }
+
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        CallRequest {
+            method: &COMMIT,
+            url_params: vec![("{+database}", self._database.clone())],
+            params: Vec::new(),
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: Some(json_body(&self._request)),
+        }
+    }
+
+
+    ///
+    /// Sets the *request* property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn request(mut self, new_value: CommitRequest) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
+        self._request = new_value;
+        self
+    }
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    ///
+    /// Sets the *database* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
+        self._database = new_value.into();
+        self
+    }
+    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
+    /// while executing the actual API request.
+    ///
+    /// It should be used to handle progress information, and to implement a certain level of resilience.
+    ///
+    /// Sets the *delegate* property to the given value.
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentCommitCall<'a, C, A> {
+        self._delegate = Some(new_value);
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request.
+    /// It should be used to set parameters which are not yet available through their own
+    /// setters.
+    ///
+    /// Please note that this method must not be used to set any of the known parameters
+    /// which have their own setter method. If done anyway, the request will fail.
+    ///
+    /// # Additional Parameters
+    ///
+    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
+    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
+    /// * *access_token* (query-string) - OAuth access token.
+    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
+    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
+    /// * *callback* (query-string) - JSONP
+    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
+    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
+    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
+    /// * *alt* (query-string) - Data format for response.
+    /// * *$.xgafv* (query-string) - V1 error format.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentCommitCall<'a, C, A>
+                                                        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    ///
+    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
+    /// tokens for more than one scope.
+    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
+    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
+    /// function for details).
+    ///
+    /// Usually there is more than one suitable scope to authorize an operation, some of which may
+    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
+    /// sufficient, a read-write scope will do as well.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentCommitCall<'a, C, A>
+                                                        where T: Into<Option<S>>,
+                                                              S: AsRef<str> {
+        match scope.into() {
+          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
+          None => None,
+        };
+        self
+    }
+}
+
+/// Rolls back a transaction.
+///
+/// A builder for the *databases.documents.rollback* method supported by a *project* resource.
+/// It is not used directly, but through a `ProjectMethods` instance.
+///
+/// # Example
+///
+/// Instantiate a resource method builder
+///
+/// ```test_harness,no_run
+/// # extern crate hyper;
+/// # extern crate hyper_rustls;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// use firestore1::RollbackRequest;
+/// # #[test] fn egal() {
+/// # use std::default::Default;
+/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
+/// # use firestore1::Firestore;
+///
+/// # let secret: ApplicationSecret = Default::default();
+/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
+/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
+/// #                               <MemoryStorage as Default>::default(), None);
+/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = RollbackRequest::default();
+///
+/// // You can configure optional parameters by calling the respective setters at will, and
+/// // execute the final call using `doit()`.
+/// // Values shown here are possibly random and not representative !
+/// let result = hub.projects().databases_documents_rollback(req, "database")
+///              .doit();
+/// # }
+/// ```
+pub struct ProjectDatabaseDocumentRollbackCall<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    _request: RollbackRequest,
+    _database: String,
+    _delegate: Option<&'a mut dyn Delegate>,
+    _additional_params: HashMap<String, String>,
+    _scopes: BTreeMap<String, ()>
+}
+
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentRollbackCall<'a, C, A> {}
+
+impl<'a, C, A> ProjectDatabaseDocumentRollbackCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
+
+
+    /// Perform the operation you have build so far.
+    pub fn doit(self) -> Result<(HttpResponse, Empty)> {
+        let request = self.call_request();
+        let mut dd = DefaultDelegate;
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(d) => d,
+            None => &mut dd
+        };
+        self.hub.execute_json(request, dlg)
+    }
+
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        CallRequest {
+            method: &ROLLBACK,
+            url_params: vec![("{+database}", self._database.clone())],
+            params: Vec::new(),
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: Some(json_body(&self._request)),
+        }
+    }
+
+
+    ///
+    /// Sets the *request* property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn request(mut self, new_value: RollbackRequest) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
+        self._request = new_value;
+        self
+    }
+    /// The database name. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    ///
+    /// Sets the *database* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn database<D: Into<String>>(mut self, new_value: D) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
+        self._database = new_value.into();
+        self
+    }
+    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
+    /// while executing the actual API request.
+    ///
+    /// It should be used to handle progress information, and to implement a certain level of resilience.
+    ///
+    /// Sets the *delegate* property to the given value.
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentRollbackCall<'a, C, A> {
+        self._delegate = Some(new_value);
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request.
+    /// It should be used to set parameters which are not yet available through their own
+    /// setters.
+    ///
+    /// Please note that this method must not be used to set any of the known parameters
+    /// which have their own setter method. If done anyway, the request will fail.
+    ///
+    /// # Additional Parameters
+    ///
+    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
+    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
+    /// * *access_token* (query-string) - OAuth access token.
+    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
+    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
+    /// * *callback* (query-string) - JSONP
+    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
+    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
+    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
+    /// * *alt* (query-string) - Data format for response.
+    /// * *$.xgafv* (query-string) - V1 error format.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentRollbackCall<'a, C, A>
+                                                        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    ///
+    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
+    /// tokens for more than one scope.
+    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
+    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
+    /// function for details).
+    ///
+    /// Usually there is more than one suitable scope to authorize an operation, some of which may
+    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
+    /// sufficient, a read-write scope will do as well.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentRollbackCall<'a, C, A>
+                                                        where T: Into<Option<S>>,
+                                                              S: AsRef<str> {
+        match scope.into() {
+          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
+          None => None,
+        };
+        self
+    }
+}
+
+/// Deletes a document.
+///
+/// A builder for the *databases.documents.delete* method supported by a *project* resource.
+/// It is not used directly, but through a `ProjectMethods` instance.
+///
+/// # Example
+///
+/// Instantiate a resource method builder
+///
+/// ```test_harness,no_run
+/// # extern crate hyper;
+/// # extern crate hyper_rustls;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// # #[test] fn egal() {
+/// # use std::default::Default;
+/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
+/// # use firestore1::Firestore;
+///
+/// # let secret: ApplicationSecret = Default::default();
+/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
+/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
+/// #                               <MemoryStorage as Default>::default(), None);
+/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // You can configure optional parameters by calling the respective setters at will, and
+/// // execute the final call using `doit()`.
+/// // Values shown here are possibly random and not representative !
+/// let result = hub.projects().databases_documents_delete("name")
+///              .current_document_update_time("sea")
+///              .current_document_exists(false)
+///              .doit();
+/// # }
+/// ```
+pub struct ProjectDatabaseDocumentDeleteCall<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    _name: String,
+    _current_document_update_time: Option<String>,
+    _current_document_exists: Option<bool>,
+    _delegate: Option<&'a mut dyn Delegate>,
+    _additional_params: HashMap<String, String>,
+    _scopes: BTreeMap<String, ()>
+}
+
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentDeleteCall<'a, C, A> {}
+
+impl<'a, C, A> ProjectDatabaseDocumentDeleteCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
+
+
+    /// Perform the operation you have build so far.
+    pub fn doit(self) -> Result<(HttpResponse, Empty)> {
+        let request = self.call_request();
+        let mut dd = DefaultDelegate;
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(d) => d,
+            None => &mut dd
+        };
+        self.hub.execute_json(request, dlg)
+    }
+
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        let mut params = Vec::new();
+        if let Some(ref value) = self._current_document_update_time {
+            params.push(("currentDocument.updateTime", value.to_string()));
+        }
+        if let Some(ref value) = self._current_document_exists {
+            params.push(("currentDocument.exists", value.to_string()));
+        }
+        CallRequest {
+            method: &DELETE,
+            url_params: vec![("{+name}", self._name.clone())],
+            params,
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: None,
+        }
+    }
+
+
+    /// The resource name of the Document to delete. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    ///
+    /// Sets the *name* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn name<N: Into<String>>(mut self, new_value: N) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
+        self._name = new_value.into();
+        self
+    }
+    /// When set, the target document must exist and have been last updated at
+    /// that time.
+    ///
+    /// Sets the *current document.update time* query property to the given value.
+    pub fn current_document_update_time(mut self, new_value: &str) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
+        self._current_document_update_time = Some(new_value.to_string());
+        self
+    }
+    /// When set to `true`, the target document must exist.
+    /// When set to `false`, the target document must not exist.
+    ///
+    /// Sets the *current document.exists* query property to the given value.
+    pub fn current_document_exists(mut self, new_value: bool) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
+        self._current_document_exists = Some(new_value);
+        self
+    }
+    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
+    /// while executing the actual API request.
+    ///
+    /// It should be used to handle progress information, and to implement a certain level of resilience.
+    ///
+    /// Sets the *delegate* property to the given value.
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
+        self._delegate = Some(new_value);
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request.
+    /// It should be used to set parameters which are not yet available through their own
+    /// setters.
+    ///
+    /// Please note that this method must not be used to set any of the known parameters
+    /// which have their own setter method. If done anyway, the request will fail.
+    ///
+    /// # Additional Parameters
+    ///
+    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
+    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
+    /// * *access_token* (query-string) - OAuth access token.
+    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
+    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
+    /// * *callback* (query-string) - JSONP
+    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
+    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
+    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
+    /// * *alt* (query-string) - Data format for response.
+    /// * *$.xgafv* (query-string) - V1 error format.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentDeleteCall<'a, C, A>
+                                                        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    ///
+    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
+    /// tokens for more than one scope.
+    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
+    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
+    /// function for details).
+    ///
+    /// Usually there is more than one suitable scope to authorize an operation, some of which may
+    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
+    /// sufficient, a read-write scope will do as well.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentDeleteCall<'a, C, A>
+                                                        where T: Into<Option<S>>,
+                                                              S: AsRef<str> {
+        match scope.into() {
+          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
+          None => None,
+        };
+        self
+    }
+}
+
+/// Creates a new document.
+///
+/// A builder for the *databases.documents.createDocument* method supported by a *project* resource.
+/// It is not used directly, but through a `ProjectMethods` instance.
+///
+/// # Example
+///
+/// Instantiate a resource method builder
+///
+/// ```test_harness,no_run
+/// # extern crate hyper;
+/// # extern crate hyper_rustls;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// use firestore1::Document;
+/// # #[test] fn egal() {
+/// # use std::default::Default;
+/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
+/// # use firestore1::Firestore;
+///
+/// # let secret: ApplicationSecret = Default::default();
+/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
+/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
+/// #                               <MemoryStorage as Default>::default(), None);
+/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = Document::default();
+///
+/// // You can configure optional parameters by calling the respective setters at will, and
+/// // execute the final call using `doit()`.
+/// // Values shown here are possibly random and not representative !
+/// let result = hub.projects().databases_documents_create_document(req, "parent", "collectionId")
+///              .add_mask_field_paths("accusam")
+///              .document_id("Lorem")
+///              .doit();
+/// # }
+/// ```
+pub struct ProjectDatabaseDocumentCreateDocumentCall<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    _request: Document,
+    _parent: String,
+    _collection_id: String,
+    _mask_field_paths: Vec<String>,
+    _document_id: Option<String>,
+    _delegate: Option<&'a mut dyn Delegate>,
+    _additional_params: HashMap<String, String>,
+    _scopes: BTreeMap<String, ()>
+}
+
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {}
+
+impl<'a, C, A> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
+
+
+    /// Perform the operation you have build so far.
+    pub fn doit(self) -> Result<(HttpResponse, Document)> {
+        let request = self.call_request();
+        let mut dd = DefaultDelegate;
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(d) => d,
+            None => &mut dd
+        };
+        self.hub.execute_json(request, dlg)
+    }
+
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        let mut params = Vec::new();
+        for f in self._mask_field_paths.iter() {
+            params.push(("mask.fieldPaths", f.to_string()));
+        }
+        if let Some(ref value) = self._document_id {
+            params.push(("documentId", value.to_string()));
+        }
+        CallRequest {
+            method: &CREATE_DOCUMENT,
+            url_params: vec![("{+parent}", self._parent.clone()), ("{collectionId}", self._collection_id.clone())],
+            params,
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: Some(json_body(&self._request)),
         }
     }
 
@@ -1255,8 +1598,8 @@ impl<'a, C, A> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> where C: Borr
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {
-        self._parent = new_value.to_string();
+    pub fn parent<P: Into<String>>(mut self, new_value: P) -> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {
+        self._parent = new_value.into();
         self
     }
     /// The collection ID, relative to `parent`, to list. For example: `chatrooms`.
@@ -1326,8 +1669,8 @@ impl<'a, C, A> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> where C: Borr
 
     /// Identifies the authorization scope for the method you are building.
     ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
     ///
     /// The `scope` will be added to a set of scopes. This is important as one can maintain access
     /// tokens for more than one scope.
@@ -1407,158 +1750,69 @@ pub struct ProjectDatabaseDocumentListCall<'a, C, A>
 
 impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentListCall<'a, C, A> {}
 
-impl<'a, C, A> ProjectDatabaseDocumentListCall<'a, C, A> where C: BorrowMut<hyper::Client>, A: oauth2::GetToken {
+impl<'a, C, A> ProjectDatabaseDocumentListCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
 
 
     /// Perform the operation you have build so far.
-    pub fn doit(mut self) -> Result<(hyper::client::Response, ListDocumentsResponse)> {
-        use url::percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-        use std::io::{Read, Seek};
-        use hyper::header::{ContentType, ContentLength, Authorization, Bearer, UserAgent, Location};
+    pub fn doit(mut self) -> Result<(HttpResponse, ListDocumentsResponse)> {
+        self.fetch()
+    }
+
+    /// Lists the documents of all pages, starting at the current page.
+    ///
+    /// Follow-up pages are requested lazily using the `next_page_token` of the previous
+    /// page, with all other properties of this call left as they are. The iteration ends
+    /// after the first page without a `next_page_token`, or after the first error.
+    pub fn all_documents(self) -> ListDocuments<'a, C, A> {
+        ListDocuments {
+            call: self,
+            documents: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+
+    /// Requests the current page.
+    fn fetch(&mut self) -> Result<(HttpResponse, ListDocumentsResponse)> {
+        let request = self.call_request();
         let mut dd = DefaultDelegate;
-        let mut dlg: &mut dyn Delegate = match self._delegate {
-            Some(d) => d,
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(ref mut d) => &mut **d,
             None => &mut dd
         };
-        dlg.begin(MethodInfo { id: "firestore.projects.databases.documents.list",
-                               http_method: hyper::method::Method::Get });
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(11 + self._additional_params.len());
-        params.push(("parent", self._parent.to_string()));
-        params.push(("collectionId", self._collection_id.to_string()));
-        if let Some(value) = self._transaction {
+        self.hub.execute_json(request, dlg)
+    }
+
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        let mut params = Vec::new();
+        if let Some(ref value) = self._transaction {
             params.push(("transaction", value.to_string()));
         }
-        if let Some(value) = self._show_missing {
+        if let Some(ref value) = self._show_missing {
             params.push(("showMissing", value.to_string()));
         }
-        if let Some(value) = self._read_time {
+        if let Some(ref value) = self._read_time {
             params.push(("readTime", value.to_string()));
         }
-        if let Some(value) = self._page_token {
+        if let Some(ref value) = self._page_token {
             params.push(("pageToken", value.to_string()));
         }
-        if let Some(value) = self._page_size {
+        if let Some(ref value) = self._page_size {
             params.push(("pageSize", value.to_string()));
         }
-        if let Some(value) = self._order_by {
+        if let Some(ref value) = self._order_by {
             params.push(("orderBy", value.to_string()));
         }
-        if self._mask_field_paths.len() > 0 {
-            for f in self._mask_field_paths.iter() {
-                params.push(("mask.fieldPaths", f.to_string()));
-            }
-        }
-        for &field in ["alt", "parent", "collectionId", "transaction", "showMissing", "readTime", "pageToken", "pageSize", "orderBy", "mask.fieldPaths"].iter() {
-            if self._additional_params.contains_key(field) {
-                dlg.finished(false);
-                return Err(Error::FieldClash(field));
-            }
-        }
-        for (name, value) in self._additional_params.iter() {
-            params.push((&name, value.clone()));
+        for f in self._mask_field_paths.iter() {
+            params.push(("mask.fieldPaths", f.to_string()));
         }
-
-        params.push(("alt", "json".to_string()));
-
-        let mut url = self.hub._base_url.clone() + "v1/{+parent}/{collectionId}";
-        if self._scopes.len() == 0 {
-            self._scopes.insert(Scope::CloudPlatform.as_ref().to_string(), ());
-        }
-
-        for &(find_this, param_name) in [("{+parent}", "parent"), ("{collectionId}", "collectionId")].iter() {
-            let mut replace_with = String::new();
-            for &(name, ref value) in params.iter() {
-                if name == param_name {
-                    replace_with = value.to_string();
-                    break;
-                }
-            }
-            if find_this.as_bytes()[1] == '+' as u8 {
-                replace_with = percent_encode(replace_with.as_bytes(), DEFAULT_ENCODE_SET).to_string();
-            }
-            url = url.replace(find_this, &replace_with);
-        }
-        {
-            let mut indices_for_removal: Vec<usize> = Vec::with_capacity(2);
-            for param_name in ["collectionId", "parent"].iter() {
-                if let Some(index) = params.iter().position(|t| &t.0 == param_name) {
-                    indices_for_removal.push(index);
-                }
-            }
-            for &index in indices_for_removal.iter() {
-                params.remove(index);
-            }
-        }
-
-        let url = hyper::Url::parse_with_params(&url, params).unwrap();
-
-
-
-        loop {
-            let token = match self.hub.auth.borrow_mut().token(self._scopes.keys()) {
-                Ok(token) => token,
-                Err(err) => {
-                    match  dlg.token(&*err) {
-                        Some(token) => token,
-                        None => {
-                            dlg.finished(false);
-                            return Err(Error::MissingToken(err))
-                        }
-                    }
-                }
-            };
-            let auth_header = Authorization(Bearer { token: token.access_token });
-            let mut req_result = {
-                let mut client = &mut *self.hub.client.borrow_mut();
-                let mut req = client.borrow_mut().request(hyper::method::Method::Get, url.clone())
-                    .header(UserAgent(self.hub._user_agent.clone()))
-                    .header(auth_header.clone());
-
-                dlg.pre_request();
-                req.send()
-            };
-
-            match req_result {
-                Err(err) => {
-                    if let oauth2::Retry::After(d) = dlg.http_error(&err) {
-                        sleep(d);
-                        continue;
-                    }
-                    dlg.finished(false);
-                    return Err(Error::HttpError(err))
-                }
-                Ok(mut res) => {
-                    if !res.status.is_success() {
-                        let mut json_err = String::new();
-                        res.read_to_string(&mut json_err).unwrap();
-                        if let oauth2::Retry::After(d) = dlg.http_failure(&res,
-                                                              json::from_str(&json_err).ok(),
-                                                              json::from_str(&json_err).ok()) {
-                            sleep(d);
-                            continue;
-                        }
-                        dlg.finished(false);
-                        return match json::from_str::<ErrorResponse>(&json_err){
-                            Err(_) => Err(Error::Failure(res)),
-                            Ok(serr) => Err(Error::BadRequest(serr))
-                        }
-                    }
-                    let result_value = {
-                        let mut json_response = String::new();
-                        res.read_to_string(&mut json_response).unwrap();
-                        match json::from_str(&json_response) {
-                            Ok(decoded) => (res, decoded),
-                            Err(err) => {
-                                dlg.response_json_decode_error(&json_response, &err);
-                                return Err(Error::JsonDecodeError(json_response, err));
-                            }
-                        }
-                    };
-
-                    dlg.finished(true);
-                    return Ok(result_value)
-                }
-            }
+        CallRequest {
+            method: &LIST,
+            url_params: vec![("{+parent}", self._parent.clone()), ("{collectionId}", self._collection_id.clone())],
+            params,
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: None,
         }
     }
 
@@ -1574,8 +1828,8 @@ impl<'a, C, A> ProjectDatabaseDocumentListCall<'a, C, A> where C: BorrowMut<hype
     ///
     /// Even though the property as already been set when instantiating this call,
     /// we provide this method for API completeness.
-    pub fn parent(mut self, new_value: &str) -> ProjectDatabaseDocumentListCall<'a, C, A> {
-        self._parent = new_value.to_string();
+    pub fn parent<P: Into<String>>(mut self, new_value: P) -> ProjectDatabaseDocumentListCall<'a, C, A> {
+        self._parent = new_value.into();
         self
     }
     /// The collection ID, relative to `parent`, to list. For example: `chatrooms`
@@ -1686,8 +1940,8 @@ impl<'a, C, A> ProjectDatabaseDocumentListCall<'a, C, A> where C: BorrowMut<hype
 
     /// Identifies the authorization scope for the method you are building.
     ///
-    /// Use this method to actively specify which scope should be used, instead the default `Scope` variant
-    /// `Scope::CloudPlatform`.
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
     ///
     /// The `scope` will be added to a set of scopes. This is important as one can maintain access
     /// tokens for more than one scope.
@@ -1709,22 +1963,264 @@ impl<'a, C, A> ProjectDatabaseDocumentListCall<'a, C, A> where C: BorrowMut<hype
     }
 }
 
-/// The response for Firestore.ListDocuments.
+/// Updates or inserts a document.
+///
+/// A builder for the *databases.documents.patch* method supported by a *project* resource.
+/// It is not used directly, but through a `ProjectMethods` instance.
+///
+/// # Example
+///
+/// Instantiate a resource method builder
 ///
-/// # Activities
+/// ```test_harness,no_run
+/// # extern crate hyper;
+/// # extern crate hyper_rustls;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// use firestore1::Document;
+/// # #[test] fn egal() {
+/// # use std::default::Default;
+/// # use oauth2::{Authenticator, DefaultAuthenticatorDelegate, ApplicationSecret, MemoryStorage};
+/// # use firestore1::Firestore;
 ///
-/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
-//// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+/// # let secret: ApplicationSecret = Default::default();
+/// # let auth = Authenticator::new(&secret, DefaultAuthenticatorDelegate,
+/// #                               hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())),
+/// #                               <MemoryStorage as Default>::default(), None);
+/// # let mut hub = Firestore::new(hyper::Client::with_connector(hyper::net::HttpsConnector::new(hyper_rustls::TlsClient::new())), auth);
+/// // As the method needs a request, you would usually fill it with the desired information
+/// // into the respective structure. Some of the parts shown here might not be applicable !
+/// // Values shown here are possibly random and not representative !
+/// let mut req = Document::default();
 ///
-/// * [databases documents list projects](struct.ProjectDatabaseDocumentListCall.html) (response)
+/// // You can configure optional parameters by calling the respective setters at will, and
+/// // execute the final call using `doit()`.
+/// // Values shown here are possibly random and not representative !
+/// let result = hub.projects().databases_documents_patch(req, "name")
+///              .add_update_mask_field_paths("ea")
+///              .add_mask_field_paths("sed")
+///              .current_document_update_time("dolor")
+///              .current_document_exists(true)
+///              .doit();
+/// # }
+/// ```
+pub struct ProjectDatabaseDocumentPatchCall<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    _request: Document,
+    _name: String,
+    _update_mask_field_paths: Vec<String>,
+    _mask_field_paths: Vec<String>,
+    _current_document_update_time: Option<String>,
+    _current_document_exists: Option<bool>,
+    _delegate: Option<&'a mut dyn Delegate>,
+    _additional_params: HashMap<String, String>,
+    _scopes: BTreeMap<String, ()>
+}
+
+impl<'a, C, A> CallBuilder for ProjectDatabaseDocumentPatchCall<'a, C, A> {}
+
+impl<'a, C, A> ProjectDatabaseDocumentPatchCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
+
+
+    /// Perform the operation you have build so far.
+    pub fn doit(self) -> Result<(HttpResponse, Document)> {
+        let request = self.call_request();
+        let mut dd = DefaultDelegate;
+        let dlg: &mut dyn Delegate = match self._delegate {
+            Some(d) => d,
+            None => &mut dd
+        };
+        self.hub.execute_json(request, dlg)
+    }
+
+    /// The request of this call, as sent by the executor of the hub.
+    fn call_request(&self) -> CallRequest {
+        let mut params = Vec::new();
+        for f in self._update_mask_field_paths.iter() {
+            params.push(("updateMask.fieldPaths", f.to_string()));
+        }
+        for f in self._mask_field_paths.iter() {
+            params.push(("mask.fieldPaths", f.to_string()));
+        }
+        if let Some(ref value) = self._current_document_update_time {
+            params.push(("currentDocument.updateTime", value.to_string()));
+        }
+        if let Some(ref value) = self._current_document_exists {
+            params.push(("currentDocument.exists", value.to_string()));
+        }
+        CallRequest {
+            method: &PATCH,
+            url_params: vec![("{+name}", self._name.clone())],
+            params,
+            additional_params: self._additional_params.clone(),
+            scopes: self._scopes.clone(),
+            body: Some(json_body(&self._request)),
+        }
+    }
+
+
+    ///
+    /// Sets the *request* property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn request(mut self, new_value: Document) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        self._request = new_value;
+        self
+    }
+    /// The resource name of the document, for example
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    ///
+    /// Sets the *name* path property to the given value.
+    ///
+    /// Even though the property as already been set when instantiating this call,
+    /// we provide this method for API completeness.
+    pub fn name<N: Into<String>>(mut self, new_value: N) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        self._name = new_value.into();
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *update mask.field paths* query property.
+    /// Each appended value will retain its original ordering and be '/'-separated in the URL's parameters.
+    pub fn add_update_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        self._update_mask_field_paths.push(new_value.to_string());
+        self
+    }
+    /// The list of field paths in the mask. See Document.fields for a field
+    /// path syntax reference.
+    ///
+    /// Append the given value to the *mask.field paths* query property.
+    /// Each appended value will retain its original ordering and be '/'-separated in the URL's parameters.
+    pub fn add_mask_field_paths(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        self._mask_field_paths.push(new_value.to_string());
+        self
+    }
+    /// When set, the target document must exist and have been last updated at
+    /// that time.
+    ///
+    /// Sets the *current document.update time* query property to the given value.
+    pub fn current_document_update_time(mut self, new_value: &str) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        self._current_document_update_time = Some(new_value.to_string());
+        self
+    }
+    /// When set to `true`, the target document must exist.
+    /// When set to `false`, the target document must not exist.
+    ///
+    /// Sets the *current document.exists* query property to the given value.
+    pub fn current_document_exists(mut self, new_value: bool) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        self._current_document_exists = Some(new_value);
+        self
+    }
+    /// The delegate implementation is consulted whenever there is an intermediate result, or if something goes wrong
+    /// while executing the actual API request.
+    ///
+    /// It should be used to handle progress information, and to implement a certain level of resilience.
+    ///
+    /// Sets the *delegate* property to the given value.
+    pub fn delegate(mut self, new_value: &'a mut dyn Delegate) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        self._delegate = Some(new_value);
+        self
+    }
+
+    /// Set any additional parameter of the query string used in the request.
+    /// It should be used to set parameters which are not yet available through their own
+    /// setters.
+    ///
+    /// Please note that this method must not be used to set any of the known parameters
+    /// which have their own setter method. If done anyway, the request will fail.
+    ///
+    /// # Additional Parameters
+    ///
+    /// * *upload_protocol* (query-string) - Upload protocol for media (e.g. "raw", "multipart").
+    /// * *prettyPrint* (query-boolean) - Returns response with indentations and line breaks.
+    /// * *access_token* (query-string) - OAuth access token.
+    /// * *fields* (query-string) - Selector specifying which fields to include in a partial response.
+    /// * *quotaUser* (query-string) - Available to use for quota purposes for server-side applications. Can be any arbitrary string assigned to a user, but should not exceed 40 characters.
+    /// * *callback* (query-string) - JSONP
+    /// * *oauth_token* (query-string) - OAuth 2.0 token for the current user.
+    /// * *key* (query-string) - API key. Your API key identifies your project and provides you with API access, quota, and reports. Required unless you provide an OAuth 2.0 token.
+    /// * *uploadType* (query-string) - Legacy upload protocol for media (e.g. "media", "multipart").
+    /// * *alt* (query-string) - Data format for response.
+    /// * *$.xgafv* (query-string) - V1 error format.
+    pub fn param<T>(mut self, name: T, value: T) -> ProjectDatabaseDocumentPatchCall<'a, C, A>
+                                                        where T: AsRef<str> {
+        self._additional_params.insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Identifies the authorization scope for the method you are building.
+    ///
+    /// Use this method to actively specify which scope should be used, instead of the default scopes of
+    /// the hub, which are `Scope::CloudPlatform` unless set with `FirestoreBuilder::add_scope()`.
+    ///
+    /// The `scope` will be added to a set of scopes. This is important as one can maintain access
+    /// tokens for more than one scope.
+    /// If `None` is specified, then all scopes will be removed and no default scope will be used either.
+    /// In that case, you have to specify your API-key using the `key` parameter (see the `param()`
+    /// function for details).
+    ///
+    /// Usually there is more than one suitable scope to authorize an operation, some of which may
+    /// encompass more rights than others. For example, for listing resources, a *read-only* scope will be
+    /// sufficient, a read-write scope will do as well.
+    pub fn add_scope<T, S>(mut self, scope: T) -> ProjectDatabaseDocumentPatchCall<'a, C, A>
+                                                        where T: Into<Option<S>>,
+                                                              S: AsRef<str> {
+        match scope.into() {
+          Some(scope) => self._scopes.insert(scope.as_ref().to_string(), ()),
+          None => None,
+        };
+        self
+    }
+}
+
+/// The documents of all pages of a *databases.documents.list* call.
 ///
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
-pub struct ListDocumentsResponse {
-    /// The next page token.
-    #[serde(rename="nextPageToken")]
-    pub next_page_token: Option<String>,
-    /// The Documents found.
-    pub documents: Option<Vec<Document>>,
+/// It is returned by `ProjectDatabaseDocumentListCall::all_documents()`.
+pub struct ListDocuments<'a, C, A>
+    where C: 'a, A: 'a {
+
+    call: ProjectDatabaseDocumentListCall<'a, C, A>,
+    documents: std::vec::IntoIter<Document>,
+    done: bool,
+}
+
+impl<'a, C, A> Iterator for ListDocuments<'a, C, A> where C: Transport, A: oauth2::GetToken {
+    type Item = Result<Document>;
+
+    fn next(&mut self) -> Option<Result<Document>> {
+        loop {
+            if let Some(document) = self.documents.next() {
+                return Some(Ok(document))
+            }
+            if self.done {
+                return None
+            }
+            match self.call.fetch() {
+                Ok((_, page)) => {
+                    self.documents = page.documents.unwrap_or_default().into_iter();
+                    match page.next_page_token {
+                        Some(ref token) if !token.is_empty() => self.call._page_token = Some(token.clone()),
+                        _ => self.done = true,
+                    }
+                },
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err))
+                }
+            }
+        }
+    }
 }
 
-impl ResponseResult for ListDocumentsResponse {}
+impl<'a, C, A> IntoIterator for ProjectDatabaseDocumentListCall<'a, C, A> where C: Transport, A: oauth2::GetToken {
+    type Item = Result<Document>;
+    type IntoIter = ListDocuments<'a, C, A>;
+
+    fn into_iter(self) -> ListDocuments<'a, C, A> {
+        self.all_documents()
+    }
+}
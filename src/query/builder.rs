@@ -0,0 +1,319 @@
+use crate::cmn::*;
+use crate::collection::*;
+use crate::query::*;
+
+/// The operator of a filter on a document field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    Equal,
+    NotEqual,
+    ArrayContains,
+    In,
+    ArrayContainsAny,
+    NotIn,
+}
+
+impl AsRef<str> for Op {
+    fn as_ref(&self) -> &str {
+        match *self {
+            Op::LessThan => "LESS_THAN",
+            Op::LessThanOrEqual => "LESS_THAN_OR_EQUAL",
+            Op::GreaterThan => "GREATER_THAN",
+            Op::GreaterThanOrEqual => "GREATER_THAN_OR_EQUAL",
+            Op::Equal => "EQUAL",
+            Op::NotEqual => "NOT_EQUAL",
+            Op::ArrayContains => "ARRAY_CONTAINS",
+            Op::In => "IN",
+            Op::ArrayContainsAny => "ARRAY_CONTAINS_ANY",
+            Op::NotIn => "NOT_IN",
+        }
+    }
+}
+
+/// The operator of a filter with a single operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnaryOp {
+    IsNan,
+    IsNull,
+    IsNotNan,
+    IsNotNull,
+}
+
+impl AsRef<str> for UnaryOp {
+    fn as_ref(&self) -> &str {
+        match *self {
+            UnaryOp::IsNan => "IS_NAN",
+            UnaryOp::IsNull => "IS_NULL",
+            UnaryOp::IsNotNan => "IS_NOT_NAN",
+            UnaryOp::IsNotNull => "IS_NOT_NULL",
+        }
+    }
+}
+
+/// The direction of an order on a field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+impl AsRef<str> for Direction {
+    fn as_ref(&self) -> &str {
+        match *self {
+            Direction::Ascending => "ASCENDING",
+            Direction::Descending => "DESCENDING",
+        }
+    }
+}
+
+fn field(path: &str) -> Option<FieldReference> {
+    Some(FieldReference { field_path: Some(path.to_string()) })
+}
+
+fn cursor<V: Into<ValueKind>>(values: Vec<V>, before: bool) -> Option<Cursor> {
+    Some(Cursor {
+        values: Some(values.into_iter().map(|v| Value::from(v.into())).collect()),
+        before: Some(before),
+    })
+}
+
+/// A builder for a `StructuredQuery`.
+///
+/// Multiple filters are combined with `AND`.
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate google_firestore as firestore1;
+/// use firestore1::{Query, Op, Direction};
+///
+/// let request = Query::collection("users")
+///     .where_field("age", Op::GreaterThan, 21)
+///     .order_by("age", Direction::Descending)
+///     .limit(10)
+///     .start_after(vec![42])
+///     .into_request();
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct Query {
+    query: StructuredQuery,
+    filters: Vec<Filter>,
+}
+
+impl Query {
+
+    /// A query on the collections with the given id which are immediate children
+    /// of the `parent` the query is run on.
+    pub fn collection(collection_id: &str) -> Query {
+        Query::collection_selector(collection_id, false)
+    }
+
+    /// A query on all collections with the given id which are descendants of the
+    /// `parent` the query is run on.
+    pub fn collection_group(collection_id: &str) -> Query {
+        Query::collection_selector(collection_id, true)
+    }
+
+    fn collection_selector(collection_id: &str, all_descendants: bool) -> Query {
+        let mut query = Query::default();
+        query.query.from = Some(vec![CollectionSelector {
+            collection_id: Some(collection_id.to_string()),
+            all_descendants: Some(all_descendants),
+        }]);
+        query
+    }
+
+    /// Only returns the given fields of the matching documents.
+    pub fn select(mut self, field_paths: &[&str]) -> Query {
+        self.query.select = Some(Projection {
+            fields: Some(field_paths.iter().map(|p| FieldReference { field_path: Some(p.to_string()) }).collect()),
+        });
+        self
+    }
+
+    /// Filters on the field at `field_path` comparing it to `value` using `op`.
+    pub fn where_field<V: Into<ValueKind>>(mut self, field_path: &str, op: Op, value: V) -> Query {
+        self.filters.push(Filter {
+            field_filter: Some(FieldFilter {
+                field: field(field_path),
+                op: Some(op.as_ref().to_string()),
+                value: Some(Value::from(value.into())),
+            }),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Filters on the field at `field_path` using the unary operator `op`.
+    pub fn where_unary(mut self, field_path: &str, op: UnaryOp) -> Query {
+        self.filters.push(Filter {
+            unary_filter: Some(UnaryFilter {
+                field: field(field_path),
+                op: Some(op.as_ref().to_string()),
+            }),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Orders the results by the field at `field_path`.
+    ///
+    /// Can be called repeatedly to order by multiple fields.
+    pub fn order_by(mut self, field_path: &str, direction: Direction) -> Query {
+        self.query.order_by.get_or_insert_with(Vec::new).push(Order {
+            field: field(field_path),
+            direction: Some(direction.as_ref().to_string()),
+        });
+        self
+    }
+
+    /// Returns at most `limit` results.
+    pub fn limit(mut self, limit: i32) -> Query {
+        self.query.limit = Some(limit);
+        self
+    }
+
+    /// Skips the first `offset` results.
+    pub fn offset(mut self, offset: i32) -> Query {
+        self.query.offset = Some(offset);
+        self
+    }
+
+    /// Starts the results at the position given by `values`, in the order of the
+    /// `order_by` clauses.
+    pub fn start_at<V: Into<ValueKind>>(mut self, values: Vec<V>) -> Query {
+        self.query.start_at = cursor(values, true);
+        self
+    }
+
+    /// Starts the results right after the position given by `values`, in the order
+    /// of the `order_by` clauses.
+    pub fn start_after<V: Into<ValueKind>>(mut self, values: Vec<V>) -> Query {
+        self.query.start_at = cursor(values, false);
+        self
+    }
+
+    /// Ends the results right before the position given by `values`, in the order
+    /// of the `order_by` clauses.
+    pub fn end_before<V: Into<ValueKind>>(mut self, values: Vec<V>) -> Query {
+        self.query.end_at = cursor(values, true);
+        self
+    }
+
+    /// Ends the results at the position given by `values`, in the order of the
+    /// `order_by` clauses.
+    pub fn end_at<V: Into<ValueKind>>(mut self, values: Vec<V>) -> Query {
+        self.query.end_at = cursor(values, false);
+        self
+    }
+
+    /// Builds the `StructuredQuery`.
+    pub fn into_structured_query(self) -> StructuredQuery {
+        let mut query = self.query;
+        let mut filters = self.filters;
+        query.where_ = match filters.len() {
+            0 => None,
+            1 => filters.pop(),
+            _ => Some(Filter {
+                composite_filter: Some(CompositeFilter {
+                    filters: Some(filters),
+                    op: Some("AND".to_string()),
+                }),
+                ..Default::default()
+            }),
+        };
+        query
+    }
+
+    /// Builds a `RunQueryRequest` running this query.
+    pub fn into_request(self) -> RunQueryRequest {
+        RunQueryRequest {
+            structured_query: Some(self.into_structured_query()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Query> for StructuredQuery {
+    fn from(query: Query) -> StructuredQuery {
+        query.into_structured_query()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json as json;
+    use crate::method::json_body;
+    use super::*;
+
+    fn to_json(query: Query) -> json::Value {
+        json::from_slice(&json_body(&StructuredQuery::from(query))).unwrap()
+    }
+
+    #[test]
+    fn collections() {
+        assert_eq!(to_json(Query::collection("users")),
+                   json::json!({"from": [{"collectionId": "users", "allDescendants": false}]}));
+        assert_eq!(to_json(Query::collection_group("messages").select(&["text", "author.name"])),
+                   json::json!({"from": [{"collectionId": "messages", "allDescendants": true}],
+                                "select": {"fields": [{"fieldPath": "text"}, {"fieldPath": "author.name"}]}}));
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(to_json(Query::collection("users").where_field("age", Op::GreaterThan, 21))["where"],
+                   json::json!({"fieldFilter": {"field": {"fieldPath": "age"}, "op": "GREATER_THAN",
+                                                "value": {"integerValue": "21"}}}));
+        assert_eq!(to_json(Query::collection("users").where_unary("email", UnaryOp::IsNotNull))["where"],
+                   json::json!({"unaryFilter": {"field": {"fieldPath": "email"}, "op": "IS_NOT_NULL"}}));
+
+        let query = Query::collection("users")
+            .where_field("name", Op::Equal, "alice")
+            .where_unary("score", UnaryOp::IsNan)
+            .where_field("tags", Op::ArrayContains, "admin");
+        assert_eq!(to_json(query)["where"], json::json!({"compositeFilter": {"op": "AND", "filters": [
+            {"fieldFilter": {"field": {"fieldPath": "name"}, "op": "EQUAL", "value": {"stringValue": "alice"}}},
+            {"unaryFilter": {"field": {"fieldPath": "score"}, "op": "IS_NAN"}},
+            {"fieldFilter": {"field": {"fieldPath": "tags"}, "op": "ARRAY_CONTAINS", "value": {"stringValue": "admin"}}},
+        ]}}));
+    }
+
+    #[test]
+    fn orders_limit_and_offset() {
+        let query = Query::collection("users")
+            .order_by("age", Direction::Descending)
+            .order_by("name", Direction::Ascending)
+            .limit(10)
+            .offset(20);
+        let query = to_json(query);
+        assert_eq!(query["orderBy"], json::json!([
+            {"field": {"fieldPath": "age"}, "direction": "DESCENDING"},
+            {"field": {"fieldPath": "name"}, "direction": "ASCENDING"},
+        ]));
+        assert_eq!(query["limit"], 10);
+        assert_eq!(query["offset"], 20);
+    }
+
+    #[test]
+    fn cursors() {
+        let query = to_json(Query::collection("users").start_at(vec![21, 1]).end_before(vec![65]));
+        assert_eq!(query["startAt"], json::json!({"values": [{"integerValue": "21"}, {"integerValue": "1"}], "before": true}));
+        assert_eq!(query["endAt"], json::json!({"values": [{"integerValue": "65"}], "before": true}));
+
+        let query = to_json(Query::collection("users").start_after(vec!["alice"]).end_at(vec!["bob"]));
+        assert_eq!(query["startAt"], json::json!({"values": [{"stringValue": "alice"}], "before": false}));
+        assert_eq!(query["endAt"], json::json!({"values": [{"stringValue": "bob"}], "before": false}));
+    }
+
+    #[test]
+    fn requests() {
+        let request = Query::collection("users").limit(1).into_request();
+        assert_eq!(json::from_slice::<json::Value>(&json_body(&request)).unwrap(),
+                   json::json!({"structuredQuery": {"from": [{"collectionId": "users", "allDescendants": false}],
+                                                    "limit": 1}}));
+    }
+}
@@ -1,8 +1,16 @@
 mod filter;
 mod projection;
+mod builder;
+mod validate;
+#[cfg(feature = "blocking")]
+mod stream;
 
 pub use filter::*;
 pub use projection::*;
+pub use builder::*;
+pub use validate::*;
+#[cfg(feature = "blocking")]
+pub use stream::*;
 
 use crate::cmn::*;
 use crate::collection::*;
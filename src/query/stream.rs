@@ -0,0 +1,64 @@
+use crate::cmn::*;
+use crate::transport::HttpResponse;
+use crate::query::*;
+use crate::stream::ResponseStream;
+
+/// The responses of a *runQuery* call, decoded one by one while they are read from
+/// the connection. It is returned by `ProjectDatabaseDocumentRunQueryCall::doit_stream()`.
+pub struct RunQueryStream {
+    responses: ResponseStream<RunQueryResponse>,
+    transaction: Option<String>,
+    read_time: Option<String>,
+    skipped_results: i64,
+}
+
+impl RunQueryStream {
+    pub(crate) fn new(response: HttpResponse) -> RunQueryStream {
+        RunQueryStream {
+            responses: ResponseStream::new(response),
+            transaction: None,
+            read_time: None,
+            skipped_results: 0,
+        }
+    }
+
+    /// The status and headers of the HTTP response.
+    pub fn response(&self) -> &HttpResponse {
+        self.responses.response()
+    }
+
+    /// The transaction started by the query, once it was received.
+    ///
+    /// It is only set if `RunQueryRequest.new_transaction` was set in the request.
+    pub fn transaction(&self) -> Option<&str> {
+        self.transaction.as_deref()
+    }
+
+    /// The latest `read_time` received so far.
+    pub fn read_time(&self) -> Option<&str> {
+        self.read_time.as_deref()
+    }
+
+    /// The total amount of results skipped due to an offset so far.
+    pub fn skipped_results(&self) -> i64 {
+        self.skipped_results
+    }
+}
+
+impl Iterator for RunQueryStream {
+    type Item = Result<RunQueryResponse>;
+
+    fn next(&mut self) -> Option<Result<RunQueryResponse>> {
+        let response = self.responses.next()?;
+        if let Ok(ref response) = response {
+            if let Some(ref transaction) = response.transaction {
+                self.transaction = Some(transaction.clone());
+            }
+            if let Some(ref read_time) = response.read_time {
+                self.read_time = Some(read_time.clone());
+            }
+            self.skipped_results += i64::from(response.skipped_results.unwrap_or(0));
+        }
+        Some(response)
+    }
+}
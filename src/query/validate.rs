@@ -0,0 +1,295 @@
+use crate::cmn::*;
+use crate::query::*;
+
+/// The maximum number of values in an `IN`, `NOT_IN` or `ARRAY_CONTAINS_ANY` filter.
+pub const MAX_DISJUNCTION_VALUES: usize = 10;
+
+/// The field path referring to the name of a document.
+const DOCUMENT_NAME_FIELD: &str = "__name__";
+
+/// A clause of a `StructuredQuery`, as named by `Error::InvalidQuery`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryClause {
+    Where,
+    OrderBy,
+    StartAt,
+    EndAt,
+    Limit,
+    Offset,
+}
+
+impl AsRef<str> for QueryClause {
+    fn as_ref(&self) -> &str {
+        match *self {
+            QueryClause::Where => "where",
+            QueryClause::OrderBy => "orderBy",
+            QueryClause::StartAt => "startAt",
+            QueryClause::EndAt => "endAt",
+            QueryClause::Limit => "limit",
+            QueryClause::Offset => "offset",
+        }
+    }
+}
+
+fn invalid<T>(clause: QueryClause, msg: String) -> Result<T> {
+    Err(Error::InvalidQuery(clause, msg))
+}
+
+fn field_path(field: &Option<FieldReference>) -> &str {
+    field.as_ref().and_then(|f| f.field_path.as_ref()).map_or("", String::as_str)
+}
+
+/// Collects the field and unary filters nested in `filter`.
+fn flatten<'a>(filter: &'a Filter, filters: &mut Vec<&'a Filter>) -> Result<()> {
+    match filter.composite_filter {
+        Some(ref composite) => {
+            let nested = composite.filters.as_ref().map_or(&[][..], Vec::as_slice);
+            if nested.is_empty() {
+                return invalid(QueryClause::Where, "a composite filter must contain at least one filter".to_string())
+            }
+            for filter in nested {
+                flatten(filter, filters)?;
+            }
+        },
+        None => filters.push(filter),
+    }
+    Ok(())
+}
+
+fn cursor_len(cursor: &Option<Cursor>) -> usize {
+    cursor.as_ref().and_then(|c| c.values.as_ref()).map_or(0, Vec::len)
+}
+
+impl StructuredQuery {
+
+    /// Checks the query against the rules Firestore enforces on the server, so an
+    /// invalid query fails without a round trip.
+    ///
+    /// The following is rejected with `Error::InvalidQuery`, naming the offending clause:
+    ///
+    /// * inequality filters on more than one field
+    /// * a first `order_by` on another field than the inequality filter
+    /// * `IN`, `NOT_IN` or `ARRAY_CONTAINS_ANY` filters without an array of at most
+    ///   `MAX_DISJUNCTION_VALUES` values
+    /// * cursors with more values than the query has orders, including the implicit
+    ///   orders on the inequality field and on `__name__`
+    /// * a negative `limit` or `offset`
+    pub fn validate(&self) -> Result<()> {
+        if let Some(limit) = self.limit.filter(|l| *l < 0) {
+            return invalid(QueryClause::Limit, format!("the limit must not be negative, got {}", limit))
+        }
+        if let Some(offset) = self.offset.filter(|o| *o < 0) {
+            return invalid(QueryClause::Offset, format!("the offset must not be negative, got {}", offset))
+        }
+
+        let mut filters = Vec::new();
+        if let Some(ref filter) = self.where_ {
+            flatten(filter, &mut filters)?;
+        }
+        let mut inequality: Option<&str> = None;
+        for filter in filters {
+            let (path, is_inequality) = if let Some(ref filter) = filter.field_filter {
+                let path = field_path(&filter.field);
+                let op = filter.op.as_ref().map_or("", String::as_str);
+                if let "IN" | "NOT_IN" | "ARRAY_CONTAINS_ANY" = op {
+                    let values = filter.value.as_ref()
+                        .and_then(|v| v.array_value.as_ref())
+                        .map(|a| a.values.as_ref().map_or(0, Vec::len));
+                    match values {
+                        None => return invalid(QueryClause::Where,
+                                    format!("the {} filter on '{}' requires an array value", op, path)),
+                        Some(0) => return invalid(QueryClause::Where,
+                                    format!("the {} filter on '{}' requires a non-empty array", op, path)),
+                        Some(len) if len > MAX_DISJUNCTION_VALUES => return invalid(QueryClause::Where,
+                                    format!("the {} filter on '{}' has {} values, but at most {} are allowed",
+                                            op, path, len, MAX_DISJUNCTION_VALUES)),
+                        _ => {},
+                    }
+                }
+                (path, matches!(op, "LESS_THAN" | "LESS_THAN_OR_EQUAL" | "GREATER_THAN" |
+                                    "GREATER_THAN_OR_EQUAL" | "NOT_EQUAL" | "NOT_IN"))
+            } else if let Some(ref filter) = filter.unary_filter {
+                let op = filter.op.as_ref().map_or("", String::as_str);
+                (field_path(&filter.field), op == "IS_NOT_NAN" || op == "IS_NOT_NULL")
+            } else {
+                continue
+            };
+            if is_inequality {
+                match inequality {
+                    Some(field) if field != path =>
+                        return invalid(QueryClause::Where,
+                                       format!("inequality filters on '{}' and '{}', but all inequality filters \
+                                                must be on the same field", field, path)),
+                    _ => inequality = Some(path),
+                }
+            }
+        }
+
+        let order_by = self.order_by.as_ref().map_or(&[][..], Vec::as_slice);
+        if let (Some(field), Some(first)) = (inequality, order_by.first()) {
+            let path = field_path(&first.field);
+            if path != field {
+                return invalid(QueryClause::OrderBy,
+                               format!("the first order is on '{}', but must be on the inequality field '{}'",
+                                       path, field))
+            }
+        }
+
+        let mut orders = order_by.len();
+        if order_by.is_empty() && inequality.is_some() {
+            orders += 1;
+        }
+        let orders_by_name = inequality == Some(DOCUMENT_NAME_FIELD)
+            || order_by.iter().any(|o| field_path(&o.field) == DOCUMENT_NAME_FIELD);
+        if !orders_by_name {
+            orders += 1;
+        }
+        for &(clause, cursor) in &[(QueryClause::StartAt, &self.start_at), (QueryClause::EndAt, &self.end_at)] {
+            let len = cursor_len(cursor);
+            if len > orders {
+                return invalid(clause, format!("the cursor has {} values, but the query is only ordered by {} fields",
+                                               len, orders))
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cmn::*;
+    use crate::query::*;
+    use crate::value::ValueKind;
+
+    fn clause(query: Query) -> Option<QueryClause> {
+        match query.into_structured_query().validate() {
+            Ok(()) => None,
+            Err(Error::InvalidQuery(clause, _)) => Some(clause),
+            Err(err) => panic!("unexpected error: {}", err),
+        }
+    }
+
+    fn array(len: usize) -> ValueKind {
+        ValueKind::Array((0..len as i64).map(ValueKind::Integer).collect())
+    }
+
+    #[test]
+    fn valid_queries() {
+        assert_eq!(clause(Query::collection("users")), None);
+        assert_eq!(clause(Query::collection("users")
+                              .where_field("age", Op::GreaterThan, 21)
+                              .where_field("age", Op::LessThan, 65)
+                              .where_field("city", Op::Equal, "Paris".to_string())
+                              .order_by("age", Direction::Descending)
+                              .order_by("name", Direction::Ascending)
+                              .start_after(vec![42, 7, 1])
+                              .limit(10)
+                              .offset(0)), None);
+        assert_eq!(clause(Query::collection("users").where_field("tags", Op::In, array(10))), None);
+    }
+
+    #[test]
+    fn inequalities_on_two_fields() {
+        assert_eq!(clause(Query::collection("users")
+                              .where_field("age", Op::GreaterThan, 21)
+                              .where_field("height", Op::LessThan, 200)),
+                   Some(QueryClause::Where));
+        assert_eq!(clause(Query::collection("users")
+                              .where_field("age", Op::NotEqual, 21)
+                              .where_unary("email", UnaryOp::IsNotNull)),
+                   Some(QueryClause::Where));
+        // Equality filters and IS_NULL may be on other fields than the inequality.
+        assert_eq!(clause(Query::collection("users")
+                              .where_field("age", Op::GreaterThan, 21)
+                              .where_field("height", Op::Equal, 200)
+                              .where_unary("email", UnaryOp::IsNull)),
+                   None);
+    }
+
+    #[test]
+    fn inequalities_in_nested_composite_filters() {
+        let mut query = Query::collection("users")
+            .where_field("age", Op::GreaterThan, 21)
+            .where_field("city", Op::Equal, "Paris".to_string())
+            .into_structured_query();
+        let nested = query.where_.take();
+        query.where_ = Some(Filter {
+            composite_filter: Some(CompositeFilter {
+                op: Some("AND".to_string()),
+                filters: Some(vec![
+                    nested.unwrap(),
+                    Query::collection("users").where_field("height", Op::LessThan, 200).into_structured_query().where_.unwrap(),
+                ]),
+            }),
+            ..Default::default()
+        });
+        match query.validate() {
+            Err(Error::InvalidQuery(QueryClause::Where, ref msg)) => assert!(msg.contains("'height'")),
+            other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+        }
+    }
+
+    #[test]
+    fn empty_composite_filter() {
+        let mut query = Query::collection("users").into_structured_query();
+        query.where_ = Some(Filter {
+            composite_filter: Some(CompositeFilter { op: Some("AND".to_string()), filters: Some(Vec::new()) }),
+            ..Default::default()
+        });
+        match query.validate() {
+            Err(Error::InvalidQuery(QueryClause::Where, _)) => {},
+            other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+        }
+    }
+
+    #[test]
+    fn first_order_not_on_inequality_field() {
+        assert_eq!(clause(Query::collection("users")
+                              .where_field("age", Op::GreaterThan, 21)
+                              .order_by("name", Direction::Ascending)),
+                   Some(QueryClause::OrderBy));
+        assert_eq!(clause(Query::collection("users")
+                              .where_field("age", Op::GreaterThan, 21)
+                              .order_by("name", Direction::Ascending)
+                              .order_by("age", Direction::Ascending)),
+                   Some(QueryClause::OrderBy));
+    }
+
+    #[test]
+    fn disjunctions() {
+        for &op in &[Op::In, Op::NotIn, Op::ArrayContainsAny] {
+            assert_eq!(clause(Query::collection("users").where_field("tags", op, 1)),
+                       Some(QueryClause::Where), "{:?} without an array", op);
+            assert_eq!(clause(Query::collection("users").where_field("tags", op, array(0))),
+                       Some(QueryClause::Where), "{:?} with an empty array", op);
+            assert_eq!(clause(Query::collection("users").where_field("tags", op, array(MAX_DISJUNCTION_VALUES + 1))),
+                       Some(QueryClause::Where), "{:?} with too many values", op);
+        }
+    }
+
+    #[test]
+    fn too_many_cursor_values() {
+        // Ordered by `name` and implicitly by `__name__`.
+        let ordered = || Query::collection("users").order_by("name", Direction::Ascending);
+        assert_eq!(clause(ordered().start_at(vec![1, 2])), None);
+        assert_eq!(clause(ordered().start_at(vec![1, 2, 3])), Some(QueryClause::StartAt));
+        assert_eq!(clause(ordered().end_before(vec![1, 2, 3])), Some(QueryClause::EndAt));
+
+        // Implicitly ordered by the inequality field and by `__name__`.
+        let inequality = || Query::collection("users").where_field("age", Op::GreaterThan, 21);
+        assert_eq!(clause(inequality().end_at(vec![1, 2])), None);
+        assert_eq!(clause(inequality().end_at(vec![1, 2, 3])), Some(QueryClause::EndAt));
+
+        // An explicit order on `__name__` replaces the implicit one.
+        let by_name = || Query::collection("users").order_by("__name__", Direction::Ascending);
+        assert_eq!(clause(by_name().start_after(vec![1])), None);
+        assert_eq!(clause(by_name().start_after(vec![1, 2])), Some(QueryClause::StartAt));
+        assert_eq!(clause(Query::collection("users").start_at(vec![1, 2])), Some(QueryClause::StartAt));
+    }
+
+    #[test]
+    fn negative_limit_and_offset() {
+        assert_eq!(clause(Query::collection("users").limit(-1)), Some(QueryClause::Limit));
+        assert_eq!(clause(Query::collection("users").offset(-1)), Some(QueryClause::Offset));
+    }
+}
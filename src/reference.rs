@@ -0,0 +1,172 @@
+use crate::cmn::*;
+use crate::firestore::*;
+use crate::document::*;
+use crate::collection::*;
+use crate::listen::*;
+use crate::path::*;
+use crate::projectmethods::*;
+use crate::query::*;
+use crate::transport::Transport;
+
+/// A handle to a collection, creating the calls on it and on its documents.
+///
+/// It is obtained from `Firestore::collection()` or `DocumentReference::collection()`.
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate hyper;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// # use firestore1::{Firestore, Result};
+/// # fn egal<A: oauth2::GetToken>(hub: Firestore<hyper::Client, A>) -> Result<()> {
+/// let alice = hub.collection("users")?.doc("alice")?;
+/// let document = alice.get_optional()?;
+/// let (_, post) = alice.collection("posts")?.add(Default::default()).doit()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct CollectionReference<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    path: CollectionPath,
+}
+
+impl<'a, C, A> CollectionReference<'a, C, A>
+    where C: Transport, A: oauth2::GetToken {
+
+    /// A handle to the collection with the given path.
+    pub fn new(hub: &'a Firestore<C, A>, path: CollectionPath) -> CollectionReference<'a, C, A> {
+        CollectionReference { hub, path }
+    }
+
+    pub fn path(&self) -> &CollectionPath {
+        &self.path
+    }
+
+    pub fn id(&self) -> &str {
+        self.path.id()
+    }
+
+    /// The document containing this collection, or `None` for a root collection.
+    pub fn parent(&self) -> Option<DocumentReference<'a, C, A>> {
+        self.path.parent().map(|path| DocumentReference::new(self.hub, path))
+    }
+
+    /// A handle to the document with the given id in this collection.
+    pub fn doc(&self, id: &str) -> Result<DocumentReference<'a, C, A>> {
+        Ok(DocumentReference::new(self.hub, self.path.doc(id)?))
+    }
+
+    /// Creates a new document with an id assigned by the server.
+    ///
+    /// Use `document_id()` on the returned call to choose the id instead.
+    pub fn add(&self, document: Document) -> ProjectDatabaseDocumentCreateDocumentCall<'a, C, A> {
+        self.hub.projects().databases_documents_create_document_in(document, &self.path)
+    }
+
+    /// Lists the documents of this collection.
+    pub fn list(&self) -> ProjectDatabaseDocumentListCall<'a, C, A> {
+        self.hub.projects().databases_documents_list_in(&self.path)
+    }
+
+    /// Listens through `channel` to the results of `query` on this collection, see
+    /// `Firestore::listen_query()`.
+    ///
+    /// The collections the query selects are replaced by this collection.
+    pub fn listen<L: ListenChannel>(&self, channel: L, mut query: StructuredQuery) -> Result<Listener<L>> {
+        query.from = Some(vec![CollectionSelector {
+            collection_id: Some(self.path.id().to_string()),
+            all_descendants: Some(false),
+        }]);
+        self.hub.listen_query(channel, self.path.parent_name(), query)
+    }
+}
+
+/// A handle to a document, creating the calls on it.
+///
+/// It is obtained from `CollectionReference::doc()`.
+pub struct DocumentReference<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    path: DocumentPath,
+}
+
+impl<'a, C, A> DocumentReference<'a, C, A>
+    where C: Transport, A: oauth2::GetToken {
+
+    /// A handle to the document with the given path.
+    pub fn new(hub: &'a Firestore<C, A>, path: DocumentPath) -> DocumentReference<'a, C, A> {
+        DocumentReference { hub, path }
+    }
+
+    pub fn path(&self) -> &DocumentPath {
+        &self.path
+    }
+
+    pub fn id(&self) -> &str {
+        self.path.id()
+    }
+
+    /// The collection containing this document.
+    pub fn parent(&self) -> CollectionReference<'a, C, A> {
+        CollectionReference::new(self.hub, self.path.parent())
+    }
+
+    /// A handle to the subcollection with the given id of this document.
+    pub fn collection(&self, id: &str) -> Result<CollectionReference<'a, C, A>> {
+        Ok(CollectionReference::new(self.hub, self.path.collection(id)?))
+    }
+
+    /// Gets the document.
+    pub fn get(&self) -> ProjectDatabaseDocumentGetCall<'a, C, A> {
+        self.hub.projects().databases_documents_get(&self.path)
+    }
+
+    /// Gets the document if it exists, see `ProjectDatabaseDocumentGetCall::doit_optional()`.
+    pub fn get_optional(&self) -> Result<Option<Document>> {
+        Ok(self.get().doit_optional()?.map(|(_, document)| document))
+    }
+
+    /// Replaces the document with the fields of `document`, creating it if it does not exist.
+    ///
+    /// The name of `document` is ignored.
+    pub fn set(&self, mut document: Document) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        document.name = Some(self.path.to_string());
+        self.hub.projects().databases_documents_patch(document, &self.path)
+    }
+
+    /// Updates the fields of the existing document which are named by `field_paths`.
+    ///
+    /// Fields listed in `field_paths` but missing in `document` are deleted. The update
+    /// fails if the document does not exist. The name of `document` is ignored.
+    pub fn update(&self, mut document: Document, field_paths: &[&str]) -> ProjectDatabaseDocumentPatchCall<'a, C, A> {
+        document.name = Some(self.path.to_string());
+        field_paths.iter().fold(self.hub.projects().databases_documents_patch(document, &self.path),
+                                |call, field_path| call.add_update_mask_field_paths(field_path))
+            .current_document_exists(true)
+    }
+
+    /// Deletes the document.
+    pub fn delete(&self) -> ProjectDatabaseDocumentDeleteCall<'a, C, A> {
+        self.hub.projects().databases_documents_delete(&self.path)
+    }
+
+    /// Listens through `channel` to the changes of the document, see
+    /// `Firestore::listen_document()`.
+    pub fn listen<L: ListenChannel>(&self, channel: L) -> Result<Listener<L>> {
+        self.hub.listen_document(channel, &self.path)
+    }
+}
+
+impl<'a, C, A> Firestore<C, A>
+    where C: Transport, A: oauth2::GetToken {
+
+    /// A handle to the root collection with the given id in the database set with
+    /// `database()`.
+    pub fn collection(&'a self, id: &str) -> Result<CollectionReference<'a, C, A>> {
+        Ok(CollectionReference::new(self, self.default_database()?.collection(id)?))
+    }
+}
@@ -0,0 +1,366 @@
+use std::cmp;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use oauth2::Retry;
+use crate::cmn::*;
+use crate::status::Code;
+use crate::transport::HttpResponse;
+
+/// The methods which can be sent again without changing their outcome. A *commit*
+/// may apply transforms twice, and a *createDocument* may create two documents.
+const IDEMPOTENT_METHODS: &[&str] = &[
+    "firestore.projects.databases.documents.get",
+    "firestore.projects.databases.documents.list",
+    "firestore.projects.databases.documents.listCollectionIds",
+    "firestore.projects.databases.documents.batchGet",
+    "firestore.projects.databases.documents.runQuery",
+    "firestore.projects.databases.documents.patch",
+    "firestore.projects.databases.documents.delete",
+    "firestore.projects.databases.documents.beginTransaction",
+    "firestore.projects.databases.documents.rollback",
+];
+
+/// Parses a `Retry-After` header, given either in seconds or as a HTTP date like
+/// `Wed, 21 Oct 2015 07:28:00 GMT`.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds))
+    }
+    let parts: Vec<&str> = value.split(&[' ', ':'][..]).collect();
+    if parts.len() != 8 || parts[7] != "GMT" {
+        return None
+    }
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    let day: i64 = parts[1].parse().ok()?;
+    let month = MONTHS.iter().position(|m| *m == parts[2])? as i64 + 1;
+    let year: i64 = parts[3].parse().ok()?;
+    let (hour, minute, second): (i64, i64, i64) = (parts[4].parse().ok()?, parts[5].parse().ok()?, parts[6].parse().ok()?);
+    // The days since the epoch of a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    let at = UNIX_EPOCH + Duration::from_secs((days * 86_400 + hour * 3600 + minute * 60 + second).max(0) as u64);
+    Some(at.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+/// A `Delegate` retrying failed requests with exponential backoff and jitter.
+///
+/// The following failures are retried, until `max_attempts()` attempts were made or
+/// the next attempt would start after the `deadline()`:
+///
+/// * `RESOURCE_EXHAUSTED`, for any call, as the server rejected it before executing it
+/// * `UNAVAILABLE`, `DEADLINE_EXCEEDED`, `ABORTED` and connection errors, for idempotent
+///   calls only, unless `retry_non_idempotent()` is set. *commit* and *createDocument*
+///   are not idempotent.
+///
+/// Any other failure, e.g. `INVALID_ARGUMENT` or `NOT_FOUND`, is returned right away.
+/// If the server sends a `Retry-After` header, the next attempt is made no earlier
+/// than requested.
+///
+/// Transactions retry `ABORTED` commits on their own, see `Firestore::run_transaction()`.
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate hyper;
+/// # extern crate yup_oauth2 as oauth2;
+/// # extern crate google_firestore as firestore1;
+/// # use std::time::Duration;
+/// # use firestore1::{Firestore, RetryingDelegate, Result};
+/// # fn egal<A: oauth2::GetToken>(hub: Firestore<hyper::Client, A>) -> Result<()> {
+/// let mut retry = RetryingDelegate::new()
+///     .max_attempts(8)
+///     .deadline(Duration::from_secs(30));
+/// let (_, document) = hub.projects()
+///     .databases_documents_get("projects/p/databases/(default)/documents/users/alice")
+///     .delegate(&mut retry)
+///     .doit()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryingDelegate {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    deadline: Option<Duration>,
+    retry_non_idempotent: bool,
+
+    method: &'static str,
+    attempts: u32,
+    started: Instant,
+    rng: u64,
+}
+
+impl Default for RetryingDelegate {
+    fn default() -> RetryingDelegate {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.subsec_nanos() as u64);
+        RetryingDelegate {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            deadline: Some(Duration::from_secs(60)),
+            retry_non_idempotent: false,
+            method: "",
+            attempts: 0,
+            started: Instant::now(),
+            rng: seed | 1,
+        }
+    }
+}
+
+impl RetryingDelegate {
+
+    pub fn new() -> RetryingDelegate {
+        RetryingDelegate::default()
+    }
+
+    /// The maximum amount of attempts of a call, including the first one.
+    /// It defaults to 5.
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryingDelegate {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// The delay before the first retry. It defaults to 100 milliseconds.
+    pub fn initial_delay(mut self, initial_delay: Duration) -> RetryingDelegate {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// The maximum delay between two attempts, unless the server asks for a longer one
+    /// with `Retry-After`. It defaults to 30 seconds.
+    pub fn max_delay(mut self, max_delay: Duration) -> RetryingDelegate {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// The factor by which the delay grows with every retry. It defaults to 2.
+    ///
+    /// Factors below 1, and NaN, are replaced by 1, so the delay never shrinks.
+    pub fn multiplier(mut self, multiplier: f64) -> RetryingDelegate {
+        self.multiplier = if multiplier >= 1.0 { multiplier } else { 1.0 };
+        self
+    }
+
+    /// The time after the start of a call after which no further attempt is started,
+    /// or `None` to only limit the amount of attempts. It defaults to 60 seconds.
+    pub fn deadline<D: Into<Option<Duration>>>(mut self, deadline: D) -> RetryingDelegate {
+        self.deadline = deadline.into();
+        self
+    }
+
+    /// Whether to retry calls which are not idempotent after failures which may have
+    /// happened after the server executed them. It defaults to `false`.
+    pub fn retry_non_idempotent(mut self, retry: bool) -> RetryingDelegate {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// The amount of attempts made by the current or last call.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// A random number in `[0, 1)`.
+    fn random(&mut self) -> f64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// The delay before the next attempt, or `Retry::Abort` if no further attempt may
+    /// be made.
+    fn next_attempt(&mut self, retry_after: Option<Duration>) -> Retry {
+        if self.attempts >= self.max_attempts {
+            return Retry::Abort
+        }
+        let backoff = self.initial_delay.as_secs_f64() * self.multiplier.powi(self.attempts as i32 - 1);
+        let backoff = backoff.min(self.max_delay.as_secs_f64());
+        // The delay is picked at random from the upper half of the backoff, so clients
+        // failing at the same time don't retry at the same time.
+        let delay = Duration::from_secs_f64(backoff * (0.5 + self.random() / 2.0));
+        let delay = cmp::max(delay, retry_after.unwrap_or_default());
+        if let Some(deadline) = self.deadline {
+            if self.started.elapsed() + delay > deadline {
+                return Retry::Abort
+            }
+        }
+        self.attempts += 1;
+        Retry::After(delay)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        self.retry_non_idempotent || IDEMPOTENT_METHODS.contains(&self.method)
+    }
+}
+
+impl Delegate for RetryingDelegate {
+    fn begin(&mut self, info: MethodInfo) {
+        self.method = info.id;
+        self.attempts = 1;
+        self.started = Instant::now();
+    }
+
+    fn http_error(&mut self, _: &hyper::Error) -> Retry {
+        if !self.is_idempotent() {
+            return Retry::Abort
+        }
+        self.next_attempt(None)
+    }
+
+    fn http_failure(&mut self, response: &HttpResponse, _: Option<JsonServerError>,
+                    error: Option<ServerError>) -> Retry {
+        let code = error.as_ref().and_then(|e| e.status.as_deref()).and_then(Code::from_name)
+            .unwrap_or_else(|| Code::from_http_status(response.status.to_u16()));
+        let retry = match code {
+            Code::ResourceExhausted => true,
+            Code::Unavailable | Code::DeadlineExceeded | Code::Aborted => self.is_idempotent(),
+            _ => false,
+        };
+        if !retry {
+            return Retry::Abort
+        }
+        let retry_after = response.headers.get_raw("Retry-After")
+            .and_then(|values| values.first())
+            .and_then(|value| std::str::from_utf8(value).ok())
+            .and_then(parse_retry_after);
+        self.next_attempt(retry_after)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use crate::cmn::*;
+    use crate::status::Code;
+    use crate::testing::{Failure, MockFirestore};
+    use crate::transaction::CommitRequest;
+    use super::*;
+
+    const DATABASE: &str = "projects/p/databases/(default)";
+    const ALICE: &str = "projects/p/databases/(default)/documents/users/alice";
+
+    fn retry() -> RetryingDelegate {
+        RetryingDelegate::new().initial_delay(Duration::from_millis(1))
+    }
+
+    fn code<T>(result: Result<T>) -> Option<Code> {
+        match result {
+            Ok(_) => None,
+            Err(err) => Some(err.code().expect("a Firestore error")),
+        }
+    }
+
+    #[test]
+    fn retried_failures() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+        let get = |retry: &mut RetryingDelegate| {
+            hub.projects().databases_documents_get(ALICE).delegate(retry).doit_optional()
+        };
+        let commit = |retry: &mut RetryingDelegate| {
+            hub.projects().databases_documents_commit(CommitRequest::default(), DATABASE).delegate(retry).doit()
+        };
+
+        let mut retry = retry();
+        server.fail_next(Failure::status(400, "INVALID_ARGUMENT", "bad request"));
+        assert_eq!(code(get(&mut retry)), Some(Code::InvalidArgument));
+        assert_eq!(retry.attempts(), 1);
+
+        for (status, code) in [(503, "UNAVAILABLE"), (409, "ABORTED"), (504, "DEADLINE_EXCEEDED")].iter() {
+            server.fail_next(Failure::status(*status, code, "try again"));
+            assert!(get(&mut retry).is_ok());
+            assert_eq!(retry.attempts(), 2);
+
+            server.fail_next(Failure::status(*status, code, "try again"));
+            assert!(commit(&mut retry).is_err());
+            assert_eq!(retry.attempts(), 1);
+        }
+
+        server.fail_next(Failure::status(429, "RESOURCE_EXHAUSTED", "quota exceeded"));
+        assert!(commit(&mut retry).is_ok());
+        assert_eq!(retry.attempts(), 2);
+
+        let mut retry = retry.retry_non_idempotent(true);
+        server.fail_next(Failure::status(409, "ABORTED", "too much contention"));
+        assert!(commit(&mut retry).is_ok());
+        assert_eq!(retry.attempts(), 2);
+    }
+
+    #[test]
+    fn connection_errors() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+        let mut retry = retry();
+
+        server.fail_next(Failure::connection_reset());
+        assert!(hub.projects().databases_documents_get(ALICE).delegate(&mut retry).doit_optional().is_ok());
+        assert_eq!(retry.attempts(), 2);
+
+        server.fail_next(Failure::connection_reset());
+        match hub.projects().databases_documents_commit(CommitRequest::default(), DATABASE).delegate(&mut retry).doit() {
+            Err(Error::HttpError(_)) => assert_eq!(retry.attempts(), 1),
+            other => panic!("unexpected result: {:?}", other.map(|r| r.1).map_err(|e| e.to_string())),
+        }
+    }
+
+    #[test]
+    fn max_attempts_and_deadline() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+        for _ in 0..5 {
+            server.fail_next(Failure::status(503, "UNAVAILABLE", "try again"));
+        }
+        let mut retry = retry().max_attempts(3);
+        let result = hub.projects().databases_documents_get(ALICE).delegate(&mut retry).doit();
+        assert_eq!(code(result), Some(Code::Unavailable));
+        assert_eq!(retry.attempts(), 3);
+        assert_eq!(server.requests().len(), 3);
+
+        let mut retry = retry.max_attempts(10).initial_delay(Duration::from_secs(10)).deadline(Duration::from_secs(1));
+        let result = hub.projects().databases_documents_get(ALICE).delegate(&mut retry).doit();
+        assert_eq!(code(result), Some(Code::Unavailable));
+        assert_eq!(retry.attempts(), 1);
+        assert_eq!(server.requests().len(), 4);
+    }
+
+    #[test]
+    fn multipliers() {
+        for multiplier in [0.5, -2.0, f64::NAN].iter() {
+            let mut retry = RetryingDelegate::new().multiplier(*multiplier).max_attempts(4);
+            retry.attempts = 1;
+            for _ in 0..3 {
+                match retry.next_attempt(None) {
+                    Retry::After(delay) => assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100)),
+                    _ => panic!("the delegate did not retry"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::from_secs(0)));
+
+        // 2105-10-21T07:28:00Z
+        let at = UNIX_EPOCH + Duration::from_secs(4_285_553_280);
+        let expected = at.duration_since(SystemTime::now()).unwrap();
+        let delay = parse_retry_after("Wed, 21 Oct 2105 07:28:00 GMT").unwrap();
+        assert!(expected - delay < Duration::from_secs(5), "{:?} is not {:?}", delay, expected);
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::from_secs(0)));
+        assert_eq!(parse_retry_after("Thu, 01 Jan 1970 00:00:00 GMT"), Some(Duration::from_secs(0)));
+
+        for malformed in ["", "soon", "-1", "1.5", "Wed, 21 Oct 2105 07:28:00 UTC", "Wed, 21 Foo 2105 07:28:00 GMT",
+                          "Wed, xx Oct 2105 07:28:00 GMT", "Wed, 21 Oct 2105 07:28 GMT"].iter() {
+            assert_eq!(parse_retry_after(malformed), None, "{}", malformed);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt::{self, Display};
+use hyper::status::StatusCode;
+use serde_json as json;
+use crate::cmn::*;
+
+/// The canonical error codes of Google APIs, as named by the `status` of an error
+/// response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Code {
+    Cancelled,
+    Unknown,
+    InvalidArgument,
+    DeadlineExceeded,
+    NotFound,
+    AlreadyExists,
+    PermissionDenied,
+    ResourceExhausted,
+    FailedPrecondition,
+    Aborted,
+    OutOfRange,
+    Unimplemented,
+    Internal,
+    Unavailable,
+    DataLoss,
+    Unauthenticated,
+}
+
+const CODES: [Code; 16] = [
+    Code::Cancelled, Code::Unknown, Code::InvalidArgument, Code::DeadlineExceeded, Code::NotFound,
+    Code::AlreadyExists, Code::PermissionDenied, Code::ResourceExhausted, Code::FailedPrecondition,
+    Code::Aborted, Code::OutOfRange, Code::Unimplemented, Code::Internal, Code::Unavailable,
+    Code::DataLoss, Code::Unauthenticated,
+];
+
+impl AsRef<str> for Code {
+    fn as_ref(&self) -> &str {
+        match *self {
+            Code::Cancelled => "CANCELLED",
+            Code::Unknown => "UNKNOWN",
+            Code::InvalidArgument => "INVALID_ARGUMENT",
+            Code::DeadlineExceeded => "DEADLINE_EXCEEDED",
+            Code::NotFound => "NOT_FOUND",
+            Code::AlreadyExists => "ALREADY_EXISTS",
+            Code::PermissionDenied => "PERMISSION_DENIED",
+            Code::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            Code::FailedPrecondition => "FAILED_PRECONDITION",
+            Code::Aborted => "ABORTED",
+            Code::OutOfRange => "OUT_OF_RANGE",
+            Code::Unimplemented => "UNIMPLEMENTED",
+            Code::Internal => "INTERNAL",
+            Code::Unavailable => "UNAVAILABLE",
+            Code::DataLoss => "DATA_LOSS",
+            Code::Unauthenticated => "UNAUTHENTICATED",
+        }
+    }
+}
+
+impl Code {
+
+    /// The code with the given name, e.g. `NOT_FOUND`.
+    pub fn from_name(name: &str) -> Option<Code> {
+        CODES.iter().cloned().find(|code| code.as_ref() == name)
+    }
+
+    /// The code an error response with the given HTTP status most likely has, for
+    /// responses which don't name their status.
+    ///
+    /// Both `ABORTED` and `ALREADY_EXISTS` are sent as `409 Conflict`, which is read
+    /// as `Code::Aborted`.
+    pub fn from_http_status(status: u16) -> Code {
+        match status {
+            400 => Code::InvalidArgument,
+            401 => Code::Unauthenticated,
+            403 => Code::PermissionDenied,
+            404 => Code::NotFound,
+            409 => Code::Aborted,
+            429 => Code::ResourceExhausted,
+            499 => Code::Cancelled,
+            501 => Code::Unimplemented,
+            503 => Code::Unavailable,
+            504 => Code::DeadlineExceeded,
+            500..=599 => Code::Internal,
+            _ => Code::Unknown,
+        }
+    }
+}
+
+impl Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// The body of an error response, `{"error": {"code": 404, "message": ..., "status": "NOT_FOUND"}}`.
+#[derive(Deserialize)]
+struct StatusResponse {
+    error: StatusBody,
+}
+
+#[derive(Deserialize)]
+struct StatusBody {
+    message: Option<String>,
+    status: Option<String>,
+    details: Option<Vec<json::Value>>,
+}
+
+/// An error response of the Firestore API.
+#[derive(Clone, Debug)]
+pub struct FirestoreError {
+    /// The canonical error code.
+    pub code: Code,
+    /// The developer-facing error message, or the raw body of a response which isn't
+    /// a Firestore error.
+    pub message: String,
+    /// The typed details of the error, each one an object with an `@type` field.
+    pub details: Vec<json::Value>,
+    /// The HTTP status of the response.
+    pub http_status: StatusCode,
+}
+
+impl FirestoreError {
+
+    /// Decodes an error response with the given status and body.
+    pub fn from_response(http_status: StatusCode, body: &str) -> FirestoreError {
+        match json::from_str::<StatusResponse>(body) {
+            Ok(response) => FirestoreError {
+                code: response.error.status.as_deref().and_then(Code::from_name)
+                    .unwrap_or_else(|| Code::from_http_status(http_status.to_u16())),
+                message: response.error.message.unwrap_or_default(),
+                details: response.error.details.unwrap_or_default(),
+                http_status,
+            },
+            Err(_) => FirestoreError {
+                code: Code::from_http_status(http_status.to_u16()),
+                message: body.to_string(),
+                details: Vec::new(),
+                http_status,
+            },
+        }
+    }
+
+    /// Whether the document or other resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.code == Code::NotFound
+    }
+
+    /// Whether the document to create already exists.
+    pub fn is_already_exists(&self) -> bool {
+        self.code == Code::AlreadyExists
+    }
+
+    /// Whether a precondition failed, e.g. a document's update time, or a query
+    /// requires an index which does not exist.
+    pub fn is_failed_precondition(&self) -> bool {
+        self.code == Code::FailedPrecondition
+    }
+
+    /// Whether a transaction was aborted because of contention.
+    pub fn is_aborted(&self) -> bool {
+        self.code == Code::Aborted
+    }
+
+    /// Whether the caller may not perform the operation.
+    pub fn is_permission_denied(&self) -> bool {
+        self.code == Code::PermissionDenied
+    }
+
+    /// Whether the request had no valid credentials.
+    pub fn is_unauthenticated(&self) -> bool {
+        self.code == Code::Unauthenticated
+    }
+
+    /// Whether the service is temporarily unavailable, or a quota was exhausted.
+    pub fn is_unavailable(&self) -> bool {
+        self.code == Code::Unavailable || self.code == Code::ResourceExhausted
+    }
+}
+
+impl Display for FirestoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.code, self.http_status.to_u16(), self.message)
+    }
+}
+
+impl Error {
+
+    /// The Firestore error response this error was created from, if any.
+    pub fn firestore_error(&self) -> Option<&FirestoreError> {
+        match *self {
+            Error::Firestore(ref err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// The code of the Firestore error response, if any.
+    pub fn code(&self) -> Option<Code> {
+        self.firestore_error().map(|err| err.code)
+    }
+
+    /// See `FirestoreError::is_not_found()`.
+    pub fn is_not_found(&self) -> bool {
+        self.code() == Some(Code::NotFound)
+    }
+
+    /// See `FirestoreError::is_already_exists()`.
+    pub fn is_already_exists(&self) -> bool {
+        self.code() == Some(Code::AlreadyExists)
+    }
+
+    /// See `FirestoreError::is_failed_precondition()`.
+    pub fn is_failed_precondition(&self) -> bool {
+        self.code() == Some(Code::FailedPrecondition)
+    }
+
+    /// See `FirestoreError::is_aborted()`.
+    pub fn is_aborted(&self) -> bool {
+        self.code() == Some(Code::Aborted)
+    }
+
+    /// See `FirestoreError::is_permission_denied()`.
+    pub fn is_permission_denied(&self) -> bool {
+        self.code() == Some(Code::PermissionDenied)
+    }
+
+    /// See `FirestoreError::is_unauthenticated()`.
+    pub fn is_unauthenticated(&self) -> bool {
+        self.code() == Some(Code::Unauthenticated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_names() {
+        for code in CODES.iter() {
+            assert_eq!(Code::from_name(code.as_ref()), Some(*code));
+            assert_eq!(code.to_string(), code.as_ref());
+        }
+        let mut names: Vec<&str> = CODES.iter().map(AsRef::as_ref).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), 16);
+        assert_eq!(Code::from_name("not_found"), None);
+        assert_eq!(Code::from_name("OK"), None);
+    }
+
+    #[test]
+    fn error_responses() {
+        let body = r#"{"error": {"code": 400, "message": "Invalid query.", "status": "FAILED_PRECONDITION",
+                       "details": [{"@type": "type.googleapis.com/google.rpc.BadRequest", "fieldViolations": []}]}}"#;
+        let err = FirestoreError::from_response(StatusCode::BadRequest, body);
+        assert_eq!(err.code, Code::FailedPrecondition);
+        assert_eq!(err.message, "Invalid query.");
+        assert_eq!(err.details, vec![json::json!({"@type": "type.googleapis.com/google.rpc.BadRequest",
+                                                  "fieldViolations": []})]);
+        assert_eq!(err.http_status, StatusCode::BadRequest);
+        assert!(err.is_failed_precondition());
+        assert_eq!(err.to_string(), "FAILED_PRECONDITION (400): Invalid query.");
+    }
+
+    #[test]
+    fn responses_without_status() {
+        let err = FirestoreError::from_response(StatusCode::NotFound, r#"{"error": {"code": 404, "message": "gone"}}"#);
+        assert_eq!(err.code, Code::NotFound);
+        assert_eq!(err.message, "gone");
+        assert!(err.details.is_empty());
+
+        let err = FirestoreError::from_response(StatusCode::Conflict, r#"{"error": {"status": "NO_SUCH_CODE"}}"#);
+        assert_eq!(err.code, Code::Aborted);
+
+        let err = FirestoreError::from_response(StatusCode::BadGateway, "<html>Bad Gateway</html>");
+        assert_eq!(err.code, Code::Internal);
+        assert_eq!(err.message, "<html>Bad Gateway</html>");
+        assert_eq!(err.http_status, StatusCode::BadGateway);
+
+        let err = FirestoreError::from_response(StatusCode::TooManyRequests, "");
+        assert_eq!(err.code, Code::ResourceExhausted);
+        assert!(err.is_unavailable());
+    }
+}
@@ -0,0 +1,204 @@
+use std::io::{BufRead, BufReader};
+use std::marker::PhantomData;
+use serde::de::{DeserializeOwned, Error as DeError};
+use hyper::status::StatusCode;
+use serde_json as json;
+use crate::cmn::*;
+use crate::status::FirestoreError;
+use crate::transport::HttpResponse;
+
+/// The elements of a JSON array, decoded one by one while the body of a streaming
+/// response is read from the connection.
+///
+/// Only the bytes of the array element currently being decoded are buffered. The
+/// stream ends after the closing bracket of the array, or after the first error.
+/// An element holding an `error` object, which the server sends if the call fails
+/// after the response started, is returned as `Error::Firestore`.
+pub struct ResponseStream<T> {
+    reader: BufReader<HttpResponse>,
+    state: StreamState,
+    _element: PhantomData<T>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StreamState {
+    /// The opening bracket of the array was not read yet.
+    Start,
+    /// Reading the elements, `.0` tells whether one was read already.
+    Elements(bool),
+    /// The array was read completely, or reading it failed.
+    Done,
+}
+
+fn decode_error(json: &[u8], msg: &str) -> Error {
+    Error::JsonDecodeError(String::from_utf8_lossy(json).into_owned(), json::Error::custom(msg))
+}
+
+impl<T: DeserializeOwned> ResponseStream<T> {
+    pub(crate) fn new(response: HttpResponse) -> ResponseStream<T> {
+        ResponseStream {
+            reader: BufReader::new(response),
+            state: StreamState::Start,
+            _element: PhantomData,
+        }
+    }
+
+    /// The status and headers of the HTTP response.
+    pub fn response(&self) -> &HttpResponse {
+        self.reader.get_ref()
+    }
+
+    fn next_byte(&mut self) -> Result<Option<u8>> {
+        let byte = match self.reader.fill_buf() {
+            Ok(buf) => buf.first().cloned(),
+            Err(err) => return Err(Error::HttpError(hyper::Error::Io(err))),
+        };
+        if byte.is_some() {
+            self.reader.consume(1);
+        }
+        Ok(byte)
+    }
+
+    fn next_token(&mut self) -> Result<Option<u8>> {
+        loop {
+            match self.next_byte()? {
+                Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') => continue,
+                byte => return Ok(byte),
+            }
+        }
+    }
+
+    /// Reads the JSON value starting with `first`, which must be an object.
+    fn read_object(&mut self, first: u8) -> Result<Vec<u8>> {
+        let mut json = vec![first];
+        if first != b'{' {
+            return Err(decode_error(&json, "expected a JSON object"))
+        }
+        let mut depth = 1;
+        let mut in_string = false;
+        let mut escaped = false;
+        while depth > 0 {
+            let byte = match self.next_byte()? {
+                Some(byte) => byte,
+                None => return Err(decode_error(&json, "unexpected end of the response")),
+            };
+            json.push(byte);
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {},
+                }
+            } else {
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => depth -= 1,
+                    _ => {},
+                }
+            }
+        }
+        Ok(json)
+    }
+
+    fn read_next(&mut self) -> Result<Option<T>> {
+        if self.state == StreamState::Start {
+            match self.next_token()? {
+                Some(b'[') => self.state = StreamState::Elements(false),
+                Some(byte) => return Err(decode_error(&[byte], "expected a JSON array")),
+                None => return Err(decode_error(&[], "unexpected end of the response")),
+            }
+        }
+        let mut byte = self.next_token()?;
+        if byte == Some(b']') {
+            self.state = StreamState::Done;
+            return Ok(None)
+        }
+        if self.state == StreamState::Elements(true) {
+            if byte != Some(b',') {
+                return Err(decode_error(&byte.into_iter().collect::<Vec<_>>(), "expected ',' or ']'"))
+            }
+            byte = self.next_token()?;
+        }
+        let json = match byte {
+            Some(byte) => self.read_object(byte)?,
+            None => return Err(decode_error(&[], "unexpected end of the response")),
+        };
+        self.state = StreamState::Elements(true);
+        let decode_error = |err| Error::JsonDecodeError(String::from_utf8_lossy(&json).into_owned(), err);
+        let value: json::Value = json::from_slice(&json).map_err(decode_error)?;
+        if let Some(error) = value.get("error") {
+            let status = error.get("code").and_then(json::Value::as_u64)
+                .map_or(self.response().status, |code| StatusCode::from_u16(code as u16));
+            return Err(Error::Firestore(FirestoreError::from_response(status, &String::from_utf8_lossy(&json))))
+        }
+        json::from_value(value).map(Some).map_err(decode_error)
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for ResponseStream<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if self.state == StreamState::Done {
+            return None
+        }
+        match self.read_next() {
+            Ok(element) => element.map(Ok),
+            Err(err) => {
+                self.state = StreamState::Done;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::status::StatusCode;
+    use serde_json as json;
+    use crate::cmn::*;
+    use crate::status::Code;
+    use crate::transport::HttpResponse;
+    use super::ResponseStream;
+
+    fn stream(body: &str) -> ResponseStream<json::Value> {
+        ResponseStream::new(HttpResponse::json(StatusCode::Ok, body))
+    }
+
+    #[test]
+    fn elements() {
+        let elements: Vec<json::Value> = stream(r#" [ {"a": "}]"}, {"b": [1, {"c": "\\\""}]} ] "#)
+            .collect::<Result<_>>().unwrap();
+        assert_eq!(elements, vec![json::json!({"a": "}]"}), json::json!({"b": [1, {"c": "\\\""}]})]);
+        assert_eq!(stream("[]").count(), 0);
+    }
+
+    #[test]
+    fn malformed_responses() {
+        for body in &["", "{}", "[{\"a\": 1} {\"b\": 2}]", "[{\"a\": 1}", "[1]"] {
+            let results: Vec<_> = stream(body).collect();
+            match results.last() {
+                Some(Err(Error::JsonDecodeError(..))) => {},
+                _ => panic!("{:?} did not fail to decode", body),
+            }
+        }
+    }
+
+    #[test]
+    fn error_elements() {
+        let mut responses = stream(r#"[{"a": 1}, {"error": {"code": 503, "message": "The service is currently unavailable.",
+                                                          "status": "UNAVAILABLE"}}, {"b": 2}]"#);
+        assert_eq!(responses.next().unwrap().unwrap(), json::json!({"a": 1}));
+        match responses.next() {
+            Some(Err(Error::Firestore(err))) => {
+                assert_eq!(err.code, Code::Unavailable);
+                assert_eq!(err.http_status, StatusCode::ServiceUnavailable);
+                assert_eq!(err.message, "The service is currently unavailable.");
+            },
+            other => panic!("unexpected element: {:?}", other.map(|r| r.map_err(|e| e.to_string()))),
+        }
+        assert!(responses.next().is_none());
+    }
+}
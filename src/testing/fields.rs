@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::mem;
+use crate::value::*;
+
+/// Splits a field path into its field names, unquoting names in backticks.
+pub(crate) fn split_path(path: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut name = String::new();
+    let mut quoted = false;
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => quoted = !quoted,
+            '\\' if quoted => name.extend(chars.next()),
+            '.' if !quoted => names.push(mem::take(&mut name)),
+            c => name.push(c),
+        }
+    }
+    names.push(name);
+    names
+}
+
+/// The value of the field at `path`, if it exists.
+pub(crate) fn get<'a>(fields: &'a HashMap<String, Value>, path: &str) -> Option<&'a Value> {
+    let names = split_path(path);
+    let (last, parents) = names.split_last()?;
+    let mut fields = fields;
+    for name in parents {
+        fields = fields.get(name)?.map_value.as_ref()?.fields.as_ref()?;
+    }
+    fields.get(last)
+}
+
+/// Sets the field at `path`, creating the maps containing it, or deletes it if
+/// `value` is `None`.
+pub(crate) fn set(fields: &mut HashMap<String, Value>, path: &str, value: Option<Value>) {
+    set_names(fields, &split_path(path), value)
+}
+
+fn set_names(fields: &mut HashMap<String, Value>, names: &[String], value: Option<Value>) {
+    let (first, rest) = match names.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    if rest.is_empty() {
+        match value {
+            Some(value) => fields.insert(first.clone(), value),
+            None => fields.remove(first),
+        };
+        return
+    }
+    if value.is_none() {
+        let map = fields.get_mut(first)
+            .and_then(|v| v.map_value.as_mut())
+            .and_then(|m| m.fields.as_mut());
+        if let Some(map) = map {
+            set_names(map, rest, None);
+        }
+        return
+    }
+    let entry = fields.entry(first.clone()).or_default();
+    if entry.map_value.is_none() {
+        *entry = Value { map_value: Some(MapValue::default()), ..Default::default() };
+    }
+    if let Some(map) = entry.map_value.as_mut().map(|m| m.fields.get_or_insert_with(HashMap::new)) {
+        set_names(map, rest, value);
+    }
+}
+
+/// Keeps only the fields at the given paths.
+pub(crate) fn project(fields: &HashMap<String, Value>, paths: &[String]) -> HashMap<String, Value> {
+    let mut projected = HashMap::new();
+    for path in paths {
+        if let Some(value) = get(fields, path) {
+            set(&mut projected, path, Some(value.clone()));
+        }
+    }
+    projected
+}
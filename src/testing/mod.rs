@@ -0,0 +1,347 @@
+//! An in-memory stand-in for the Firestore REST API, to test code using the `Firestore`
+//! hub without network access.
+//!
+//! A `MockFirestore` is a `Transport` answering the requests of a hub in memory. It can
+//! also answer the requests of a `hyper::Client` created by its `client()`, through a
+//! custom connector, so no socket is opened either way. It implements the
+//! *get*, *list*, *createDocument*, *patch*, *delete*, *runQuery*, *commit*,
+//! *beginTransaction* and *rollback* methods of `projects.databases.documents`.
+//! All other methods fail with `501 UNIMPLEMENTED`.
+//!
+//! Queries support field, unary and composite filters, orders, cursors, offset, limit
+//! and projections, but no indexes are required or checked. *list* supports `orderBy`.
+//! Reads in a transaction are not isolated from other writes, but fail if the transaction
+//! is unknown. Failures can be scripted with `fail_next()`.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate google_firestore as firestore1;
+//! # use firestore1::{Document, Result};
+//! # use firestore1::testing::{MockFirestore, Failure};
+//! # fn egal() -> Result<()> {
+//! let server = MockFirestore::new();
+//! let hub = server.hub("my-project")?;
+//!
+//! let (_, alice) = hub.collection("users")?.add(Document::default()).doit()?;
+//! assert!(server.document(alice.name.as_ref().unwrap()).is_some());
+//!
+//! server.fail_next(Failure::status(503, "UNAVAILABLE", "try again").method("get"));
+//! assert!(hub.projects().databases_documents_get(alice.name.unwrap()).doit().is_err());
+//! # Ok(())
+//! # }
+//! # fn main() { egal().unwrap() }
+//! ```
+use std::io::{self, Cursor, Read, Write};
+use std::net::{Shutdown, SocketAddr};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use crate::cmn::*;
+use crate::document::*;
+use crate::emulator::*;
+use crate::firestore::*;
+use crate::transport::*;
+
+mod fields;
+mod query;
+mod server;
+
+use self::server::{Reply, State};
+
+/// The emulator host of the hubs created by `MockFirestore::hub()`, which is never resolved.
+const MOCK_HOST: &str = "mock.firestore.local:8080";
+
+/// A failure of a request, scripted with `MockFirestore::fail_next()`.
+#[derive(Clone, Debug)]
+pub struct Failure {
+    method: Option<String>,
+    /// The HTTP status code, status and message, or `None` to reset the connection.
+    status: Option<(u16, String, String)>,
+}
+
+impl Failure {
+
+    /// Answers with an error response, e.g. `Failure::status(409, "ABORTED", "Too much contention")`.
+    pub fn status(code: u16, status: &str, message: &str) -> Failure {
+        Failure {
+            method: None,
+            status: Some((code, status.to_string(), message.to_string())),
+        }
+    }
+
+    /// Resets the connection before a response is sent, which fails the call with
+    /// `Error::HttpError`.
+    pub fn connection_reset() -> Failure {
+        Failure {
+            method: None,
+            status: None,
+        }
+    }
+
+    /// Only fails a request of the given method, e.g. `get`, `list`, `createDocument`,
+    /// `patch`, `delete`, `runQuery`, `commit`, `beginTransaction` or `rollback`.
+    /// By default the next request of any method fails.
+    pub fn method(mut self, method: &str) -> Failure {
+        self.method = Some(method.to_string());
+        self
+    }
+}
+
+/// An in-memory Firestore server, see the [module documentation](index.html).
+///
+/// Clones share the same documents, so a clone can be kept to inspect the data
+/// written by the code under test.
+#[derive(Clone, Default)]
+pub struct MockFirestore {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockFirestore {
+
+    /// A server without any documents.
+    pub fn new() -> MockFirestore {
+        MockFirestore::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// A connector sending all requests to this server, whatever their url.
+    pub fn connector(&self) -> MockConnector {
+        MockConnector { state: self.state.clone() }
+    }
+
+    /// A client sending all requests to this server.
+    pub fn client(&self) -> hyper::Client {
+        hyper::Client::with_connector(self.connector())
+    }
+
+    /// A hub using this server as its transport, whose default database is `(default)`
+    /// in the given project.
+    pub fn hub(&self, project_id: &str) -> Result<Firestore<MockFirestore, EmulatorAuth>> {
+        FirestoreBuilder::new()
+            .project_id(project_id)
+            .emulator_host(MOCK_HOST)
+            .build_emulator(self.clone())
+    }
+
+    /// Stores `document` under its name, replacing any document of the same name.
+    ///
+    /// # Panics
+    ///
+    /// If the document has no name.
+    pub fn insert(&self, mut document: Document) {
+        let name = document.name.clone().expect("a document to insert must have a name");
+        let mut state = self.state();
+        let now = state.now();
+        document.create_time.get_or_insert_with(|| now.clone());
+        document.update_time.get_or_insert(now);
+        state.documents.insert(name, document);
+    }
+
+    /// The document with the given name, if it exists.
+    pub fn document(&self, name: &str) -> Option<Document> {
+        self.state().documents.get(name).cloned()
+    }
+
+    /// All documents, ordered by name.
+    pub fn documents(&self) -> Vec<Document> {
+        self.state().documents.values().cloned().collect()
+    }
+
+    /// Deletes all documents.
+    pub fn clear(&self) {
+        self.state().documents.clear()
+    }
+
+    /// Fails the next request matching `failure`. Failures are used in the order they
+    /// were added.
+    pub fn fail_next(&self, failure: Failure) {
+        self.state().failures.push_back(failure)
+    }
+
+    /// The requests received so far, as the method followed by the resource name,
+    /// e.g. `get projects/p/databases/(default)/documents/users/alice`.
+    pub fn requests(&self) -> Vec<String> {
+        self.state().requests.clone()
+    }
+}
+
+impl Transport for MockFirestore {
+    fn send(&mut self, request: HttpRequest) -> hyper::Result<HttpResponse> {
+        let target = match request.url.query() {
+            Some(query) => format!("{}?{}", request.url.path(), query),
+            None => request.url.path().to_string(),
+        };
+        match self.state().handle(request.method.as_ref(), &target, &request.body) {
+            Reply::Response(code, body) => Ok(HttpResponse::json(hyper::status::StatusCode::from_u16(code), body)),
+            Reply::Disconnect => Err(hyper::Error::Io(io::Error::new(io::ErrorKind::ConnectionReset,
+                                                                     "connection reset by the mock server"))),
+        }
+    }
+}
+
+/// Connects a `hyper::Client` to a `MockFirestore`, see `MockFirestore::connector()`.
+pub struct MockConnector {
+    state: Arc<Mutex<State>>,
+}
+
+impl hyper::net::NetworkConnector for MockConnector {
+    type Stream = MockStream;
+
+    fn connect(&self, _host: &str, _port: u16, _scheme: &str) -> hyper::Result<MockStream> {
+        Ok(MockStream {
+            state: self.state.clone(),
+            request: Vec::new(),
+            response: None,
+        })
+    }
+}
+
+/// A connection to a `MockFirestore`.
+///
+/// It buffers the request, and answers it once the client starts reading.
+pub struct MockStream {
+    state: Arc<Mutex<State>>,
+    request: Vec<u8>,
+    response: Option<Cursor<Vec<u8>>>,
+}
+
+impl MockStream {
+    fn respond(&mut self) -> io::Result<Vec<u8>> {
+        let request = String::from_utf8_lossy(&self.request).to_string();
+        let head_len = request.find("\r\n\r\n").map_or(request.len(), |i| i + 4);
+        let mut lines = request[..head_len].lines();
+        let mut request_line = lines.next().unwrap_or("").split(' ');
+        let method = request_line.next().unwrap_or("");
+        let target = request_line.next().unwrap_or("/");
+        let body = &self.request[head_len.min(self.request.len())..];
+
+        let reply = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+            .handle(method, target, body);
+        match reply {
+            Reply::Response(code, body) => {
+                let reason = hyper::status::StatusCode::from_u16(code).canonical_reason().unwrap_or("");
+                Ok(format!("HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=UTF-8\r\n\
+                            Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                           code, reason, body.len(), body).into_bytes())
+            },
+            Reply::Disconnect => Err(io::Error::new(io::ErrorKind::ConnectionReset, "connection reset by the mock server")),
+        }
+    }
+}
+
+impl Read for MockStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.response.is_none() {
+            let response = self.respond()?;
+            self.response = Some(Cursor::new(response));
+        }
+        self.response.as_mut().map_or(Ok(0), |response| response.read(buf))
+    }
+}
+
+impl Write for MockStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.request.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl hyper::net::NetworkStream for MockStream {
+    fn peer_addr(&mut self) -> io::Result<SocketAddr> {
+        Ok("127.0.0.1:1337".parse().unwrap())
+    }
+
+    fn set_read_timeout(&self, _dur: Option<Duration>) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn set_write_timeout(&self, _dur: Option<Duration>) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn close(&mut self, _how: Shutdown) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::cmn::*;
+    use crate::document::*;
+    use crate::value::*;
+    use super::MockFirestore;
+
+    fn insert(server: &MockFirestore, name: &str, age: i32) {
+        let mut fields = HashMap::new();
+        fields.insert("age".to_string(), Value::from(ValueKind::from(age)));
+        server.insert(Document {
+            name: Some(format!("projects/p/databases/(default)/documents/users/{}", name)),
+            fields: Some(fields),
+            ..Default::default()
+        });
+    }
+
+    fn ids(response: ListDocumentsResponse) -> Vec<String> {
+        response.documents.unwrap_or_default().iter()
+            .map(|d| d.name.as_deref().unwrap().rsplit('/').next().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn list_order_by() {
+        let server = MockFirestore::new();
+        insert(&server, "alice", 30);
+        insert(&server, "bob", 25);
+        insert(&server, "carol", 30);
+        let hub = server.hub("p").unwrap();
+        let users = hub.collection("users").unwrap();
+
+        assert_eq!(ids(users.list().doit().unwrap().1), vec!["alice", "bob", "carol"]);
+        assert_eq!(ids(users.list().order_by("age").doit().unwrap().1), vec!["bob", "alice", "carol"]);
+        assert_eq!(ids(users.list().order_by("age desc, __name__ desc").doit().unwrap().1),
+                   vec!["carol", "alice", "bob"]);
+
+        // The implicit order on `__name__` has the direction of the last order.
+        let (_, page) = users.list().order_by("age DESC").page_size(2).doit().unwrap();
+        assert_eq!(page.next_page_token.as_deref(), Some("2"));
+        assert_eq!(ids(page), vec!["carol", "alice"]);
+
+        match users.list().order_by("age sideways").doit() {
+            Err(Error::Firestore(ref err)) => assert_eq!(err.http_status.to_u16(), 400),
+            other => panic!("unexpected result: {:?}", other.map(|r| r.1).map_err(|e| e.to_string())),
+        }
+    }
+
+    #[test]
+    fn reads_in_transactions() {
+        let server = MockFirestore::new();
+        insert(&server, "alice", 30);
+        let hub = server.hub("p").unwrap();
+
+        let age = hub.run_transaction_default(|transaction| {
+            let alice = transaction.database().to_string() + "/documents/users/alice";
+            let (_, document) = transaction.get(alice.as_str()).doit()?;
+            let (_, users) = transaction.list(transaction.database().to_string() + "/documents", "users").doit()?;
+            assert_eq!(users.documents.map(|d| d.len()), Some(1));
+            Ok(document.fields.unwrap()["age"].integer_value.clone())
+        }).unwrap();
+        assert_eq!(age.as_deref(), Some("30"));
+
+        let users = hub.collection("users").unwrap();
+        for result in [users.doc("alice").unwrap().get().transaction("unknown").doit().map(|_| ()),
+                           users.list().transaction("unknown").doit().map(|_| ())] {
+            match result {
+                Err(Error::Firestore(ref err)) => assert_eq!(err.message, "Transaction is invalid or has expired."),
+                other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+            }
+        }
+    }
+}
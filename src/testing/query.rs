@@ -0,0 +1,442 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use crate::document::*;
+use crate::query::*;
+use crate::value::*;
+use crate::value::ValueKind as V;
+use super::fields;
+use super::server::{Status, StatusResult};
+
+/// The field path referring to the name of a document.
+const DOCUMENT_NAME_FIELD: &str = "__name__";
+
+pub(crate) fn kind(value: &Value) -> ValueKind {
+    ValueKind::try_from(value.clone()).unwrap_or(V::Null)
+}
+
+/// The value of the field at `path`, where `__name__` is the name of the document.
+fn field(document: &Document, path: &str) -> Option<ValueKind> {
+    if path == DOCUMENT_NAME_FIELD {
+        return document.name.clone().map(V::Reference)
+    }
+    document.fields.as_ref().and_then(|f| fields::get(f, path)).map(kind)
+}
+
+/// The position of the type of `value` in the order of values of different types.
+fn rank(value: &ValueKind) -> u8 {
+    match *value {
+        V::Null => 0,
+        V::Boolean(_) => 1,
+        V::Integer(_) | V::Double(_) => 2,
+        V::Timestamp(_) => 3,
+        V::String(_) => 4,
+        V::Bytes(_) => 5,
+        V::Reference(_) => 6,
+        V::GeoPoint(_) => 7,
+        V::Array(_) => 8,
+        V::Map(_) => 9,
+    }
+}
+
+/// Orders `NaN` before all other numbers.
+fn compare_f64(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()).reverse())
+}
+
+/// Splits a RFC 3339 timestamp in UTC into its seconds and its nanoseconds padded to
+/// nine digits, so timestamps of different precision compare correctly.
+fn timestamp_key(timestamp: &str) -> (&str, String) {
+    let timestamp = timestamp.trim_end_matches('Z');
+    match timestamp.find('.') {
+        Some(dot) => (&timestamp[..dot], format!("{:0<9}", &timestamp[dot + 1..])),
+        None => (timestamp, "0".repeat(9)),
+    }
+}
+
+/// The first ordering which is not equal, or else the ordering of the lengths.
+fn compare_all<I: Iterator<Item = Ordering>>(mut orderings: I, a: usize, b: usize) -> Ordering {
+    orderings.find(|o| *o != Ordering::Equal).unwrap_or_else(|| a.cmp(&b))
+}
+
+/// Compares values the way Firestore orders them, first by type and then by value.
+pub(crate) fn compare(a: &ValueKind, b: &ValueKind) -> Ordering {
+    match (a, b) {
+        (V::Boolean(a), V::Boolean(b)) => a.cmp(b),
+        (V::Integer(a), V::Integer(b)) => a.cmp(b),
+        (V::Integer(a), V::Double(b)) => compare_f64(*a as f64, *b),
+        (V::Double(a), V::Integer(b)) => compare_f64(*a, *b as f64),
+        (V::Double(a), V::Double(b)) => compare_f64(*a, *b),
+        (V::Timestamp(a), V::Timestamp(b)) => timestamp_key(a).cmp(&timestamp_key(b)),
+        (V::String(a), V::String(b)) => a.cmp(b),
+        (V::Bytes(a), V::Bytes(b)) => a.cmp(b),
+        (V::Reference(a), V::Reference(b)) => a.split('/').cmp(b.split('/')),
+        (V::GeoPoint(a), V::GeoPoint(b)) =>
+            compare_f64(a.latitude.unwrap_or(0.0), b.latitude.unwrap_or(0.0))
+                .then_with(|| compare_f64(a.longitude.unwrap_or(0.0), b.longitude.unwrap_or(0.0))),
+        (V::Array(a), V::Array(b)) =>
+            compare_all(a.iter().zip(b).map(|(a, b)| compare(a, b)), a.len(), b.len()),
+        (V::Map(a), V::Map(b)) => {
+            let mut a: Vec<_> = a.iter().collect();
+            let mut b: Vec<_> = b.iter().collect();
+            a.sort_by(|x, y| x.0.cmp(y.0));
+            b.sort_by(|x, y| x.0.cmp(y.0));
+            compare_all(a.iter().zip(&b).map(|((ka, va), (kb, vb))| ka.cmp(kb).then_with(|| compare(va, vb))),
+                        a.len(), b.len())
+        },
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+fn equal(a: &ValueKind, b: &ValueKind) -> bool {
+    compare(a, b) == Ordering::Equal
+}
+
+fn is_nan(value: &ValueKind) -> bool {
+    matches!(*value, V::Double(d) if d.is_nan())
+}
+
+fn elements(value: &ValueKind) -> &[ValueKind] {
+    match *value {
+        V::Array(ref values) => values,
+        _ => &[],
+    }
+}
+
+fn field_path(field: &Option<FieldReference>) -> &str {
+    field.as_ref().and_then(|f| f.field_path.as_deref()).unwrap_or("")
+}
+
+/// Whether `document` matches `filter`.
+fn matches(filter: &Filter, document: &Document) -> StatusResult<bool> {
+    if let Some(ref composite) = filter.composite_filter {
+        let filters = composite.filters.as_deref().unwrap_or(&[]);
+        let mut results = filters.iter().map(|f| matches(f, document));
+        return match composite.op.as_deref() {
+            Some("AND") => results.try_fold(true, |all, matched| matched.map(|m| all && m)),
+            Some("OR") => results.try_fold(false, |any, matched| matched.map(|m| any || m)),
+            op => Err(Status::invalid_argument(format!("unsupported composite filter operator {:?}", op))),
+        }
+    }
+    if let Some(ref filter) = filter.field_filter {
+        let value = filter.value.as_ref().map(kind).unwrap_or(V::Null);
+        let field = match field(document, field_path(&filter.field)) {
+            Some(field) => field,
+            None => return Ok(false),
+        };
+        let ordered = rank(&field) == rank(&value);
+        return Ok(match filter.op.as_deref().unwrap_or("") {
+            "EQUAL" => equal(&field, &value),
+            "NOT_EQUAL" => field != V::Null && !equal(&field, &value),
+            "LESS_THAN" => ordered && compare(&field, &value) == Ordering::Less,
+            "LESS_THAN_OR_EQUAL" => ordered && compare(&field, &value) != Ordering::Greater,
+            "GREATER_THAN" => ordered && compare(&field, &value) == Ordering::Greater,
+            "GREATER_THAN_OR_EQUAL" => ordered && compare(&field, &value) != Ordering::Less,
+            "ARRAY_CONTAINS" => elements(&field).iter().any(|e| equal(e, &value)),
+            "IN" => elements(&value).iter().any(|v| equal(&field, v)),
+            "NOT_IN" => field != V::Null && !elements(&value).iter().any(|v| equal(&field, v)),
+            "ARRAY_CONTAINS_ANY" => elements(&field).iter().any(|e| elements(&value).iter().any(|v| equal(e, v))),
+            op => return Err(Status::invalid_argument(format!("unsupported field filter operator '{}'", op))),
+        })
+    }
+    if let Some(ref filter) = filter.unary_filter {
+        let field = match field(document, field_path(&filter.field)) {
+            Some(field) => field,
+            None => return Ok(false),
+        };
+        return Ok(match filter.op.as_deref().unwrap_or("") {
+            "IS_NAN" => is_nan(&field),
+            "IS_NULL" => field == V::Null,
+            "IS_NOT_NAN" => !is_nan(&field),
+            "IS_NOT_NULL" => field != V::Null,
+            op => return Err(Status::invalid_argument(format!("unsupported unary filter operator '{}'", op))),
+        })
+    }
+    Ok(true)
+}
+
+/// The field of the first inequality filter, which is implicitly ordered by.
+fn inequality_field(filter: &Filter) -> Option<&str> {
+    if let Some(ref composite) = filter.composite_filter {
+        return composite.filters.as_deref().unwrap_or(&[]).iter().filter_map(inequality_field).next()
+    }
+    if let Some(ref filter) = filter.field_filter {
+        if let Some("LESS_THAN" | "LESS_THAN_OR_EQUAL" | "GREATER_THAN" | "GREATER_THAN_OR_EQUAL" |
+                    "NOT_EQUAL" | "NOT_IN") = filter.op.as_deref() {
+            return Some(field_path(&filter.field))
+        }
+    }
+    if let Some(ref filter) = filter.unary_filter {
+        if let Some("IS_NOT_NAN" | "IS_NOT_NULL") = filter.op.as_deref() {
+            return Some(field_path(&filter.field))
+        }
+    }
+    None
+}
+
+/// The field paths the query is ordered by and whether they are descending, including
+/// the implicit orders on the inequality field and on `__name__`.
+fn orders(query: &StructuredQuery) -> Vec<(String, bool)> {
+    let mut orders: Vec<(String, bool)> = query.order_by.as_deref().unwrap_or(&[]).iter()
+        .map(|o| (field_path(&o.field).to_string(), o.direction.as_deref() == Some("DESCENDING")))
+        .collect();
+    if orders.is_empty() {
+        if let Some(field) = query.where_.as_ref().and_then(inequality_field) {
+            orders.push((field.to_string(), false));
+        }
+    }
+    if !orders.iter().any(|o| o.0 == DOCUMENT_NAME_FIELD) {
+        let descending = orders.last().is_some_and(|o| o.1);
+        orders.push((DOCUMENT_NAME_FIELD.to_string(), descending));
+    }
+    orders
+}
+
+fn compare_keys(a: &[ValueKind], b: &[ValueKind], orders: &[(String, bool)]) -> Ordering {
+    let orderings = a.iter().zip(b).zip(orders).map(|((a, b), &(_, descending))| {
+        let ordering = compare(a, b);
+        if descending { ordering.reverse() } else { ordering }
+    });
+    compare_all(orderings, 0, 0)
+}
+
+/// Whether `name` is the name of a document in the collection `collection_id` of
+/// `parent`, or of any of its descendants if `all_descendants` is set.
+fn in_collection(name: &str, parent: &str, collection_id: &str, all_descendants: bool) -> bool {
+    let rest = match name.strip_prefix(parent).and_then(|rest| rest.strip_prefix('/')) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let segments: Vec<&str> = rest.split('/').collect();
+    if all_descendants {
+        segments.len() >= 2 && segments[segments.len() - 2] == collection_id
+    } else {
+        segments.len() == 2 && segments[0] == collection_id
+    }
+}
+
+/// Runs `query` below `parent` against `documents`, returning the matching documents
+/// and the number of documents skipped due to the offset.
+pub(crate) fn run<'a, I>(query: &StructuredQuery, parent: &str, documents: I) -> StatusResult<(Vec<Document>, i32)>
+    where I: Iterator<Item = &'a Document> {
+    query.validate().map_err(|err| Status::invalid_argument(err.to_string()))?;
+    let selector = match query.from.as_deref() {
+        Some([selector]) => selector,
+        _ => return Err(Status::invalid_argument("a query must select exactly one collection".to_string())),
+    };
+    let collection_id = selector.collection_id.as_deref().unwrap_or("");
+    let all_descendants = selector.all_descendants.unwrap_or(false);
+    let orders = orders(query);
+
+    let mut results = Vec::new();
+    for document in documents {
+        if !in_collection(document.name.as_deref().unwrap_or(""), parent, collection_id, all_descendants) {
+            continue
+        }
+        if let Some(ref filter) = query.where_ {
+            if !matches(filter, document)? {
+                continue
+            }
+        }
+        // Documents without a field the query is ordered by are never returned.
+        let key: Option<Vec<ValueKind>> = orders.iter().map(|o| field(document, &o.0)).collect();
+        if let Some(key) = key {
+            results.push((key, document));
+        }
+    }
+    results.sort_by(|a, b| compare_keys(&a.0, &b.0, &orders));
+
+    for &(cursor, is_start) in &[(&query.start_at, true), (&query.end_at, false)] {
+        if let Some(ref cursor) = *cursor {
+            let values: Vec<ValueKind> = cursor.values.as_deref().unwrap_or(&[]).iter().map(kind).collect();
+            let before = cursor.before.unwrap_or(false);
+            results.retain(|(key, _)| {
+                let ordering = compare_keys(key, &values, &orders);
+                match (is_start, before) {
+                    (true, true) => ordering != Ordering::Less,
+                    (true, false) => ordering == Ordering::Greater,
+                    (false, true) => ordering == Ordering::Less,
+                    (false, false) => ordering != Ordering::Greater,
+                }
+            });
+        }
+    }
+
+    let offset = query.offset.unwrap_or(0) as usize;
+    let skipped = offset.min(results.len());
+    let limit = query.limit.map_or(usize::MAX, |l| l as usize);
+    let paths: Option<Vec<String>> = query.select.as_ref().and_then(|s| s.fields.as_ref()).map(|fields| {
+        fields.iter().map(|f| f.field_path.clone().unwrap_or_default())
+            .filter(|path| path != DOCUMENT_NAME_FIELD)
+            .collect()
+    });
+    let documents = results.into_iter().skip(offset).take(limit).map(|(_, document)| {
+        let mut document = document.clone();
+        if let Some(ref paths) = paths {
+            document.fields = Some(fields::project(&document.fields.unwrap_or_default(), paths));
+        }
+        document
+    }).collect();
+    Ok((documents, skipped as i32))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::document::*;
+    use crate::query::*;
+    use crate::value::*;
+    use super::run;
+
+    const PARENT: &str = "projects/p/databases/d/documents";
+
+    fn document(path: &str, fields: Vec<(&str, ValueKind)>) -> Document {
+        Document {
+            name: Some(format!("{}/{}", PARENT, path)),
+            fields: Some(fields.into_iter().map(|(k, v)| (k.to_string(), Value::from(v))).collect::<HashMap<_, _>>()),
+            ..Default::default()
+        }
+    }
+
+    fn documents() -> Vec<Document> {
+        vec![
+            document("users/alice", vec![("age", 30.into()), ("city", "Paris".into()), ("tags", vec!["a", "b"].into())]),
+            document("users/bob", vec![("age", 25.into()), ("city", "Berlin".into()), ("tags", vec!["b"].into())]),
+            document("users/carol", vec![("age", 35.into()), ("city", "Paris".into())]),
+            document("users/dave", vec![("city", "Rome".into())]),
+            document("users/erin", vec![("age", 27.5.into()), ("city", "Berlin".into())]),
+            document("users/frank", vec![("age", ValueKind::Null), ("city", "Rome".into())]),
+            document("users/alice/users/grace", vec![("age", 40.into())]),
+            document("posts/first", vec![("age", 1.into())]),
+        ]
+    }
+
+    /// The ids of the documents `query` returns, and the amount of skipped documents.
+    fn ids(query: Query) -> (Vec<String>, i32) {
+        let documents = documents();
+        let (results, skipped) = run(&query.into_structured_query(), PARENT, documents.iter())
+            .unwrap_or_else(|_| panic!("the query failed"));
+        let ids = results.iter().map(|d| d.name.as_deref().unwrap().rsplit('/').next().unwrap().to_string()).collect();
+        (ids, skipped)
+    }
+
+    fn users() -> Query {
+        Query::collection("users")
+    }
+
+    #[test]
+    fn collections() {
+        assert_eq!(ids(users()).0, vec!["alice", "bob", "carol", "dave", "erin", "frank"]);
+        assert_eq!(ids(Query::collection_group("users").where_field("age", Op::GreaterThan, 35)).0, vec!["grace"]);
+        assert_eq!(ids(Query::collection("posts")).0, vec!["first"]);
+    }
+
+    #[test]
+    fn field_filters() {
+        assert_eq!(ids(users().where_field("city", Op::Equal, "Paris")).0, vec!["alice", "carol"]);
+        // Inequalities only match values of the same type, and order by their field.
+        assert_eq!(ids(users().where_field("age", Op::LessThan, 30)).0, vec!["bob", "erin"]);
+        assert_eq!(ids(users().where_field("age", Op::GreaterThanOrEqual, 30)).0, vec!["alice", "carol"]);
+        assert_eq!(ids(users().where_field("age", Op::NotEqual, 30)).0, vec!["bob", "erin", "carol"]);
+        assert_eq!(ids(users().where_field("tags", Op::ArrayContains, "b")).0, vec!["alice", "bob"]);
+        assert_eq!(ids(users().where_field("tags", Op::ArrayContainsAny, vec!["a", "c"])).0, vec!["alice"]);
+        assert_eq!(ids(users().where_field("city", Op::In, vec!["Rome", "Berlin"])).0, vec!["bob", "dave", "erin", "frank"]);
+        assert_eq!(ids(users().where_field("city", Op::NotIn, vec!["Rome", "Berlin"])).0, vec!["alice", "carol"]);
+        assert_eq!(ids(users().where_field("age", Op::Equal, 27.5)).0, vec!["erin"]);
+        assert_eq!(ids(users().where_field("age", Op::Equal, 30.0)).0, vec!["alice"]);
+    }
+
+    #[test]
+    fn unary_and_composite_filters() {
+        assert_eq!(ids(users().where_unary("age", UnaryOp::IsNull)).0, vec!["frank"]);
+        assert_eq!(ids(users().where_unary("age", UnaryOp::IsNotNull)).0, vec!["bob", "erin", "alice", "carol"]);
+        assert_eq!(ids(users().where_field("city", Op::Equal, "Berlin").where_field("age", Op::GreaterThan, 26)).0,
+                   vec!["erin"]);
+
+        let mut query = users().into_structured_query();
+        let filter = |field: &str, value: &str| Filter {
+            field_filter: Some(FieldFilter {
+                field: Some(FieldReference { field_path: Some(field.to_string()) }),
+                op: Some("EQUAL".to_string()),
+                value: Some(Value::from(ValueKind::from(value))),
+            }),
+            ..Default::default()
+        };
+        query.where_ = Some(Filter {
+            composite_filter: Some(CompositeFilter {
+                op: Some("OR".to_string()),
+                filters: Some(vec![filter("city", "Rome"), filter("tags", "x"), filter("city", "Berlin")]),
+            }),
+            ..Default::default()
+        });
+        let documents = documents();
+        let (results, _) = run(&query, PARENT, documents.iter()).ok().unwrap();
+        let names: Vec<_> = results.iter().map(|d| d.name.as_deref().unwrap().rsplit('/').next().unwrap()).collect();
+        assert_eq!(names, vec!["bob", "dave", "erin", "frank"]);
+    }
+
+    #[test]
+    fn orders() {
+        // Documents without the ordered field are left out, ties are ordered by name.
+        assert_eq!(ids(users().order_by("age", Direction::Ascending)).0,
+                   vec!["frank", "bob", "erin", "alice", "carol"]);
+        assert_eq!(ids(users().order_by("age", Direction::Descending)).0,
+                   vec!["carol", "alice", "erin", "bob", "frank"]);
+        assert_eq!(ids(users().order_by("city", Direction::Descending).order_by("age", Direction::Ascending)).0,
+                   vec!["frank", "alice", "carol", "bob", "erin"]);
+        assert_eq!(ids(users().order_by("city", Direction::Descending)).0,
+                   vec!["frank", "dave", "carol", "alice", "erin", "bob"]);
+        assert_eq!(ids(users().order_by("__name__", Direction::Descending)).0,
+                   vec!["frank", "erin", "dave", "carol", "bob", "alice"]);
+    }
+
+    #[test]
+    fn cursors() {
+        let by_age = || users().where_field("age", Op::GreaterThan, 0);
+        assert_eq!(ids(by_age().start_at(vec![27.5])).0, vec!["erin", "alice", "carol"]);
+        assert_eq!(ids(by_age().start_after(vec![27.5])).0, vec!["alice", "carol"]);
+        assert_eq!(ids(by_age().end_at(vec![30])).0, vec!["bob", "erin", "alice"]);
+        assert_eq!(ids(by_age().end_before(vec![30])).0, vec!["bob", "erin"]);
+        assert_eq!(ids(by_age().start_after(vec![25]).end_before(vec![35])).0, vec!["erin", "alice"]);
+
+        // A cursor on all orders, including `__name__`, is a position between documents.
+        let by_city = || users().order_by("city", Direction::Ascending);
+        let carol = format!("{}/users/carol", PARENT);
+        assert_eq!(ids(by_city().start_after(vec![ValueKind::from("Paris"), ValueKind::Reference(carol.clone())])).0,
+                   vec!["dave", "frank"]);
+        assert_eq!(ids(by_city().end_before(vec![ValueKind::from("Paris"), ValueKind::Reference(carol)])).0,
+                   vec!["bob", "erin", "alice"]);
+    }
+
+    #[test]
+    fn offset_and_limit() {
+        let by_name = || users().order_by("__name__", Direction::Ascending);
+        assert_eq!(ids(by_name().limit(2)), (vec!["alice".to_string(), "bob".to_string()], 0));
+        assert_eq!(ids(by_name().offset(4)), (vec!["erin".to_string(), "frank".to_string()], 4));
+        assert_eq!(ids(by_name().offset(2).limit(1)), (vec!["carol".to_string()], 2));
+        assert_eq!(ids(by_name().offset(10)), (Vec::new(), 6));
+        assert_eq!(ids(by_name().start_after(vec![ValueKind::Reference(format!("{}/users/bob", PARENT))])
+                       .offset(1).limit(2)).0, vec!["dave", "erin"]);
+    }
+
+    #[test]
+    fn projections() {
+        let documents = documents();
+        let query = users().where_field("city", Op::Equal, "Paris").select(&["age", "__name__"]).into_structured_query();
+        let (results, _) = run(&query, PARENT, documents.iter()).ok().unwrap();
+        assert_eq!(results.len(), 2);
+        for result in results {
+            let fields = result.fields.unwrap();
+            assert_eq!(fields.keys().collect::<Vec<_>>(), vec!["age"]);
+        }
+    }
+
+    #[test]
+    fn invalid_queries() {
+        let documents = documents();
+        let invalid = users().where_field("age", Op::GreaterThan, 1).where_field("city", Op::LessThan, "x");
+        assert!(run(&invalid.into_structured_query(), PARENT, documents.iter()).is_err());
+        let mut two_collections = users().into_structured_query();
+        two_collections.from.as_mut().unwrap().push(Default::default());
+        assert!(run(&two_collections, PARENT, documents.iter()).is_err());
+    }
+}
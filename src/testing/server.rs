@@ -0,0 +1,542 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json as json;
+use url::form_urlencoded;
+use url::percent_encoding::percent_decode;
+use crate::cmn::remove_json_null_values;
+use crate::collection::*;
+use crate::document::*;
+use crate::path::*;
+use crate::document::ListDocumentsResponse;
+use crate::query::*;
+use crate::value::*;
+use crate::value::ValueKind as V;
+use crate::write::*;
+use super::{fields, query, Failure};
+
+/// An error status as returned by the Firestore REST API.
+pub(crate) struct Status {
+    code: u16,
+    status: String,
+    message: String,
+}
+
+impl Status {
+    pub(crate) fn new(code: u16, status: &str, message: String) -> Status {
+        Status { code, status: status.to_string(), message }
+    }
+
+    pub(crate) fn invalid_argument(message: String) -> Status {
+        Status::new(400, "INVALID_ARGUMENT", message)
+    }
+
+    fn not_found(message: String) -> Status {
+        Status::new(404, "NOT_FOUND", message)
+    }
+
+    fn already_exists(message: String) -> Status {
+        Status::new(409, "ALREADY_EXISTS", message)
+    }
+
+    fn failed_precondition(message: String) -> Status {
+        Status::new(400, "FAILED_PRECONDITION", message)
+    }
+
+    fn unimplemented(message: String) -> Status {
+        Status::new(501, "UNIMPLEMENTED", message)
+    }
+
+    /// The JSON body of the error response.
+    fn to_json(&self) -> json::Value {
+        json::json!({
+            "error": {
+                "code": self.code,
+                "message": self.message,
+                "status": self.status,
+                "errors": [],
+            }
+        })
+    }
+}
+
+/// What the server answers to a request.
+pub(crate) enum Reply {
+    Response(u16, String),
+    /// The connection is reset before a response is sent.
+    Disconnect,
+}
+
+/// The result of handling a request, or of a step of it.
+pub(crate) type StatusResult<T> = std::result::Result<T, Status>;
+
+fn to_json<T: Serialize>(value: &T) -> json::Value {
+    let mut value = json::to_value(value).expect("serializable response");
+    remove_json_null_values(&mut value);
+    value
+}
+
+fn from_body<T: DeserializeOwned>(body: &[u8]) -> StatusResult<T> {
+    json::from_slice(body).map_err(|err| Status::invalid_argument(format!("invalid JSON payload: {}", err)))
+}
+
+/// Formats a time as a RFC 3339 timestamp in UTC with microsecond precision.
+fn format_timestamp(time: Duration) -> String {
+    let secs = time.as_secs();
+    let (days, rem) = ((secs / 86_400) as i64, secs % 86_400);
+    // The civil date of a day since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
+            year, month, day, rem / 3600, rem / 60 % 60, rem % 60, time.subsec_micros())
+}
+
+fn precondition_error(name: &str, existing: Option<&Document>, exists: Option<bool>,
+                      update_time: Option<&str>) -> Option<Status> {
+    match (existing, exists) {
+        (None, Some(true)) => return Some(Status::not_found(format!("No document to update: {}", name))),
+        (Some(_), Some(false)) => return Some(Status::already_exists(format!("Document already exists: {}", name))),
+        _ => {},
+    }
+    match (existing, update_time) {
+        (None, Some(_)) => Some(Status::not_found(format!("No document to update: {}", name))),
+        (Some(document), Some(update_time)) if document.update_time.as_deref() != Some(update_time) =>
+            Some(Status::failed_precondition(format!("The document {} was updated at another time", name))),
+        _ => None,
+    }
+}
+
+fn check_precondition(documents: &BTreeMap<String, Document>, name: &str,
+                      precondition: Option<&Precondition>) -> StatusResult<()> {
+    let (exists, update_time) = precondition.map_or((None, None), |p| (p.exists, p.update_time.as_deref()));
+    match precondition_error(name, documents.get(name), exists, update_time) {
+        Some(status) => Err(status),
+        None => Ok(()),
+    }
+}
+
+/// Writes the fields of `update` to the document `name`, either all of them or only
+/// the ones in `mask`, and returns the stored document.
+fn update(documents: &mut BTreeMap<String, Document>, name: &str, update: Document,
+          mask: Option<&[String]>, now: &str) -> Document {
+    let existing = documents.remove(name);
+    let create_time = existing.as_ref().and_then(|d| d.create_time.clone());
+    let fields = match mask {
+        Some(paths) => {
+            let mut fields_ = existing.and_then(|d| d.fields).unwrap_or_default();
+            let update_fields = update.fields.unwrap_or_default();
+            for path in paths {
+                fields::set(&mut fields_, path, fields::get(&update_fields, path).cloned());
+            }
+            fields_
+        },
+        None => update.fields.unwrap_or_default(),
+    };
+    let document = Document {
+        name: Some(name.to_string()),
+        fields: Some(fields),
+        create_time: Some(create_time.unwrap_or_else(|| now.to_string())),
+        update_time: Some(now.to_string()),
+    };
+    documents.insert(name.to_string(), document.clone());
+    document
+}
+
+fn number(value: &ValueKind) -> Option<f64> {
+    match *value {
+        V::Integer(i) => Some(i as f64),
+        V::Double(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// The value of a field after applying `transform` to its current value.
+fn transform_value(current: Option<ValueKind>, transform: &FieldTransform, now: &str) -> StatusResult<ValueKind> {
+    let operand = |value: &Option<Value>| value.as_ref().map(query::kind);
+    let elements = |array: &Option<ArrayValue>| -> Vec<ValueKind> {
+        array.as_ref().and_then(|a| a.values.as_ref()).map_or_else(Vec::new, |v| v.iter().map(query::kind).collect())
+    };
+    let current_elements = match current {
+        Some(V::Array(ref values)) => values.clone(),
+        _ => Vec::new(),
+    };
+    if let Some(ref server_value) = transform.set_to_server_value {
+        return match server_value.as_str() {
+            "REQUEST_TIME" => Ok(V::Timestamp(now.to_string())),
+            _ => Err(Status::invalid_argument(format!("unsupported server value '{}'", server_value))),
+        }
+    }
+    if let Some(increment) = operand(&transform.increment) {
+        return Ok(match (current, increment) {
+            (Some(V::Integer(a)), V::Integer(b)) => V::Integer(a.saturating_add(b)),
+            (Some(ref a), ref b) if number(a).is_some() && number(b).is_some() =>
+                V::Double(number(a).unwrap_or(0.0) + number(b).unwrap_or(0.0)),
+            (_, increment) => increment,
+        })
+    }
+    for &(value, replaced_when) in &[(&transform.maximum, Ordering::Less), (&transform.minimum, Ordering::Greater)] {
+        if let Some(value) = operand(value) {
+            return Ok(match current {
+                Some(current) if number(&current).is_some() && query::compare(&current, &value) != replaced_when => current,
+                _ => value,
+            })
+        }
+    }
+    if transform.append_missing_elements.is_some() {
+        let mut values = current_elements;
+        for element in elements(&transform.append_missing_elements) {
+            if !values.iter().any(|v| query::compare(v, &element) == Ordering::Equal) {
+                values.push(element);
+            }
+        }
+        return Ok(V::Array(values))
+    }
+    if transform.remove_all_from_array.is_some() {
+        let removed = elements(&transform.remove_all_from_array);
+        let values = current_elements.into_iter()
+            .filter(|v| !removed.iter().any(|r| query::compare(v, r) == Ordering::Equal))
+            .collect();
+        return Ok(V::Array(values))
+    }
+    Err(Status::invalid_argument(format!("the transform of '{}' has no operation",
+                                         transform.field_path.as_deref().unwrap_or(""))))
+}
+
+/// Applies the field transforms to the document `name`, creating it if it does not
+/// exist, and returns the transformed values.
+fn transform(documents: &mut BTreeMap<String, Document>, name: &str, transforms: &[FieldTransform],
+             now: &str) -> StatusResult<Vec<Value>> {
+    let mut document = documents.remove(name).unwrap_or_else(|| Document {
+        name: Some(name.to_string()),
+        create_time: Some(now.to_string()),
+        ..Default::default()
+    });
+    let mut fields_ = document.fields.take().unwrap_or_default();
+    let mut results = Vec::with_capacity(transforms.len());
+    for field_transform in transforms {
+        let path = field_transform.field_path.as_deref().unwrap_or("");
+        let current = fields::get(&fields_, path).map(query::kind);
+        let value = Value::from(transform_value(current, field_transform, now)?);
+        fields::set(&mut fields_, path, Some(value.clone()));
+        results.push(value);
+    }
+    document.fields = Some(fields_);
+    document.update_time = Some(now.to_string());
+    documents.insert(name.to_string(), document);
+    Ok(results)
+}
+
+/// The state of a mock server, see `MockFirestore`.
+#[derive(Default)]
+pub(crate) struct State {
+    pub(crate) documents: BTreeMap<String, Document>,
+    pub(crate) failures: VecDeque<Failure>,
+    pub(crate) requests: Vec<String>,
+    transactions: HashSet<String>,
+    last_time: Duration,
+    ids: u64,
+}
+
+impl State {
+
+    /// The current time, strictly increasing with every call.
+    pub(crate) fn now(&mut self) -> String {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        self.last_time = if now > self.last_time { now } else { self.last_time + Duration::from_micros(1) };
+        format_timestamp(self.last_time)
+    }
+
+    /// A random looking document or transaction id. The ids are the same for every
+    /// server, so test runs are reproducible.
+    fn next_id(&mut self) -> String {
+        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+        self.ids += 1;
+        let mut x = self.ids.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        (0..20).map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            CHARS[(x % CHARS.len() as u64) as usize] as char
+        }).collect()
+    }
+
+    /// Answers a HTTP request, given its method, its target (path and query) and its body.
+    pub(crate) fn handle(&mut self, method: &str, target: &str, body: &[u8]) -> Reply {
+        let (path, query) = match target.find('?') {
+            Some(index) => (&target[..index], &target[index + 1..]),
+            None => (target, ""),
+        };
+        let path = percent_decode(path.as_bytes()).decode_utf8_lossy().to_string();
+        let params: Vec<(String, String)> = form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+        let resource = path.trim_start_matches('/').trim_start_matches("v1/");
+        let (resource, verb) = match resource.rfind(':') {
+            Some(index) if !resource[index..].contains('/') => (&resource[..index], Some(&resource[index + 1..])),
+            _ => (resource, None),
+        };
+        let segments = resource.split('/').count();
+        let api_method = match (method, verb) {
+            (_, Some(verb)) => verb,
+            ("GET", None) if segments % 2 == 0 => "list",
+            ("POST", None) if segments % 2 == 0 => "createDocument",
+            ("GET", None) => "get",
+            ("PATCH", None) => "patch",
+            ("DELETE", None) => "delete",
+            (method, None) => method,
+        };
+        self.requests.push(format!("{} {}", api_method, resource));
+
+        let failure = self.failures.iter()
+            .position(|f| f.method.as_ref().is_none_or(|m| m == api_method))
+            .and_then(|index| self.failures.remove(index));
+        let result = match failure {
+            Some(Failure { status: None, .. }) => return Reply::Disconnect,
+            Some(Failure { status: Some((code, status, message)), .. }) => Err(Status { code, status, message }),
+            None => self.dispatch(api_method, resource, &params, body),
+        };
+        match result {
+            Ok(value) => Reply::Response(200, value.to_string()),
+            Err(status) => Reply::Response(status.code, status.to_json().to_string()),
+        }
+    }
+
+    fn dispatch(&mut self, api_method: &str, resource: &str, params: &[(String, String)], body: &[u8]) -> StatusResult<json::Value> {
+        let param = |name: &str| params.iter().find(|p| p.0 == name).map(|p| p.1.as_str());
+        let list_param = |name: &str| -> Vec<String> {
+            params.iter().filter(|p| p.0 == name).map(|p| p.1.clone()).collect()
+        };
+        let mask = list_param("mask.fieldPaths");
+        let mask = if mask.is_empty() { None } else { Some(mask) };
+        match api_method {
+            "get" => {
+                self.check_transaction(param("transaction"))?;
+                self.get(resource.parse().map_err(invalid_name)?, mask)
+            },
+            "list" => {
+                let collection: CollectionPath = resource.parse().map_err(invalid_name)?;
+                self.check_transaction(param("transaction"))?;
+                let order_by = param("orderBy").map(parse_order_by).transpose()?;
+                let page_size = param("pageSize").and_then(|s| s.parse().ok()).unwrap_or(0);
+                let offset = param("pageToken").and_then(|s| s.parse().ok()).unwrap_or(0);
+                self.list(&collection, order_by, page_size, offset, mask)
+            },
+            "createDocument" => {
+                let collection: CollectionPath = resource.parse().map_err(invalid_name)?;
+                self.create_document(&collection, param("documentId"), from_body(body)?, mask)
+            },
+            "patch" => {
+                let name: DocumentPath = resource.parse().map_err(invalid_name)?;
+                let update_mask = list_param("updateMask.fieldPaths");
+                let exists = param("currentDocument.exists").map(|e| e == "true");
+                self.patch(&name, from_body(body)?, if update_mask.is_empty() { None } else { Some(update_mask) },
+                           exists, param("currentDocument.updateTime"), mask)
+            },
+            "delete" => {
+                let name: DocumentPath = resource.parse().map_err(invalid_name)?;
+                let exists = param("currentDocument.exists").map(|e| e == "true");
+                self.delete(&name, exists, param("currentDocument.updateTime"))
+            },
+            "runQuery" => self.run_query(resource, from_body(body)?),
+            "commit" => self.commit(from_body(body)?),
+            "beginTransaction" => Ok(self.begin_transaction()),
+            "rollback" => self.rollback(from_body(body)?),
+            method => Err(Status::unimplemented(format!("The method '{}' is not implemented by the mock server", method))),
+        }
+    }
+
+    fn get(&mut self, name: DocumentPath, mask: Option<Vec<String>>) -> StatusResult<json::Value> {
+        let name = name.to_string();
+        match self.documents.get(&name) {
+            Some(document) => Ok(to_json(&masked(document, mask.as_deref()))),
+            None => Err(Status::not_found(format!("Document \"{}\" not found.", name))),
+        }
+    }
+
+    /// Lists the documents of `collection` like a query on it ordered by `order_by`, so
+    /// documents without a field it is ordered by are left out.
+    fn list(&mut self, collection: &CollectionPath, order_by: Option<Vec<Order>>, page_size: usize, offset: usize,
+            mask: Option<Vec<String>>) -> StatusResult<json::Value> {
+        let query = StructuredQuery {
+            from: Some(vec![CollectionSelector {
+                collection_id: Some(collection.id().to_string()),
+                all_descendants: Some(false),
+            }]),
+            order_by,
+            ..Default::default()
+        };
+        let (documents, _) = query::run(&query, &collection.parent_name(), self.documents.values())?;
+        let page_size = if page_size == 0 { documents.len() } else { page_size };
+        let page: Vec<Document> = documents.iter().skip(offset).take(page_size)
+            .map(|d| masked(d, mask.as_deref()))
+            .collect();
+        let end = offset + page.len();
+        Ok(to_json(&ListDocumentsResponse {
+            next_page_token: if end < documents.len() { Some(end.to_string()) } else { None },
+            documents: Some(page),
+        }))
+    }
+
+    fn create_document(&mut self, collection: &CollectionPath, document_id: Option<&str>, document: Document,
+                       mask: Option<Vec<String>>) -> StatusResult<json::Value> {
+        let id = match document_id {
+            Some(id) if !id.is_empty() => id.to_string(),
+            _ => self.next_id(),
+        };
+        let name = collection.doc(&id).map_err(invalid_name)?.to_string();
+        if self.documents.contains_key(&name) {
+            return Err(Status::already_exists(format!("Document already exists: {}", name)))
+        }
+        let now = self.now();
+        let document = update(&mut self.documents, &name, document, None, &now);
+        Ok(to_json(&masked(&document, mask.as_deref())))
+    }
+
+    fn patch(&mut self, name: &DocumentPath, document: Document, update_mask: Option<Vec<String>>,
+             exists: Option<bool>, update_time: Option<&str>, mask: Option<Vec<String>>) -> StatusResult<json::Value> {
+        let name = name.to_string();
+        if let Some(status) = precondition_error(&name, self.documents.get(&name), exists, update_time) {
+            return Err(status)
+        }
+        let now = self.now();
+        let document = update(&mut self.documents, &name, document, update_mask.as_deref(), &now);
+        Ok(to_json(&masked(&document, mask.as_deref())))
+    }
+
+    fn delete(&mut self, name: &DocumentPath, exists: Option<bool>, update_time: Option<&str>) -> StatusResult<json::Value> {
+        let name = name.to_string();
+        if let Some(status) = precondition_error(&name, self.documents.get(&name), exists, update_time) {
+            return Err(status)
+        }
+        self.documents.remove(&name);
+        Ok(json::json!({}))
+    }
+
+    fn run_query(&mut self, parent: &str, request: RunQueryRequest) -> StatusResult<json::Value> {
+        if !parent.ends_with("/documents") {
+            parent.parse::<DocumentPath>().map_err(invalid_name)?;
+        }
+        let query = match request.structured_query {
+            Some(ref query) => query,
+            None => return Err(Status::invalid_argument("a structured query is required".to_string())),
+        };
+        self.check_transaction(request.transaction.as_deref())?;
+        let (documents, skipped) = query::run(query, parent, self.documents.values())?;
+        let transaction = request.new_transaction.map(|_| self.begin());
+        let read_time = self.now();
+        let mut responses: Vec<RunQueryResponse> = documents.into_iter().map(|document| RunQueryResponse {
+            document: Some(document),
+            read_time: Some(read_time.clone()),
+            ..Default::default()
+        }).collect();
+        if responses.is_empty() {
+            responses.push(RunQueryResponse { read_time: Some(read_time), ..Default::default() });
+        }
+        responses[0].transaction = transaction;
+        if skipped > 0 {
+            responses[0].skipped_results = Some(skipped);
+        }
+        Ok(to_json(&responses))
+    }
+
+    fn commit(&mut self, request: CommitRequest) -> StatusResult<json::Value> {
+        self.check_transaction(request.transaction.as_deref())?;
+        let now = self.now();
+        // Writes are applied to a copy, so nothing is written if one of them fails.
+        let mut documents = self.documents.clone();
+        let mut write_results = Vec::new();
+        for write in request.writes.unwrap_or_default() {
+            let precondition = write.current_document.as_ref();
+            let mut result = WriteResult { update_time: Some(now.clone()), transform_results: None };
+            if let Some(document) = write.update {
+                let name: DocumentPath = document.name.as_deref().unwrap_or("").parse().map_err(invalid_name)?;
+                let name = name.to_string();
+                check_precondition(&documents, &name, precondition)?;
+                let mask = write.update_mask.and_then(|m| m.field_paths);
+                update(&mut documents, &name, document, mask.as_deref(), &now);
+            } else if let Some(name) = write.delete {
+                let name = name.parse::<DocumentPath>().map_err(invalid_name)?.to_string();
+                check_precondition(&documents, &name, precondition)?;
+                documents.remove(&name);
+            } else if let Some(document_transform) = write.transform {
+                let name: DocumentPath = document_transform.document.as_deref().unwrap_or("").parse()
+                    .map_err(invalid_name)?;
+                let name = name.to_string();
+                check_precondition(&documents, &name, precondition)?;
+                let transforms = document_transform.field_transforms.unwrap_or_default();
+                result.transform_results = Some(transform(&mut documents, &name, &transforms, &now)?);
+            } else {
+                return Err(Status::invalid_argument("a write must set an update, a delete or a transform".to_string()))
+            }
+            write_results.push(result);
+        }
+        if let Some(ref transaction) = request.transaction {
+            self.transactions.remove(transaction);
+        }
+        self.documents = documents;
+        Ok(to_json(&CommitResponse { write_results: Some(write_results), commit_time: Some(now) }))
+    }
+
+    fn begin(&mut self) -> String {
+        let transaction = base64::encode(&self.next_id());
+        self.transactions.insert(transaction.clone());
+        transaction
+    }
+
+    fn begin_transaction(&mut self) -> json::Value {
+        to_json(&BeginTransactionResponse { transaction: Some(self.begin()) })
+    }
+
+    fn rollback(&mut self, request: RollbackRequest) -> StatusResult<json::Value> {
+        self.check_transaction(request.transaction.as_deref())?;
+        if let Some(ref transaction) = request.transaction {
+            self.transactions.remove(transaction);
+        }
+        Ok(json::json!({}))
+    }
+
+    fn check_transaction(&self, transaction: Option<&str>) -> StatusResult<()> {
+        match transaction {
+            Some(transaction) if !self.transactions.contains(transaction) =>
+                Err(Status::invalid_argument("Transaction is invalid or has expired.".to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Parses the `orderBy` parameter of *list*, e.g. `age desc, name`.
+fn parse_order_by(order_by: &str) -> StatusResult<Vec<Order>> {
+    order_by.split(',').map(|order| {
+        let parts: Vec<&str> = order.split_whitespace().collect();
+        let direction = match parts[..] {
+            [_] => "ASCENDING",
+            [_, direction] if direction.eq_ignore_ascii_case("asc") => "ASCENDING",
+            [_, direction] if direction.eq_ignore_ascii_case("desc") => "DESCENDING",
+            _ => return Err(Status::invalid_argument(format!("invalid order_by '{}'", order_by))),
+        };
+        Ok(Order {
+            field: Some(FieldReference { field_path: Some(parts[0].to_string()) }),
+            direction: Some(direction.to_string()),
+        })
+    }).collect()
+}
+
+fn invalid_name(err: crate::cmn::Error) -> Status {
+    Status::invalid_argument(err.to_string())
+}
+
+/// A copy of `document` with only the fields in `mask`, if given.
+fn masked(document: &Document, mask: Option<&[String]>) -> Document {
+    let mut document = document.clone();
+    if let Some(paths) = mask {
+        document.fields = document.fields.map(|f| fields::project(&f, paths));
+    }
+    document
+}
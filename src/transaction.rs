@@ -1,4 +1,14 @@
+use std::thread::sleep;
+use std::time::Duration;
 use crate::cmn::*;
+use crate::write::*;
+#[cfg(feature = "blocking")]
+use crate::firestore::*;
+use crate::document::*;
+use crate::query::*;
+#[cfg(feature = "blocking")]
+use crate::projectmethods::*;
+use crate::transport::Transport;
 
 /// Options for creating a new transaction.
 ///
@@ -42,3 +52,344 @@ pub struct ReadOnly {
 }
 
 impl Part for ReadOnly {}
+
+/// The request for Firestore.BeginTransaction.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents begin transaction projects](struct.ProjectDatabaseDocumentBeginTransactionCall.html) (request)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct BeginTransactionRequest {
+    /// The options for the transaction.
+    /// Defaults to a read-write transaction.
+    pub options: Option<TransactionOptions>,
+}
+
+impl RequestValue for BeginTransactionRequest {}
+
+/// The response for Firestore.BeginTransaction.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents begin transaction projects](struct.ProjectDatabaseDocumentBeginTransactionCall.html) (response)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct BeginTransactionResponse {
+    /// The transaction that was started.
+    pub transaction: Option<String>,
+}
+
+impl ResponseResult for BeginTransactionResponse {}
+
+/// The request for Firestore.Commit.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents commit projects](struct.ProjectDatabaseDocumentCommitCall.html) (request)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct CommitRequest {
+    /// The writes to apply.
+    ///
+    /// Always executed atomically and in order.
+    pub writes: Option<Vec<Write>>,
+    /// If set, applies all writes in this transaction, and commits it.
+    pub transaction: Option<String>,
+}
+
+impl RequestValue for CommitRequest {}
+
+/// The response for Firestore.Commit.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents commit projects](struct.ProjectDatabaseDocumentCommitCall.html) (response)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct CommitResponse {
+    /// The result of applying the writes.
+    ///
+    /// This i-th write result corresponds to the i-th write in the
+    /// request.
+    #[serde(rename="writeResults")]
+    pub write_results: Option<Vec<WriteResult>>,
+    /// The time at which the commit occurred.
+    #[serde(rename="commitTime")]
+    pub commit_time: Option<String>,
+}
+
+impl ResponseResult for CommitResponse {}
+
+/// The request for Firestore.Rollback.
+///
+/// # Activities
+///
+/// This type is used in activities, which are methods you may call on this type or where this type is involved in.
+/// The list links the activity name, along with information about where it is used (one of *request* and *response*).
+///
+/// * [databases documents rollback projects](struct.ProjectDatabaseDocumentRollbackCall.html) (request)
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct RollbackRequest {
+    /// The transaction to roll back.
+    pub transaction: Option<String>,
+}
+
+impl RequestValue for RollbackRequest {}
+
+/// The number of times `Firestore::run_transaction` attempts a transaction
+/// before giving up on a contended document.
+pub const MAX_TRANSACTION_ATTEMPTS: usize = 5;
+
+/// The delay before the second attempt of a transaction, doubled for every further attempt.
+#[cfg(feature = "blocking")]
+const TRANSACTION_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// A read-write transaction started by `Firestore::run_transaction`.
+///
+/// Reads are routed through the transaction using the `transaction(...)` setters of
+/// the respective call builders. Writes are buffered and sent in a single commit
+/// once the transaction function returns successfully.
+#[cfg(feature = "blocking")]
+pub struct Transaction<'a, C, A>
+    where C: 'a, A: 'a {
+
+    hub: &'a Firestore<C, A>,
+    database: String,
+    id: String,
+    writes: Vec<Write>,
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, C, A> Transaction<'a, C, A>
+    where C: Transport, A: oauth2::GetToken {
+
+    /// The id of the transaction, as returned by the server.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The database name this transaction runs in. In the format:
+    /// `projects/{project_id}/databases/{database_id}`.
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+
+    /// Gets a single document within this transaction.
+    pub fn get<N: Into<String>>(&self, name: N) -> ProjectDatabaseDocumentGetCall<'a, C, A> {
+        self.hub.projects().databases_documents_get(name)
+            .transaction(&self.id)
+    }
+
+    /// Lists documents within this transaction.
+    pub fn list<P: Into<String>>(&self, parent: P, collection_id: &str) -> ProjectDatabaseDocumentListCall<'a, C, A> {
+        self.hub.projects().databases_documents_list(parent, collection_id)
+            .transaction(&self.id)
+    }
+
+    /// Runs a query within this transaction.
+    ///
+    /// Any transaction options already set on the `request` are replaced.
+    pub fn run_query<P: Into<String>>(&self, mut request: RunQueryRequest, parent: P) -> ProjectDatabaseDocumentRunQueryCall<'a, C, A> {
+        request.new_transaction = None;
+        request.read_time = None;
+        request.transaction = Some(self.id.clone());
+        self.hub.projects().databases_documents_run_query(request, parent)
+    }
+
+    /// Buffers a write to be applied when the transaction is committed.
+    pub fn write(&mut self, write: Write) -> &mut Transaction<'a, C, A> {
+        self.writes.push(write);
+        self
+    }
+
+    /// Buffers a write replacing the document named by `document.name`.
+    pub fn update(&mut self, document: Document) -> &mut Transaction<'a, C, A> {
+        self.write(Write {
+            update: Some(document),
+            ..Default::default()
+        })
+    }
+
+    /// Buffers the deletion of the document with the given resource name.
+    pub fn delete<N: Into<String>>(&mut self, name: N) -> &mut Transaction<'a, C, A> {
+        self.write(Write {
+            delete: Some(name.into()),
+            ..Default::default()
+        })
+    }
+
+    /// Buffers the given field transforms of the document with the given resource name.
+    pub fn transform<N: Into<String>>(&mut self, name: N, field_transforms: Vec<FieldTransform>) -> &mut Transaction<'a, C, A> {
+        self.write(Write {
+            transform: Some(DocumentTransform {
+                document: Some(name.into()),
+                field_transforms: Some(field_transforms),
+            }),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, C, A> Firestore<C, A>
+    where C: Transport, A: oauth2::GetToken {
+
+    /// Runs `f` in a read-write transaction and commits the writes it buffered.
+    ///
+    /// If the transaction function or the commit fails with `ABORTED` because of
+    /// contention, the transaction is rolled back and `f` is called again in a new
+    /// transaction which retries the previous one, after a delay doubling with every
+    /// attempt. At most `MAX_TRANSACTION_ATTEMPTS` attempts are made. Any other error
+    /// rolls back the transaction and is returned as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    pub fn run_transaction<T, F, D>(&'a self, database: D, mut f: F) -> Result<T>
+        where F: FnMut(&mut Transaction<'a, C, A>) -> Result<T>, D: Into<String> {
+        let database = database.into();
+        let mut retry_transaction = None;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let request = BeginTransactionRequest {
+                options: Some(TransactionOptions {
+                    read_write: Some(ReadWrite { retry_transaction: retry_transaction.take() }),
+                    read_only: None,
+                }),
+            };
+            let (_, response) = self.projects().databases_documents_begin_transaction(request, database.as_str()).doit()?;
+            let mut transaction = Transaction {
+                hub: self,
+                database: database.clone(),
+                id: response.transaction.unwrap_or_default(),
+                writes: Vec::new(),
+            };
+
+            let result = f(&mut transaction).and_then(|value| {
+                validate_writes(&transaction.writes)?;
+                let request = CommitRequest {
+                    writes: Some(transaction.writes.drain(..).collect()),
+                    transaction: Some(transaction.id.clone()),
+                };
+                self.projects().databases_documents_commit(request, database.as_str()).doit()
+                    .map(|_| value)
+            });
+
+            match result {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let request = RollbackRequest { transaction: Some(transaction.id.clone()) };
+                    let _ = self.projects().databases_documents_rollback(request, database.as_str()).doit();
+                    if !err.is_aborted() || attempt >= MAX_TRANSACTION_ATTEMPTS {
+                        return Err(err)
+                    }
+                    retry_transaction = Some(transaction.id);
+                    sleep(TRANSACTION_RETRY_DELAY * 2u32.pow(attempt as u32 - 1));
+                }
+            }
+        }
+    }
+
+    /// Runs `f` in a read-write transaction in the database set with `database()`,
+    /// see `run_transaction()`.
+    pub fn run_transaction_default<T, F>(&'a self, f: F) -> Result<T>
+        where F: FnMut(&mut Transaction<'a, C, A>) -> Result<T> {
+        self.run_transaction(self.default_database()?, f)
+    }
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use std::cell::Cell;
+    use crate::cmn::*;
+    use crate::document::*;
+    use crate::status::Code;
+    use crate::testing::{Failure, MockFirestore};
+    use super::*;
+
+    const DATABASE: &str = "projects/p/databases/(default)";
+    const ALICE: &str = "projects/p/databases/(default)/documents/users/alice";
+
+    fn methods(server: &MockFirestore) -> Vec<String> {
+        server.requests().iter().map(|r| r.split(' ').next().unwrap().to_string()).collect()
+    }
+
+    fn aborted() -> Failure {
+        Failure::status(409, "ABORTED", "Too much contention on these documents.").method("commit")
+    }
+
+    #[test]
+    fn retries_aborted_transactions() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+        server.fail_next(aborted());
+
+        let attempts = Cell::new(0);
+        let result = hub.run_transaction(DATABASE, |transaction| {
+            attempts.set(attempts.get() + 1);
+            transaction.update(Document { name: Some(ALICE.to_string()), ..Default::default() });
+            Ok(attempts.get())
+        });
+        assert_eq!(result.unwrap(), 2);
+        assert!(server.document(ALICE).is_some());
+        assert_eq!(methods(&server), vec!["beginTransaction", "commit", "rollback", "beginTransaction", "commit"]);
+    }
+
+    #[test]
+    fn gives_up_on_contention() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+        for _ in 0..MAX_TRANSACTION_ATTEMPTS {
+            server.fail_next(aborted());
+        }
+
+        let attempts = Cell::new(0);
+        let result = hub.run_transaction_default(|transaction| {
+            attempts.set(attempts.get() + 1);
+            transaction.delete(ALICE);
+            Ok(())
+        });
+        match result {
+            Err(Error::Firestore(ref err)) => assert_eq!(err.code, Code::Aborted),
+            other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+        }
+        assert_eq!(attempts.get(), MAX_TRANSACTION_ATTEMPTS);
+        assert_eq!(methods(&server).iter().filter(|m| *m == "commit").count(), MAX_TRANSACTION_ATTEMPTS);
+        assert_eq!(methods(&server).last().map(String::as_str), Some("rollback"));
+    }
+
+    #[test]
+    fn rolls_back_on_errors() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+
+        let attempts = Cell::new(0);
+        let result: Result<()> = hub.run_transaction_default(|transaction| {
+            attempts.set(attempts.get() + 1);
+            transaction.delete(ALICE);
+            Err(Error::Cancelled)
+        });
+        match result {
+            Err(Error::Cancelled) => {},
+            other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+        }
+        assert_eq!(attempts.get(), 1);
+        assert_eq!(methods(&server), vec!["beginTransaction", "rollback"]);
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::io::{self, Cursor, Read};
+use std::cell::RefCell;
+use std::rc::Rc;
+use hyper::header::{ContentLength, ContentType, Headers};
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use hyper::Url;
+
+/// A request of a call, as handed to a `Transport`.
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// The url, including the query parameters of the call.
+    pub url: Url,
+    /// The `User-Agent` and `Authorization` headers, and `Content-Type` and
+    /// `Content-Length` for requests with a body.
+    pub headers: Headers,
+    /// The JSON body, or no bytes for methods without a request body.
+    pub body: Vec<u8>,
+}
+
+/// The response to an `HttpRequest`. The body is read from the response itself.
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: Headers,
+    body: Box<dyn Read>,
+}
+
+impl HttpResponse {
+
+    /// A response whose body is read from `body`, e.g. a connection which is still
+    /// receiving it.
+    pub fn new<R: Read + 'static>(status: StatusCode, headers: Headers, body: R) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers,
+            body: Box::new(body),
+        }
+    }
+
+    /// A response with the given JSON body, and the matching `Content-Type` and
+    /// `Content-Length` headers.
+    pub fn json<B: Into<String>>(status: StatusCode, body: B) -> HttpResponse {
+        let body = body.into().into_bytes();
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        headers.set(ContentLength(body.len() as u64));
+        HttpResponse::new(status, headers, Cursor::new(body))
+    }
+}
+
+impl Read for HttpResponse {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.body.read(buf)
+    }
+}
+
+impl fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
+
+/// Sends the requests of all calls of a `Firestore` hub.
+///
+/// It is implemented by `hyper::Client`, and by `InMemoryTransport` and
+/// `testing::MockFirestore`, which never touch the network. Other implementations can
+/// record or replay requests, send them through a proxy, or fake a server.
+pub trait Transport {
+    /// Sends `request`, and returns the response once its status and headers were
+    /// received. Errors are passed to `Delegate::http_error()`, which may retry the call.
+    fn send(&mut self, request: HttpRequest) -> hyper::Result<HttpResponse>;
+}
+
+impl Transport for hyper::Client {
+    fn send(&mut self, request: HttpRequest) -> hyper::Result<HttpResponse> {
+        let mut req = self.request(request.method, request.url)
+            .headers(request.headers);
+        if !request.body.is_empty() {
+            req = req.body(&request.body[..]);
+        }
+        let res = req.send()?;
+        Ok(HttpResponse::new(res.status, res.headers.clone(), res))
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for &mut T {
+    fn send(&mut self, request: HttpRequest) -> hyper::Result<HttpResponse> {
+        (**self).send(request)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn send(&mut self, request: HttpRequest) -> hyper::Result<HttpResponse> {
+        (**self).send(request)
+    }
+}
+
+type Handler = Box<dyn FnMut(&HttpRequest) -> hyper::Result<HttpResponse>>;
+
+/// A transport answering each request with a function, and recording all requests.
+///
+/// Clones share the handler and the recorded requests, so a clone can be kept to
+/// inspect the requests sent by a hub.
+///
+/// # Example
+///
+/// ```
+/// # extern crate hyper;
+/// # extern crate google_firestore as firestore1;
+/// # use firestore1::{EmulatorAuth, FirestoreBuilder, HttpResponse, InMemoryTransport, Result};
+/// # use hyper::status::StatusCode;
+/// # fn egal() -> Result<()> {
+/// let transport = InMemoryTransport::new(|_| {
+///     Ok(HttpResponse::json(StatusCode::Ok, r#"{"name": "projects/p/databases/(default)/documents/users/alice"}"#))
+/// });
+/// let hub = FirestoreBuilder::new().build(transport.clone(), EmulatorAuth)?;
+/// let (_, alice) = hub.projects()
+///     .databases_documents_get("projects/p/databases/(default)/documents/users/alice")
+///     .doit()?;
+/// assert_eq!(transport.requests()[0].url.path(), "/v1/projects/p/databases/(default)/documents/users/alice");
+/// # Ok(())
+/// # }
+/// # fn main() { egal().unwrap() }
+/// ```
+#[derive(Clone)]
+pub struct InMemoryTransport {
+    handler: Rc<RefCell<Handler>>,
+    requests: Rc<RefCell<Vec<HttpRequest>>>,
+}
+
+impl InMemoryTransport {
+
+    /// A transport answering each request with `handler`.
+    pub fn new<F>(handler: F) -> InMemoryTransport
+        where F: FnMut(&HttpRequest) -> hyper::Result<HttpResponse> + 'static {
+        InMemoryTransport {
+            handler: Rc::new(RefCell::new(Box::new(handler))),
+            requests: Default::default(),
+        }
+    }
+
+    /// The requests sent so far, oldest first.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.borrow().clone()
+    }
+}
+
+impl Transport for InMemoryTransport {
+    fn send(&mut self, request: HttpRequest) -> hyper::Result<HttpResponse> {
+        let response = (*self.handler.borrow_mut())(&request);
+        self.requests.borrow_mut().push(request);
+        response
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser::SerializeMap;
+use crate::cmn::*;
+use crate::value::*;
+use crate::convert::NULL_VALUE;
+
+/// A Firestore value with exactly one of the supported value types.
+///
+/// It (de)serializes to the same JSON as `Value`, and converts losslessly from and
+/// to it. Converting a `Value` fails if it sets more than one value type, or if its
+/// integer or bytes value can't be decoded. A `Value` without any value type set is
+/// read as `ValueKind::Null`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueKind {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Double(f64),
+    /// A timestamp in RFC 3339 format, e.g. `2014-10-02T15:01:23.045123456Z`.
+    Timestamp(String),
+    String(String),
+    Bytes(Vec<u8>),
+    /// A document resource name, e.g.
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    Reference(String),
+    GeoPoint(LatLng),
+    Array(Vec<ValueKind>),
+    Map(HashMap<String, ValueKind>),
+}
+
+impl TryFrom<Value> for ValueKind {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<ValueKind> {
+        let mut kinds = Vec::with_capacity(1);
+        if value.null_value.is_some() {
+            kinds.push(ValueKind::Null);
+        }
+        if let Some(b) = value.boolean_value {
+            kinds.push(ValueKind::Boolean(b));
+        }
+        if let Some(i) = value.integer_value {
+            match i.parse() {
+                Ok(i) => kinds.push(ValueKind::Integer(i)),
+                Err(_) => return Err(Error::Conversion(format!("invalid integer value '{}'", i))),
+            }
+        }
+        if let Some(d) = value.double_value {
+            kinds.push(ValueKind::Double(d));
+        }
+        if let Some(t) = value.timestamp_value {
+            kinds.push(ValueKind::Timestamp(t));
+        }
+        if let Some(s) = value.string_value {
+            kinds.push(ValueKind::String(s));
+        }
+        if let Some(b) = value.bytes_value {
+            match base64::decode(&b) {
+                Ok(b) => kinds.push(ValueKind::Bytes(b)),
+                Err(err) => return Err(Error::Conversion(format!("invalid bytes value: {}", err))),
+            }
+        }
+        if let Some(r) = value.reference_value {
+            kinds.push(ValueKind::Reference(r));
+        }
+        if let Some(p) = value.geo_point_value {
+            kinds.push(ValueKind::GeoPoint(p));
+        }
+        if let Some(a) = value.array_value {
+            let values = a.values.unwrap_or_default().into_iter()
+                .map(ValueKind::try_from)
+                .collect::<Result<_>>()?;
+            kinds.push(ValueKind::Array(values));
+        }
+        if let Some(m) = value.map_value {
+            let fields = m.fields.unwrap_or_default().into_iter()
+                .map(|(k, v)| ValueKind::try_from(v).map(|v| (k, v)))
+                .collect::<Result<_>>()?;
+            kinds.push(ValueKind::Map(fields));
+        }
+        match kinds.len() {
+            0 => Ok(ValueKind::Null),
+            1 => Ok(kinds.remove(0)),
+            _ => Err(Error::Conversion("a value must not have more than one value type".to_string())),
+        }
+    }
+}
+
+impl From<ValueKind> for Value {
+    fn from(kind: ValueKind) -> Value {
+        let mut value = Value::default();
+        match kind {
+            ValueKind::Null => value.null_value = Some(NULL_VALUE.to_string()),
+            ValueKind::Boolean(b) => value.boolean_value = Some(b),
+            ValueKind::Integer(i) => value.integer_value = Some(i.to_string()),
+            ValueKind::Double(d) => value.double_value = Some(d),
+            ValueKind::Timestamp(t) => value.timestamp_value = Some(t),
+            ValueKind::String(s) => value.string_value = Some(s),
+            ValueKind::Bytes(b) => value.bytes_value = Some(base64::encode(&b)),
+            ValueKind::Reference(r) => value.reference_value = Some(r),
+            ValueKind::GeoPoint(p) => value.geo_point_value = Some(p),
+            ValueKind::Array(a) => value.array_value = Some(ArrayValue {
+                values: Some(a.into_iter().map(Value::from).collect()),
+            }),
+            ValueKind::Map(m) => value.map_value = Some(MapValue {
+                fields: Some(m.into_iter().map(|(k, v)| (k, Value::from(v))).collect()),
+            }),
+        }
+        value
+    }
+}
+
+/// Serializes as `{"values": [...]}`, the JSON of an `ArrayValue`.
+struct ArrayRef<'a>(&'a [ValueKind]);
+
+impl<'a> Serialize for ArrayRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("values", self.0)?;
+        map.end()
+    }
+}
+
+/// Serializes as `{"fields": {...}}`, the JSON of a `MapValue`.
+struct MapRef<'a>(&'a HashMap<String, ValueKind>);
+
+impl<'a> Serialize for MapRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("fields", self.0)?;
+        map.end()
+    }
+}
+
+impl Serialize for ValueKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        match *self {
+            ValueKind::Null => map.serialize_entry("nullValue", NULL_VALUE)?,
+            ValueKind::Boolean(ref b) => map.serialize_entry("booleanValue", b)?,
+            ValueKind::Integer(ref i) => map.serialize_entry("integerValue", &i.to_string())?,
+            ValueKind::Double(ref d) => map.serialize_entry("doubleValue", d)?,
+            ValueKind::Timestamp(ref t) => map.serialize_entry("timestampValue", t)?,
+            ValueKind::String(ref s) => map.serialize_entry("stringValue", s)?,
+            ValueKind::Bytes(ref b) => map.serialize_entry("bytesValue", &base64::encode(b))?,
+            ValueKind::Reference(ref r) => map.serialize_entry("referenceValue", r)?,
+            ValueKind::GeoPoint(ref p) => map.serialize_entry("geoPointValue", p)?,
+            ValueKind::Array(ref a) => map.serialize_entry("arrayValue", &ArrayRef(a))?,
+            ValueKind::Map(ref m) => map.serialize_entry("mapValue", &MapRef(m))?,
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<ValueKind, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        ValueKind::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<bool> for ValueKind {
+    fn from(value: bool) -> ValueKind {
+        ValueKind::Boolean(value)
+    }
+}
+
+impl From<i32> for ValueKind {
+    fn from(value: i32) -> ValueKind {
+        ValueKind::Integer(value.into())
+    }
+}
+
+impl From<i64> for ValueKind {
+    fn from(value: i64) -> ValueKind {
+        ValueKind::Integer(value)
+    }
+}
+
+impl From<u32> for ValueKind {
+    fn from(value: u32) -> ValueKind {
+        ValueKind::Integer(value.into())
+    }
+}
+
+impl From<f64> for ValueKind {
+    fn from(value: f64) -> ValueKind {
+        ValueKind::Double(value)
+    }
+}
+
+impl<'a> From<&'a str> for ValueKind {
+    fn from(value: &'a str) -> ValueKind {
+        ValueKind::String(value.to_string())
+    }
+}
+
+impl From<String> for ValueKind {
+    fn from(value: String) -> ValueKind {
+        ValueKind::String(value)
+    }
+}
+
+impl<T: Into<ValueKind>> From<Option<T>> for ValueKind {
+    fn from(value: Option<T>) -> ValueKind {
+        value.map_or(ValueKind::Null, Into::into)
+    }
+}
+
+impl<T: Into<ValueKind>> From<Vec<T>> for ValueKind {
+    fn from(values: Vec<T>) -> ValueKind {
+        ValueKind::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use serde_json as json;
+    use super::*;
+
+    fn every_kind() -> Vec<ValueKind> {
+        let mut map = HashMap::new();
+        map.insert("nested".to_string(), ValueKind::Array(vec![ValueKind::Null, 7.into()]));
+        map.insert("empty".to_string(), ValueKind::Map(HashMap::new()));
+        vec![
+            ValueKind::Null,
+            ValueKind::Boolean(true),
+            ValueKind::Integer(i64::MIN),
+            ValueKind::Double(-0.25),
+            ValueKind::Timestamp("2014-10-02T15:01:23.045123456Z".to_string()),
+            ValueKind::String("ünïcödé".to_string()),
+            ValueKind::Bytes(vec![0, 159, 146, 150]),
+            ValueKind::Reference("projects/p/databases/(default)/documents/users/alice".to_string()),
+            ValueKind::GeoPoint(LatLng { latitude: Some(52.5), longitude: Some(13.4) }),
+            ValueKind::Array(Vec::new()),
+            ValueKind::Map(map),
+        ]
+    }
+
+    #[test]
+    fn value_round_trip() {
+        for kind in every_kind() {
+            let value = Value::from(kind.clone());
+            assert_eq!(ValueKind::try_from(value).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn json_matches_value() {
+        for kind in every_kind() {
+            let from_kind = json::to_value(&kind).unwrap();
+            // `Value` writes its unset fields as `null`, which are left out of requests.
+            let mut from_value = json::to_value(Value::from(kind.clone())).unwrap();
+            remove_json_null_values(&mut from_value);
+            assert_eq!(from_kind, from_value);
+            assert_eq!(json::from_value::<ValueKind>(from_kind).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn empty_value_is_null() {
+        assert_eq!(ValueKind::try_from(Value::default()).unwrap(), ValueKind::Null);
+        assert_eq!(json::from_str::<ValueKind>("{}").unwrap(), ValueKind::Null);
+
+        let array = Value {
+            array_value: Some(ArrayValue { values: Some(vec![Value::default()]) }),
+            ..Default::default()
+        };
+        assert_eq!(ValueKind::try_from(array).unwrap(), ValueKind::Array(vec![ValueKind::Null]));
+    }
+
+    #[test]
+    fn invalid_values() {
+        let two_types = Value {
+            boolean_value: Some(true),
+            string_value: Some("yes".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(ValueKind::try_from(two_types), Err(Error::Conversion(_))));
+
+        let integer = Value { integer_value: Some("1.5".to_string()), ..Default::default() };
+        assert!(matches!(ValueKind::try_from(integer), Err(Error::Conversion(_))));
+
+        let bytes = Value { bytes_value: Some("%%%".to_string()), ..Default::default() };
+        assert!(matches!(ValueKind::try_from(bytes), Err(Error::Conversion(_))));
+
+        let nested = Value {
+            map_value: Some(MapValue {
+                fields: Some(vec![("n".to_string(), Value { integer_value: Some("x".to_string()), ..Default::default() })]
+                    .into_iter().collect()),
+            }),
+            ..Default::default()
+        };
+        assert!(ValueKind::try_from(nested).is_err());
+    }
+
+    #[test]
+    fn conversions_from_rust_types() {
+        assert_eq!(ValueKind::from(None::<i64>), ValueKind::Null);
+        assert_eq!(ValueKind::from(Some("a")), ValueKind::String("a".to_string()));
+        assert_eq!(ValueKind::from(vec![1u32, 2]), ValueKind::Array(vec![ValueKind::Integer(1), ValueKind::Integer(2)]));
+    }
+}
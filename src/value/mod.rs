@@ -1,5 +1,9 @@
 pub use crate::cmn::*;
 use std::collections::HashMap;
+
+mod kind;
+pub use kind::*;
+
 /// A message that can hold any of the supported value types.
 ///
 /// This type is not used in any activity, and only used as *part* of another schema.
@@ -0,0 +1,200 @@
+use crate::cmn::*;
+use crate::document::*;
+#[cfg(feature = "blocking")]
+use crate::firestore::*;
+use crate::transaction::*;
+use crate::write::*;
+use crate::transport::Transport;
+
+/// A batch of writes which are applied atomically by a single commit.
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate google_firestore as firestore1;
+/// use firestore1::{Document, WriteBatch};
+///
+/// let mut batch = WriteBatch::new();
+/// batch.set(Document::default())
+///      .update(Document::default(), &["stats.views"])
+///      .delete("projects/p/databases/(default)/documents/users/bob");
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct WriteBatch {
+    writes: Vec<Write>,
+}
+
+impl WriteBatch {
+
+    /// Creates an empty batch.
+    pub fn new() -> WriteBatch {
+        Default::default()
+    }
+
+    /// Writes `document` to `document.name`, replacing any existing document.
+    pub fn set(&mut self, document: Document) -> &mut WriteBatch {
+        self.write(Write {
+            update: Some(document),
+            ..Default::default()
+        })
+    }
+
+    /// Updates the fields of the existing document `document.name` which are named
+    /// by `field_paths`.
+    ///
+    /// Fields listed in `field_paths` but missing in `document` are deleted on the server.
+    /// The write fails if the document does not exist.
+    pub fn update(&mut self, document: Document, field_paths: &[&str]) -> &mut WriteBatch {
+        self.write(Write {
+            update: Some(document),
+            update_mask: Some(DocumentMask {
+                field_paths: Some(field_paths.iter().map(|p| p.to_string()).collect()),
+            }),
+            current_document: Some(Precondition::exists(true)),
+            ..Default::default()
+        })
+    }
+
+    /// Deletes the document with the given resource name.
+    pub fn delete<N: Into<String>>(&mut self, name: N) -> &mut WriteBatch {
+        self.write(Write {
+            delete: Some(name.into()),
+            ..Default::default()
+        })
+    }
+
+    /// Applies the given field transforms, in order, to the document with the given
+    /// resource name.
+    pub fn transform<N: Into<String>>(&mut self, name: N, field_transforms: Vec<FieldTransform>) -> &mut WriteBatch {
+        self.write(Write {
+            transform: Some(DocumentTransform {
+                document: Some(name.into()),
+                field_transforms: Some(field_transforms),
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// Sets the precondition of the most recently added write.
+    pub fn precondition(&mut self, precondition: Precondition) -> &mut WriteBatch {
+        if let Some(write) = self.writes.last_mut() {
+            write.current_document = Some(precondition);
+        }
+        self
+    }
+
+    /// Adds an arbitrary write to the batch.
+    pub fn write(&mut self, write: Write) -> &mut WriteBatch {
+        self.writes.push(write);
+        self
+    }
+
+    /// The amount of writes in this batch.
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    /// Whether this batch contains no writes.
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// Converts this batch into a `CommitRequest`.
+    ///
+    /// Fails with `Error::WriteLimitExceeded` naming the first write beyond
+    /// `MAX_WRITES_PER_COMMIT`, or with `Error::InvalidFieldTransform` naming the
+    /// first write with an invalid `FieldTransform`, or a transform without any.
+    pub fn into_request(self) -> Result<CommitRequest> {
+        validate_writes(&self.writes)?;
+        Ok(CommitRequest {
+            writes: Some(self.writes),
+            transaction: None,
+        })
+    }
+
+    /// Commits all writes of this batch atomically.
+    ///
+    /// The i-th `WriteResult` corresponds to the i-th write added to the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database name. In the format:
+    ///   `projects/{project_id}/databases/{database_id}`.
+    #[cfg(feature = "blocking")]
+    pub fn commit<C, A, D>(self, hub: &Firestore<C, A>, database: D) -> Result<Vec<WriteResult>>
+        where C: Transport, A: oauth2::GetToken, D: Into<String> {
+        let request = self.into_request()?;
+        let (_, response) = hub.projects().databases_documents_commit(request, database).doit()?;
+        Ok(response.write_results.unwrap_or_default())
+    }
+
+    /// Commits all writes of this batch atomically in the database set with
+    /// `Firestore::database()`, see `commit()`.
+    #[cfg(feature = "blocking")]
+    pub fn commit_default<C, A>(self, hub: &Firestore<C, A>) -> Result<Vec<WriteResult>>
+        where C: Transport, A: oauth2::GetToken {
+        self.commit(hub, hub.default_database()?)
+    }
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use std::collections::HashMap;
+    use crate::testing::MockFirestore;
+    use crate::value::*;
+    use super::*;
+
+    const ALICE: &str = "projects/p/databases/(default)/documents/users/alice";
+    const BOB: &str = "projects/p/databases/(default)/documents/users/bob";
+
+    fn user(name: &str, age: i64) -> Document {
+        let mut fields = HashMap::new();
+        fields.insert("age".to_string(), Value::from(ValueKind::from(age)));
+        Document {
+            name: Some(name.to_string()),
+            fields: Some(fields),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn too_many_writes() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+        let mut batch = WriteBatch::new();
+        for _ in 0..MAX_WRITES_PER_COMMIT + 1 {
+            batch.delete(ALICE);
+        }
+        match batch.commit_default(&hub) {
+            Err(err @ Error::WriteLimitExceeded(MAX_WRITES_PER_COMMIT)) =>
+                assert!(err.to_string().contains("limit of 500 writes")),
+            other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+        }
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn commits_writes_in_order() {
+        let server = MockFirestore::new();
+        let hub = server.hub("p").unwrap();
+        let mut batch = WriteBatch::new();
+        batch.set(user(ALICE, 30))
+             .update(user(ALICE, 31), &["age"])
+             .set(user(BOB, 25))
+             .delete(BOB);
+
+        let request = batch.clone().into_request().unwrap();
+        let writes: Vec<_> = request.writes.unwrap().into_iter()
+            .map(|w| (w.update.and_then(|d| d.name), w.delete))
+            .collect();
+        assert_eq!(writes, vec![(Some(ALICE.to_string()), None), (Some(ALICE.to_string()), None),
+                                (Some(BOB.to_string()), None), (None, Some(BOB.to_string()))]);
+
+        let results = batch.commit_default(&hub).unwrap();
+        assert_eq!(results.len(), 4);
+        assert_eq!(server.requests(), vec!["commit projects/p/databases/(default)/documents"]);
+        let alice = server.document(ALICE).unwrap();
+        assert_eq!(alice.fields.unwrap()["age"].integer_value.as_deref(), Some("31"));
+        assert!(server.document(BOB).is_none());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::cmn::*;
+use crate::document::*;
+
+mod transform;
+mod batch;
+pub use transform::*;
+pub use batch::*;
+
+/// A write on a document.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Write {
+    /// A document name to delete. In the format:
+    /// `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub delete: Option<String>,
+    /// An optional precondition on the document.
+    ///
+    /// The write will fail if this is set and not met by the target document.
+    #[serde(rename="currentDocument")]
+    pub current_document: Option<Precondition>,
+    /// The fields to update in this write.
+    ///
+    /// This field can be set only when the operation is `update`.
+    /// If the mask is not set for an `update` and the document exists, any
+    /// existing data will be overwritten.
+    /// If the mask is set and the document on the server has fields not covered by
+    /// the mask, they are left unchanged.
+    /// Fields referenced in the mask, but not present in the input document, are
+    /// deleted from the document on the server.
+    /// The field paths in this mask must not contain a reserved field name.
+    #[serde(rename="updateMask")]
+    pub update_mask: Option<DocumentMask>,
+    /// Applies a transformation to a document.
+    /// At most one `transform` per document is allowed in a given request.
+    /// An `update` cannot follow a `transform` on the same document in a given
+    /// request.
+    pub transform: Option<DocumentTransform>,
+    /// A document to write.
+    pub update: Option<Document>,
+}
+
+impl Part for Write {}
+
+/// A precondition on a document, used for conditional operations.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Precondition {
+    /// When set, the target document must exist and have been last updated at
+    /// that time.
+    #[serde(rename="updateTime")]
+    pub update_time: Option<String>,
+    /// When set to `true`, the target document must exist.
+    /// When set to `false`, the target document must not exist.
+    pub exists: Option<bool>,
+}
+
+impl Part for Precondition {}
+
+impl Precondition {
+    /// A precondition requiring the target document to exist, or not to exist if
+    /// `exists` is `false`.
+    pub fn exists(exists: bool) -> Precondition {
+        Precondition {
+            exists: Some(exists),
+            update_time: None,
+        }
+    }
+
+    /// A precondition requiring the target document to exist and to have been
+    /// last updated at `update_time`.
+    pub fn update_time(update_time: &str) -> Precondition {
+        Precondition {
+            exists: None,
+            update_time: Some(update_time.to_string()),
+        }
+    }
+}
+
+/// The result of applying a write.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct WriteResult {
+    /// The last update time of the document after applying the write. Not set
+    /// after a `delete`.
+    ///
+    /// If the write did not actually change the document, this will be the
+    /// previous update_time.
+    #[serde(rename="updateTime")]
+    pub update_time: Option<String>,
+    /// The results of applying each DocumentTransform.FieldTransform, in the
+    /// same order.
+    #[serde(rename="transformResults")]
+    pub transform_results: Option<Vec<Value>>,
+}
+
+impl Part for WriteResult {}
+
+/// The maximum amount of writes Firestore accepts in a single commit.
+pub const MAX_WRITES_PER_COMMIT: usize = 500;
+
+/// Checks `writes` against the limits Firestore enforces on a single commit, and
+/// rejects transforms without any valid `FieldTransform`.
+pub(crate) fn validate_writes(writes: &[Write]) -> Result<()> {
+    if writes.len() > MAX_WRITES_PER_COMMIT {
+        return Err(Error::WriteLimitExceeded(MAX_WRITES_PER_COMMIT))
+    }
+    for (index, write) in writes.iter().enumerate() {
+        let field_transforms = match write.transform {
+            Some(ref transform) => transform.field_transforms.as_deref().unwrap_or_default(),
+            None => continue,
+        };
+        if field_transforms.is_empty() {
+            return Err(Error::InvalidFieldTransform(index, String::new()))
+        }
+        for field_transform in field_transforms {
+            if field_transform.kind().is_none() {
+                let field_path = field_transform.field_path.clone().unwrap_or_default();
+                return Err(Error::InvalidFieldTransform(index, field_path))
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "projects/p/databases/(default)/documents/users/alice";
+
+    fn transform(field_transforms: Option<Vec<FieldTransform>>) -> Write {
+        Write {
+            transform: Some(DocumentTransform { document: Some(ALICE.to_string()), field_transforms }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn valid_writes() {
+        let writes = vec![
+            Write { delete: Some(ALICE.to_string()), ..Default::default() },
+            transform(Some(vec![FieldTransform::increment("a", 1), FieldTransform::server_timestamp("b")])),
+        ];
+        assert!(validate_writes(&writes).is_ok());
+    }
+
+    #[test]
+    fn invalid_transforms() {
+        let invalid = FieldTransform { increment: None, ..FieldTransform::increment("b", 1) };
+        let cases = vec![
+            (transform(None), ""),
+            (transform(Some(Vec::new())), ""),
+            (transform(Some(vec![FieldTransform::increment("a", 1), invalid])), "b"),
+            (transform(Some(vec![FieldTransform { field_path: None, ..FieldTransform::increment("a", 1) }])), ""),
+        ];
+        for (write, field_path) in cases {
+            let writes = vec![Write { delete: Some(ALICE.to_string()), ..Default::default() }, write];
+            match validate_writes(&writes) {
+                Err(Error::InvalidFieldTransform(1, ref path)) => assert_eq!(path, field_path),
+                other => panic!("unexpected result: {:?}", other.map_err(|e| e.to_string())),
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use crate::cmn::*;
+use crate::value::*;
+
+/// A transformation of a document.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct DocumentTransform {
+    /// The name of the document to transform.
+    pub document: Option<String>,
+    /// The list of transformations to apply to the fields of the document, in
+    /// order.
+    /// This must not be empty.
+    #[serde(rename="fieldTransforms")]
+    pub field_transforms: Option<Vec<FieldTransform>>,
+}
+
+impl Part for DocumentTransform {}
+
+/// A transformation of a field of the document.
+///
+/// This type is not used in any activity, and only used as *part* of another schema.
+///
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct FieldTransform {
+    /// The path of the field. See Document.fields for the field path syntax
+    /// reference.
+    #[serde(rename="fieldPath")]
+    pub field_path: Option<String>,
+    /// Sets the field to the minimum of its current value and the given value.
+    ///
+    /// This must be an integer or a double value.
+    /// If the field is not an integer or double, or if the field does not yet
+    /// exist, the transformation will set the field to the input value.
+    /// If a minimum operation is applied where the field and the input value
+    /// are of mixed types (that is - one is an integer and one is a double)
+    /// the field takes on the type of the smaller operand. If the operands are
+    /// equivalent (e.g. 3 and 3.0), the field does not change.
+    /// 0, 0.0, and -0.0 are all zero. The minimum of a zero stored value and
+    /// zero input value is always the stored value.
+    /// The minimum of any numeric value x and NaN is NaN.
+    pub minimum: Option<Value>,
+    /// Append the given elements in order if they are not already present in
+    /// the current field value.
+    /// If the field is not an array, or if the field does not yet exist, it is
+    /// first set to the empty array.
+    ///
+    /// Equivalent numbers of different types (e.g. 3L and 3.0) are
+    /// considered equal when checking if a value is missing.
+    /// NaN is equal to NaN, and Null is equal to Null.
+    /// If the input contains multiple equivalent values, only the first will
+    /// be considered.
+    ///
+    /// The corresponding transform_result will be the null value.
+    #[serde(rename="appendMissingElements")]
+    pub append_missing_elements: Option<ArrayValue>,
+    /// Adds the given value to the field's current value.
+    ///
+    /// This must be an integer or a double value.
+    /// If the field is not an integer or double, or if the field does not yet
+    /// exist, the transformation will set the field to the given value.
+    /// If either of the given value or the current field value are doubles,
+    /// both values will be interpreted as doubles. Double arithmetic and
+    /// representation of double values follow IEEE 754 semantics.
+    /// If there is positive/negative integer overflow, the field is resolved
+    /// to the largest magnitude positive/negative integer.
+    pub increment: Option<Value>,
+    /// Sets the field to the maximum of its current value and the given value.
+    ///
+    /// This must be an integer or a double value.
+    /// If the field is not an integer or double, or if the field does not yet
+    /// exist, the transformation will set the field to the given value.
+    /// If a maximum operation is applied where the field and the input value
+    /// are of mixed types (that is - one is an integer and one is a double)
+    /// the field takes on the type of the larger operand. If the operands are
+    /// equivalent (e.g. 3 and 3.0), the field does not change.
+    /// 0, 0.0, and -0.0 are all zero. The maximum of a zero stored value and
+    /// zero input value is always the stored value.
+    /// The maximum of any numeric value x and NaN is NaN.
+    pub maximum: Option<Value>,
+    /// Remove all of the given elements from the array in the field.
+    /// If the field is not an array, or if the field does not yet exist, it is
+    /// set to the empty array.
+    ///
+    /// Equivalent numbers of the different types (e.g. 3L and 3.0) are
+    /// considered equal when deciding whether an element should be removed.
+    /// NaN is equal to NaN, and Null is equal to Null.
+    /// This will remove all equivalent values if there are duplicates.
+    ///
+    /// The corresponding transform_result will be the null value.
+    #[serde(rename="removeAllFromArray")]
+    pub remove_all_from_array: Option<ArrayValue>,
+    /// Sets the field to the given server value.
+    #[serde(rename="setToServerValue")]
+    pub set_to_server_value: Option<String>,
+}
+
+impl Part for FieldTransform {}
+
+/// A numeric operand of an `increment`, `maximum` or `minimum` field transform.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Numeric {
+    Integer(i64),
+    Double(f64),
+}
+
+impl From<i64> for Numeric {
+    fn from(value: i64) -> Numeric {
+        Numeric::Integer(value)
+    }
+}
+
+impl From<i32> for Numeric {
+    fn from(value: i32) -> Numeric {
+        Numeric::Integer(value.into())
+    }
+}
+
+impl From<u32> for Numeric {
+    fn from(value: u32) -> Numeric {
+        Numeric::Integer(value.into())
+    }
+}
+
+impl From<f64> for Numeric {
+    fn from(value: f64) -> Numeric {
+        Numeric::Double(value)
+    }
+}
+
+impl From<f32> for Numeric {
+    fn from(value: f32) -> Numeric {
+        Numeric::Double(value.into())
+    }
+}
+
+impl From<Numeric> for Value {
+    fn from(value: Numeric) -> Value {
+        match value {
+            Numeric::Integer(i) => Value { integer_value: Some(i.to_string()), ..Default::default() },
+            Numeric::Double(d) => Value { double_value: Some(d), ..Default::default() },
+        }
+    }
+}
+
+/// The kind of transformation a `FieldTransform` applies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransformKind {
+    Increment,
+    Maximum,
+    Minimum,
+    AppendMissingElements,
+    RemoveAllFromArray,
+    ServerTimestamp,
+}
+
+/// The only server value currently supported by `set_to_server_value`.
+const REQUEST_TIME: &str = "REQUEST_TIME";
+
+impl FieldTransform {
+
+    /// Adds `value` to the current value of the field at `field_path`.
+    pub fn increment<N: Into<Numeric>>(field_path: &str, value: N) -> FieldTransform {
+        FieldTransform {
+            field_path: Some(field_path.to_string()),
+            increment: Some(value.into().into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the field at `field_path` to the maximum of its current value and `value`.
+    pub fn maximum<N: Into<Numeric>>(field_path: &str, value: N) -> FieldTransform {
+        FieldTransform {
+            field_path: Some(field_path.to_string()),
+            maximum: Some(value.into().into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the field at `field_path` to the minimum of its current value and `value`.
+    pub fn minimum<N: Into<Numeric>>(field_path: &str, value: N) -> FieldTransform {
+        FieldTransform {
+            field_path: Some(field_path.to_string()),
+            minimum: Some(value.into().into()),
+            ..Default::default()
+        }
+    }
+
+    /// Appends each of `values` to the array at `field_path` unless it is already present.
+    pub fn array_union(field_path: &str, values: Vec<Value>) -> FieldTransform {
+        FieldTransform {
+            field_path: Some(field_path.to_string()),
+            append_missing_elements: Some(ArrayValue { values: Some(values) }),
+            ..Default::default()
+        }
+    }
+
+    /// Removes all occurrences of each of `values` from the array at `field_path`.
+    pub fn array_remove(field_path: &str, values: Vec<Value>) -> FieldTransform {
+        FieldTransform {
+            field_path: Some(field_path.to_string()),
+            remove_all_from_array: Some(ArrayValue { values: Some(values) }),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the field at `field_path` to the time at which the server processed the request.
+    pub fn server_timestamp(field_path: &str) -> FieldTransform {
+        FieldTransform {
+            field_path: Some(field_path.to_string()),
+            set_to_server_value: Some(REQUEST_TIME.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the kind of this transform, or `None` if it is invalid.
+    ///
+    /// A transform is valid if it has a field path and sets exactly one transformation,
+    /// `increment`, `maximum` and `minimum` use an integer or double operand and
+    /// `set_to_server_value` is `REQUEST_TIME`.
+    pub fn kind(&self) -> Option<TransformKind> {
+        fn is_numeric(value: &Value) -> bool {
+            match (&value.integer_value, value.double_value) {
+                (Some(i), None) => i.parse::<i64>().is_ok(),
+                (None, Some(_)) => true,
+                _ => false,
+            }
+        }
+        match self.field_path {
+            Some(ref path) if !path.is_empty() => {},
+            _ => return None,
+        }
+        let mut kinds = Vec::with_capacity(1);
+        if let Some(ref value) = self.increment {
+            kinds.push((TransformKind::Increment, is_numeric(value)));
+        }
+        if let Some(ref value) = self.maximum {
+            kinds.push((TransformKind::Maximum, is_numeric(value)));
+        }
+        if let Some(ref value) = self.minimum {
+            kinds.push((TransformKind::Minimum, is_numeric(value)));
+        }
+        if self.append_missing_elements.is_some() {
+            kinds.push((TransformKind::AppendMissingElements, true));
+        }
+        if self.remove_all_from_array.is_some() {
+            kinds.push((TransformKind::RemoveAllFromArray, true));
+        }
+        if let Some(ref value) = self.set_to_server_value {
+            kinds.push((TransformKind::ServerTimestamp, value == REQUEST_TIME));
+        }
+        match kinds.as_slice() {
+            [(kind, true)] => Some(*kind),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json as json;
+    use crate::method::json_body;
+    use crate::value::*;
+    use super::*;
+
+    fn to_json(transform: &FieldTransform) -> json::Value {
+        json::from_slice(&json_body(transform)).unwrap()
+    }
+
+    #[test]
+    fn constructors() {
+        let cases = [
+            (FieldTransform::increment("stats.views", 1), TransformKind::Increment,
+             json::json!({"fieldPath": "stats.views", "increment": {"integerValue": "1"}})),
+            (FieldTransform::maximum("score", 2.5), TransformKind::Maximum,
+             json::json!({"fieldPath": "score", "maximum": {"doubleValue": 2.5}})),
+            (FieldTransform::minimum("score", -3i64), TransformKind::Minimum,
+             json::json!({"fieldPath": "score", "minimum": {"integerValue": "-3"}})),
+            (FieldTransform::array_union("tags", vec![Value::from(ValueKind::from("a"))]), TransformKind::AppendMissingElements,
+             json::json!({"fieldPath": "tags", "appendMissingElements": {"values": [{"stringValue": "a"}]}})),
+            (FieldTransform::array_remove("tags", vec![Value::from(ValueKind::from("b"))]), TransformKind::RemoveAllFromArray,
+             json::json!({"fieldPath": "tags", "removeAllFromArray": {"values": [{"stringValue": "b"}]}})),
+            (FieldTransform::server_timestamp("updated"), TransformKind::ServerTimestamp,
+             json::json!({"fieldPath": "updated", "setToServerValue": "REQUEST_TIME"})),
+        ];
+        for (transform, kind, expected) in cases.iter() {
+            assert_eq!(transform.kind(), Some(*kind));
+            assert_eq!(&to_json(transform), expected);
+        }
+    }
+
+    #[test]
+    fn invalid_transforms() {
+        let no_path = FieldTransform { field_path: None, ..FieldTransform::increment("", 1) };
+        let empty_path = FieldTransform::increment("", 1);
+        let no_kind = FieldTransform { field_path: Some("a".to_string()), ..Default::default() };
+        let two_kinds = FieldTransform { maximum: Some(Numeric::from(1).into()), ..FieldTransform::increment("a", 1) };
+        let not_numeric = FieldTransform { increment: Some(Value::from(ValueKind::from("1"))), ..FieldTransform::increment("a", 1) };
+        let bad_integer = FieldTransform {
+            increment: Some(Value { integer_value: Some("one".to_string()), ..Default::default() }),
+            ..FieldTransform::increment("a", 1)
+        };
+        let unknown_server_value = FieldTransform {
+            set_to_server_value: Some("SERVER_VALUE_UNSPECIFIED".to_string()),
+            ..FieldTransform::server_timestamp("a")
+        };
+        for transform in [no_path, empty_path, no_kind, two_kinds, not_numeric, bad_integer, unknown_server_value].iter() {
+            assert_eq!(transform.kind(), None, "{:?}", transform);
+        }
+    }
+}